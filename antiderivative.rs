@@ -0,0 +1,152 @@
+// SPDX-License-Identifier: MIT
+// F(x) = ∫_x0^x f on a whole grid at once: one Gauss-Legendre rule per panel, accumulated left to
+// right, so F at every grid point costs the same as a single composite integral. In between, F is
+// the cubic Hermite interpolant of F and F' = f at the panel ends, which is O(h^4) like the
+// quadrature. Where f >= 0 (a density, say) F is monotone and can be inverted, e.g. for inverse
+// transform sampling.
+
+use crate::quadgl;
+
+#[derive(Clone, Debug)]
+pub struct Antiderivative {
+    // grid points, strictly increasing
+    xs: Vec<f64>,
+    // f there
+    ys: Vec<f64>,
+    // F there, starting from 0
+    fs: Vec<f64>,
+    // whether fs is nondecreasing, so that inverse makes sense
+    monotone: bool,
+}
+
+impl Antiderivative {
+    // an n-point rule on each panel of the grid
+    pub fn new(n: usize, f: impl Fn(f64) -> f64, grid: &[f64]) -> Antiderivative {
+        assert!(grid.len() >= 2);
+        assert!(
+            grid.windows(2).all(|p| p[0] < p[1]),
+            "grid points must be strictly increasing"
+        );
+        let mut fs: Vec<f64> = Vec::with_capacity(grid.len());
+        fs.push(0.0);
+        for p in grid.windows(2) {
+            let prev = *fs.last().unwrap();
+            fs.push(prev + quadgl::integrate(n, &f, p[0], p[1]));
+        }
+        return Antiderivative {
+            xs: grid.to_vec(),
+            ys: grid.iter().map(|x| f(*x)).collect(),
+            monotone: fs.is_sorted(),
+            fs,
+        };
+    }
+
+    // the same on `panels` equal panels of [x0, x1]
+    pub fn uniform(
+        n: usize,
+        f: impl Fn(f64) -> f64,
+        x0: f64,
+        x1: f64,
+        panels: usize,
+    ) -> Antiderivative {
+        let h = (x1 - x0) / (panels as f64);
+        let mut grid = (0..panels)
+            .map(|k| x0 + (k as f64) * h)
+            .collect::<Vec<f64>>();
+        grid.push(x1);
+        return Antiderivative::new(n, f, &grid);
+    }
+
+    pub fn grid(&self) -> &[f64] {
+        return &self.xs;
+    }
+
+    // F at the grid points
+    pub fn values(&self) -> &[f64] {
+        return &self.fs;
+    }
+
+    // ∫ f over the whole grid
+    pub fn total(&self) -> f64 {
+        return *self.fs.last().unwrap();
+    }
+
+    // the panel [xs[i], xs[i+1]] holding x
+    fn panel(&self, x: f64) -> usize {
+        let first = self.xs[0];
+        let last = *self.xs.last().unwrap();
+        assert!(
+            (first..=last).contains(&x),
+            "{x} is outside the grid [{first}, {last}]"
+        );
+        return self
+            .xs
+            .partition_point(|xi| *xi <= x)
+            .clamp(1, self.xs.len() - 1)
+            - 1;
+    }
+
+    // F and F' on panel i at x
+    fn hermite(&self, i: usize, x: f64) -> (f64, f64) {
+        let h = self.xs[i + 1] - self.xs[i];
+        let t = (x - self.xs[i]) / h;
+        let (f0, f1) = (self.fs[i], self.fs[i + 1]);
+        let (d0, d1) = (h * self.ys[i], h * self.ys[i + 1]);
+        let t2 = t * t;
+        let t3 = t2 * t;
+        let value = (2.0 * t3 - 3.0 * t2 + 1.0) * f0
+            + (t3 - 2.0 * t2 + t) * d0
+            + (-2.0 * t3 + 3.0 * t2) * f1
+            + (t3 - t2) * d1;
+        let slope = ((6.0 * t2 - 6.0 * t) * (f0 - f1)
+            + (3.0 * t2 - 4.0 * t + 1.0) * d0
+            + (3.0 * t2 - 2.0 * t) * d1)
+            / h;
+        return (value, slope);
+    }
+
+    // F(x) = ∫_x0^x f, for x on the grid's range
+    pub fn eval(&self, x: f64) -> f64 {
+        return self.hermite(self.panel(x), x).0;
+    }
+
+    // the x with F(x) = y, for 0 <= y <= total(); F must be nondecreasing (f >= 0)
+    pub fn inverse(&self, y: f64) -> f64 {
+        assert!(self.monotone, "F must be nondecreasing to invert");
+        assert!(
+            (0.0..=self.total()).contains(&y),
+            "{y} is outside [0, {}]",
+            self.total()
+        );
+        let i = self
+            .fs
+            .partition_point(|fi| *fi < y)
+            .clamp(1, self.fs.len() - 1)
+            - 1;
+        // Newton from the secant guess, falling back to bisection whenever it leaves the bracket
+        let (mut lo, mut hi) = (self.xs[i], self.xs[i + 1]);
+        let span = self.fs[i + 1] - self.fs[i];
+        if span <= 0.0 {
+            return lo;
+        }
+        let mut x = lo + (hi - lo) * (y - self.fs[i]) / span;
+        for _ in 0..100 {
+            let (value, slope) = self.hermite(i, x);
+            if value < y {
+                lo = x;
+            } else {
+                hi = x;
+            }
+            let mut next = x - (value - y) / slope;
+            if !(lo..=hi).contains(&next) {
+                next = (lo + hi) / 2.0;
+            }
+            let tol = 4.0 * f64::EPSILON * x.abs();
+            if (next - x).abs() <= tol || hi - lo <= tol {
+                return next;
+            }
+            x = next;
+        }
+        return x;
+    }
+}
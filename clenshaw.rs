@@ -0,0 +1,182 @@
+// SPDX-License-Identifier: MIT
+// Clenshaw-Curtis and Fejér rules. The weights are the (type I/II/III) DCT of the Chebyshev
+// moments ∫ T_2j = -2/(4j^2 - 1), done directly in O(n^2) since they're cached per (rule, n).
+// Nodes are cos(theta), so they run from 1 down to -1. They're computed as sin(pi/2 - theta), with
+// pi/2 - theta written over a common integer denominator, which keeps them exactly symmetric (and
+// the centre exactly 0) and makes nested rules share nodes bit for bit.
+
+use crate::quadgl::AdaptiveResult;
+use crate::value::{Vector, weighted_sum};
+use std::collections::HashMap;
+use std::f64::consts::PI;
+use std::sync::{LazyLock, Mutex};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Rule {
+    // n points including both endpoints, theta_k = k pi / (n - 1)
+    ClenshawCurtis,
+    // n points at the Chebyshev roots, theta_k = (2k + 1) pi / 2n
+    Fejer1,
+    // n interior points, theta_k = (k + 1) pi / (n + 1)
+    Fejer2,
+}
+
+type Nodes = (&'static [f64], &'static [f64]);
+
+static CACHE: LazyLock<Mutex<HashMap<(Rule, usize), Nodes>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+fn generate(rule: Rule, n: usize) -> (Vec<f64>, Vec<f64>) {
+    let mut xs: Vec<f64> = Vec::with_capacity(n);
+    let mut ws: Vec<f64> = Vec::with_capacity(n);
+    let nf = n as f64;
+    for k in 0..n {
+        let kf = k as f64;
+        let (x, w) = match rule {
+            Rule::ClenshawCurtis => {
+                let m = nf - 1.0;
+                let theta = kf * PI / m;
+                let mut s: f64 = 1.0;
+                for j in 1..=(n - 1) / 2 {
+                    let j = j as f64;
+                    let b = if 2.0 * j == m { 1.0 } else { 2.0 };
+                    s -= b * (2.0 * j * theta).cos() / (4.0 * j * j - 1.0);
+                }
+                let c = if k == 0 || k == n - 1 { 1.0 } else { 2.0 };
+                let x = ((m - 2.0 * kf) * PI / (2.0 * m)).sin();
+                (x, c * s / m)
+            }
+            Rule::Fejer1 => {
+                let theta = (2.0 * kf + 1.0) * PI / (2.0 * nf);
+                let mut s: f64 = 1.0;
+                for j in 1..=n / 2 {
+                    let j = j as f64;
+                    s -= 2.0 * (2.0 * j * theta).cos() / (4.0 * j * j - 1.0);
+                }
+                let x = ((nf - 2.0 * kf - 1.0) * PI / (2.0 * nf)).sin();
+                (x, 2.0 * s / nf)
+            }
+            Rule::Fejer2 => {
+                let theta = (kf + 1.0) * PI / (nf + 1.0);
+                let mut s: f64 = 0.0;
+                for j in 1..=n.div_ceil(2) {
+                    let j = (2 * j - 1) as f64;
+                    s += (j * theta).sin() / j;
+                }
+                let x = ((nf - 2.0 * kf - 1.0) * PI / (2.0 * (nf + 1.0))).sin();
+                (x, 4.0 * theta.sin() * s / (nf + 1.0))
+            }
+        };
+        xs.push(x);
+        ws.push(w);
+    }
+    return (xs, ws);
+}
+
+fn nodes(rule: Rule, n: usize) -> Nodes {
+    assert!(n >= if rule == Rule::ClenshawCurtis { 2 } else { 1 });
+    let mut cache = CACHE.lock().unwrap();
+    return *cache.entry((rule, n)).or_insert_with(|| {
+        let (xs, ws) = generate(rule, n);
+        (xs.leak(), ws.leak())
+    });
+}
+
+#[inline]
+pub fn xs(rule: Rule, n: usize) -> &'static [f64] {
+    return nodes(rule, n).0;
+}
+
+#[inline]
+pub fn ws(rule: Rule, n: usize) -> &'static [f64] {
+    return nodes(rule, n).1;
+}
+
+pub fn integrate(rule: Rule, n: usize, f: impl Fn(f64) -> f64, x0: f64, x1: f64) -> f64 {
+    let half = (x1 - x0) / 2.0;
+    let mid = (x1 + x0) / 2.0;
+    let mut ret: f64 = 0.0;
+    let ws = ws(rule, n);
+    let xs = xs(rule, n);
+    for i in 0..n {
+        ret += ws[i] * f(mid + half * xs[i]);
+    }
+    return half * ret;
+}
+
+// stop refining past this many points; the weights are O(n^2) to build
+const MAX_POINTS: usize = 1 << 13;
+
+// nested refinement: Clenshaw-Curtis and Fejér 2 double (n -> 2n - 1 and 2n + 1), Fejér 1
+// triples, and every old node is a node of the new rule, so only the new ones get evaluated. The
+// error estimate is the difference between the last two levels.
+pub fn integrate_nested<T: Vector>(
+    rule: Rule,
+    f: impl Fn(f64) -> T,
+    x0: f64,
+    x1: f64,
+    abs_tol: f64,
+    rel_tol: f64,
+) -> AdaptiveResult<T> {
+    let half = (x1 - x0) / 2.0;
+    let mid = (x1 + x0) / 2.0;
+    let mut n: usize = match rule {
+        Rule::ClenshawCurtis => 3,
+        Rule::Fejer1 | Rule::Fejer2 => 1,
+    };
+    let mut fs = xs(rule, n)
+        .iter()
+        .map(|x| f(mid + half * x))
+        .collect::<Vec<T>>();
+    let mut evaluations = n;
+    let mut value = weighted_sum(ws(rule, n), &fs).scale(half);
+    let mut error = value.map(|_| f64::INFINITY);
+    let mut level = 0;
+    loop {
+        let (next, stride, offset) = match rule {
+            Rule::ClenshawCurtis => (2 * n - 1, 2, 0),
+            Rule::Fejer1 => (3 * n, 3, 1),
+            Rule::Fejer2 => (2 * n + 1, 2, 1),
+        };
+        if next > MAX_POINTS {
+            return AdaptiveResult {
+                value,
+                error,
+                evaluations,
+                converged: false,
+            };
+        }
+        let xs = xs(rule, next);
+        let mut new_fs: Vec<T> = Vec::with_capacity(next);
+        for (i, x) in xs.iter().enumerate() {
+            if i >= offset && (i - offset) % stride == 0 {
+                new_fs.push(fs[(i - offset) / stride].clone());
+            } else {
+                new_fs.push(f(mid + half * x));
+                evaluations += 1;
+            }
+        }
+        n = next;
+        fs = new_fs;
+        level += 1;
+        let prev = value;
+        value = weighted_sum(ws(rule, n), &fs).scale(half);
+        error = value.clone();
+        for (e, p) in error.components_mut().iter_mut().zip(prev.components()) {
+            *e = (*e - p).abs();
+        }
+        let converged = value
+            .components()
+            .iter()
+            .zip(error.components())
+            .all(|(v, e)| *e <= abs_tol.max(rel_tol * v.abs()));
+        if level >= 2 && converged {
+            return AdaptiveResult {
+                value,
+                error,
+                evaluations,
+                converged: true,
+            };
+        }
+    }
+}
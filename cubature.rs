@@ -0,0 +1,202 @@
+// SPDX-License-Identifier: MIT
+// multi-dimensional integration. Tensor products of Gauss-Legendre rules, one order per axis,
+// done as iterated integrals so that inner limits may depend on the outer coordinates; and Smolyak
+// sparse grids for when the tensor product has too many points.
+
+use crate::clenshaw;
+use crate::quadgl;
+use std::collections::HashMap;
+
+// limits for axis k, given the coordinates already fixed on axes 0..k
+pub type Limits<'a> = &'a dyn Fn(&[f64]) -> (f64, f64);
+
+fn _iterated_impl(
+    ns: &[usize],
+    f: &impl Fn(&[f64]) -> f64,
+    limits: &[Limits],
+    x: &mut Vec<f64>,
+) -> f64 {
+    let k = x.len();
+    if k == ns.len() {
+        return f(x);
+    }
+    let (x0, x1) = limits[k](x);
+    let half = (x1 - x0) / 2.0;
+    let mid = (x1 + x0) / 2.0;
+    let ws = quadgl::ws(ns[k]);
+    let xs = quadgl::xs(ns[k]);
+    let mut ret: f64 = 0.0;
+    for i in 0..ns[k] {
+        x.push(mid + half * xs[i]);
+        ret += ws[i] * _iterated_impl(ns, f, limits, x);
+        x.pop();
+    }
+    return half * ret;
+}
+
+// ∫ ... ∫ f(x) dx_(d-1) ... dx_0, with ns[k] points along axis k and limits[k] giving that axis'
+// range in terms of x_0..x_(k-1)
+pub fn integrate_iterated(ns: &[usize], f: impl Fn(&[f64]) -> f64, limits: &[Limits]) -> f64 {
+    assert_eq!(ns.len(), limits.len());
+    return _iterated_impl(ns, &f, limits, &mut Vec::with_capacity(ns.len()));
+}
+
+// the axis-aligned box [lo_0, hi_0] x ... x [lo_(d-1), hi_(d-1)]
+pub fn integrate_box(ns: &[usize], f: impl Fn(&[f64]) -> f64, lo: &[f64], hi: &[f64]) -> f64 {
+    assert!(ns.len() == lo.len() && ns.len() == hi.len());
+    let limits = (0..ns.len())
+        .map(|k| move |_: &[f64]| (lo[k], hi[k]))
+        .collect::<Vec<_>>();
+    let limits = limits.iter().map(|l| l as Limits).collect::<Vec<Limits>>();
+    return integrate_iterated(ns, f, &limits);
+}
+
+// ∫_x0^x1 ∫_g1(x)^g2(x) f(x, y) dy dx
+pub fn integrate_2d(
+    nx: usize,
+    ny: usize,
+    f: impl Fn(f64, f64) -> f64,
+    x0: f64,
+    x1: f64,
+    g1: impl Fn(f64) -> f64,
+    g2: impl Fn(f64) -> f64,
+) -> f64 {
+    return quadgl::integrate(
+        nx,
+        |x| quadgl::integrate(ny, |y| f(x, y), g1(x), g2(x)),
+        x0,
+        x1,
+    );
+}
+
+// 1-D rule families for the sparse grids, by level l >= 1
+#[derive(Clone, Copy, Debug)]
+pub enum SparseRule {
+    // nested, 1 then 2^(l-1) + 1 points
+    ClenshawCurtis,
+    // nested, 2^l - 1 points
+    Fejer2,
+    // not nested, l points; more points overall, but higher degree per point
+    GaussLegendre,
+}
+
+// level l of a family as (node, weight) pairs on [0, 1]
+fn sparse_rule_1d(rule: SparseRule, l: usize) -> Vec<(f64, f64)> {
+    let (xs, ws): (&[f64], &[f64]) = match rule {
+        SparseRule::ClenshawCurtis if l == 1 => (&[0.0], &[2.0]),
+        SparseRule::ClenshawCurtis => {
+            let n = (1 << (l - 1)) + 1;
+            (
+                clenshaw::xs(clenshaw::Rule::ClenshawCurtis, n),
+                clenshaw::ws(clenshaw::Rule::ClenshawCurtis, n),
+            )
+        }
+        SparseRule::Fejer2 => {
+            let n = (1 << l) - 1;
+            (
+                clenshaw::xs(clenshaw::Rule::Fejer2, n),
+                clenshaw::ws(clenshaw::Rule::Fejer2, n),
+            )
+        }
+        SparseRule::GaussLegendre => (quadgl::xs(l), quadgl::ws(l)),
+    };
+    return xs
+        .iter()
+        .zip(ws)
+        .map(|(x, w)| (0.5 + 0.5 * x, 0.5 * w))
+        .collect();
+}
+
+// all multi-indices l in N^d, l_i >= 1, with lo <= |l| <= hi
+fn multi_indices(d: usize, lo: usize, hi: usize) -> Vec<Vec<usize>> {
+    fn recurse(
+        d: usize,
+        lo: usize,
+        hi: usize,
+        cur: &mut Vec<usize>,
+        sum: usize,
+        out: &mut Vec<Vec<usize>>,
+    ) {
+        if cur.len() == d {
+            if sum >= lo {
+                out.push(cur.clone());
+            }
+            return;
+        }
+        // leave at least 1 for each remaining axis
+        let rest = d - cur.len() - 1;
+        for l in 1..=(hi - sum - rest) {
+            cur.push(l);
+            recurse(d, lo, hi, cur, sum + l, out);
+            cur.pop();
+        }
+    }
+    let mut out: Vec<Vec<usize>> = Vec::new();
+    recurse(d, lo, hi, &mut Vec::with_capacity(d), 0, &mut out);
+    return out;
+}
+
+fn binomial(n: usize, k: usize) -> f64 {
+    return (0..k).fold(1.0, |acc, i| acc * ((n - i) as f64) / ((i + 1) as f64));
+}
+
+// Smolyak's combination technique over [0, 1]^d:
+//     A(q, d) = Σ_(q-d+1 <= |l| <= q) (-1)^(q-|l|) C(d-1, q-|l|) (U^l_1 ⊗ ... ⊗ U^l_d)
+// with q = d + level. Points shared between terms are merged before f is called, so for the
+// nested families each point is evaluated once. Returns (number of points, value).
+pub fn integrate_sparse(
+    rule: SparseRule,
+    d: usize,
+    level: usize,
+    f: impl Fn(&[f64]) -> f64,
+) -> (usize, f64) {
+    assert!(d >= 1);
+    let q = d + level;
+    let rules = (0..=level + 1)
+        .map(|l| {
+            if l == 0 {
+                Vec::new()
+            } else {
+                sparse_rule_1d(rule, l)
+            }
+        })
+        .collect::<Vec<_>>();
+    let mut grid: HashMap<Vec<u64>, f64> = HashMap::new();
+    for l in multi_indices(d, (q + 1).saturating_sub(d).max(d), q) {
+        let k = q - l.iter().sum::<usize>();
+        let c = if k.is_multiple_of(2) { 1.0 } else { -1.0 } * binomial(d - 1, k);
+        // walk the tensor product of rules[l_0] x ... x rules[l_(d-1)] like an odometer
+        let mut idx: Vec<usize> = vec![0; d];
+        loop {
+            let mut w = c;
+            let mut key: Vec<u64> = Vec::with_capacity(d);
+            for (i, &j) in idx.iter().enumerate() {
+                let (x, wi) = rules[l[i]][j];
+                w *= wi;
+                key.push(x.to_bits());
+            }
+            *grid.entry(key).or_insert(0.0) += w;
+            let mut axis = 0;
+            while axis < d {
+                idx[axis] += 1;
+                if idx[axis] < rules[l[axis]].len() {
+                    break;
+                }
+                idx[axis] = 0;
+                axis += 1;
+            }
+            if axis == d {
+                break;
+            }
+        }
+    }
+    let mut ret: f64 = 0.0;
+    let mut x: Vec<f64> = vec![0.0; d];
+    for (key, w) in grid.iter() {
+        for (xi, k) in x.iter_mut().zip(key) {
+            *xi = f64::from_bits(*k);
+        }
+        ret += w * f(&x);
+    }
+    return (grid.len(), ret);
+}
@@ -0,0 +1,259 @@
+// SPDX-License-Identifier: MIT
+// double-double arithmetic: a value is the unevaluated sum hi + lo with |lo| <= ulp(hi)/2, good for
+// about 32 significant digits. The algorithms are the usual error-free transformations (Dekker,
+// Knuth; see Hida, Li & Bailey's QD library), with fma for the exact products.
+
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct DoubleDouble {
+    pub hi: f64,
+    pub lo: f64,
+}
+
+// a + b = s + e exactly
+#[inline]
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    let bb = s - a;
+    return (s, (a - (s - bb)) + (b - bb));
+}
+
+// the same, given |a| >= |b|
+#[inline]
+fn quick_two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    return (s, b - (s - a));
+}
+
+// a * b = p + e exactly
+#[inline]
+fn two_prod(a: f64, b: f64) -> (f64, f64) {
+    let p = a * b;
+    return (p, a.mul_add(b, -p));
+}
+
+pub const PI: DoubleDouble = DoubleDouble {
+    hi: std::f64::consts::PI,
+    lo: 1.2246467991473532e-16,
+};
+
+impl DoubleDouble {
+    pub const ZERO: DoubleDouble = DoubleDouble { hi: 0.0, lo: 0.0 };
+    pub const ONE: DoubleDouble = DoubleDouble { hi: 1.0, lo: 0.0 };
+
+    pub fn new(hi: f64, lo: f64) -> DoubleDouble {
+        let (hi, lo) = two_sum(hi, lo);
+        return DoubleDouble { hi, lo };
+    }
+
+    pub fn to_f64(self) -> f64 {
+        return self.hi + self.lo;
+    }
+
+    pub fn abs(self) -> DoubleDouble {
+        return if self.hi < 0.0 { -self } else { self };
+    }
+
+    pub fn floor(self) -> DoubleDouble {
+        let hi = self.hi.floor();
+        if hi == self.hi {
+            // hi is already an integer, so only lo needs rounding
+            return DoubleDouble::new(hi, self.lo.floor());
+        }
+        return DoubleDouble { hi, lo: 0.0 };
+    }
+
+    pub fn powi(self, n: i32) -> DoubleDouble {
+        let mut ret = DoubleDouble::ONE;
+        let mut base = self;
+        let mut k = n.unsigned_abs();
+        while k > 0 {
+            if k & 1 == 1 {
+                ret = ret * base;
+            }
+            base = base * base;
+            k >>= 1;
+        }
+        return if n < 0 { DoubleDouble::ONE / ret } else { ret };
+    }
+
+    // sin and cos of r for |r| <= pi/4, by Taylor series
+    fn sin_cos_reduced(r: DoubleDouble) -> (DoubleDouble, DoubleDouble) {
+        let r2 = r * r;
+        let (mut s, mut c) = (r, DoubleDouble::ONE);
+        let (mut ts, mut tc) = (r, DoubleDouble::ONE);
+        for i in 1..=30 {
+            let i = i as f64;
+            ts = -ts * r2 / ((2.0 * i) * (2.0 * i + 1.0));
+            tc = -tc * r2 / ((2.0 * i - 1.0) * (2.0 * i));
+            s = s + ts;
+            c = c + tc;
+            if ts.hi.abs() < 1E-34 && tc.hi.abs() < 1E-34 {
+                break;
+            }
+        }
+        return (s, c);
+    }
+
+    pub fn sin_cos(self) -> (DoubleDouble, DoubleDouble) {
+        // x = k pi/2 + r; only accurate while k pi/2 fits comfortably in a double-double
+        let half_pi = PI * 0.5;
+        let k = (self / half_pi).to_f64().round();
+        let r = self - half_pi * k;
+        let (s, c) = DoubleDouble::sin_cos_reduced(r);
+        return match (k as i64).rem_euclid(4) {
+            0 => (s, c),
+            1 => (c, -s),
+            2 => (-s, -c),
+            _ => (-c, s),
+        };
+    }
+
+    pub fn sin(self) -> DoubleDouble {
+        return self.sin_cos().0;
+    }
+
+    pub fn cos(self) -> DoubleDouble {
+        return self.sin_cos().1;
+    }
+}
+
+impl From<f64> for DoubleDouble {
+    fn from(x: f64) -> DoubleDouble {
+        return DoubleDouble { hi: x, lo: 0.0 };
+    }
+}
+
+impl fmt::Display for DoubleDouble {
+    // 32 significant digits in scientific notation
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if !self.hi.is_finite() || self.hi == 0.0 {
+            return write!(f, "{:e}", self.hi);
+        }
+        let mut x = self.abs();
+        let mut e = x.hi.log10().floor() as i32;
+        x = x / DoubleDouble::from(10.0).powi(e);
+        // the f64 estimate of the exponent can be off by one either way
+        if x.hi >= 10.0 {
+            x = x / 10.0;
+            e += 1;
+        } else if x.hi < 1.0 {
+            x = x * 10.0;
+            e -= 1;
+        }
+        let mut digits: Vec<u8> = Vec::with_capacity(33);
+        for _ in 0..33 {
+            let d = x.floor().hi.clamp(0.0, 9.0);
+            digits.push(d as u8);
+            x = (x - d) * 10.0;
+        }
+        // round to 32 digits
+        if digits[32] >= 5 {
+            let mut i = 31;
+            loop {
+                digits[i] += 1;
+                if digits[i] < 10 {
+                    break;
+                }
+                digits[i] = 0;
+                if i == 0 {
+                    digits.insert(0, 1);
+                    e += 1;
+                    break;
+                }
+                i -= 1;
+            }
+        }
+        let digits = digits[..32]
+            .iter()
+            .map(|d| char::from(b'0' + d))
+            .collect::<String>();
+        let sign = if self.hi < 0.0 { "-" } else { "" };
+        return write!(f, "{sign}{}.{}e{e}", &digits[..1], &digits[1..]);
+    }
+}
+
+impl Neg for DoubleDouble {
+    type Output = DoubleDouble;
+    fn neg(self) -> DoubleDouble {
+        return DoubleDouble {
+            hi: -self.hi,
+            lo: -self.lo,
+        };
+    }
+}
+
+impl Add for DoubleDouble {
+    type Output = DoubleDouble;
+    fn add(self, o: DoubleDouble) -> DoubleDouble {
+        let (s, e) = two_sum(self.hi, o.hi);
+        let (t, f) = two_sum(self.lo, o.lo);
+        let (s, e) = quick_two_sum(s, e + t);
+        let (hi, lo) = quick_two_sum(s, e + f);
+        return DoubleDouble { hi, lo };
+    }
+}
+
+impl Sub for DoubleDouble {
+    type Output = DoubleDouble;
+    fn sub(self, o: DoubleDouble) -> DoubleDouble {
+        return self + (-o);
+    }
+}
+
+impl Mul for DoubleDouble {
+    type Output = DoubleDouble;
+    fn mul(self, o: DoubleDouble) -> DoubleDouble {
+        let (p, e) = two_prod(self.hi, o.hi);
+        let (hi, lo) = quick_two_sum(p, e + (self.hi * o.lo + self.lo * o.hi));
+        return DoubleDouble { hi, lo };
+    }
+}
+
+impl Div for DoubleDouble {
+    type Output = DoubleDouble;
+    fn div(self, o: DoubleDouble) -> DoubleDouble {
+        // long division, one double's worth of quotient at a time
+        let q1 = self.hi / o.hi;
+        let r = self - o * q1;
+        let q2 = r.hi / o.hi;
+        let r = r - o * q2;
+        let q3 = r.hi / o.hi;
+        let (hi, lo) = quick_two_sum(q1, q2);
+        return DoubleDouble { hi, lo } + q3;
+    }
+}
+
+impl Add<f64> for DoubleDouble {
+    type Output = DoubleDouble;
+    fn add(self, o: f64) -> DoubleDouble {
+        let (s, e) = two_sum(self.hi, o);
+        let (hi, lo) = quick_two_sum(s, e + self.lo);
+        return DoubleDouble { hi, lo };
+    }
+}
+
+impl Sub<f64> for DoubleDouble {
+    type Output = DoubleDouble;
+    fn sub(self, o: f64) -> DoubleDouble {
+        return self + (-o);
+    }
+}
+
+impl Mul<f64> for DoubleDouble {
+    type Output = DoubleDouble;
+    fn mul(self, o: f64) -> DoubleDouble {
+        let (p, e) = two_prod(self.hi, o);
+        let (hi, lo) = quick_two_sum(p, e + self.lo * o);
+        return DoubleDouble { hi, lo };
+    }
+}
+
+impl Div<f64> for DoubleDouble {
+    type Output = DoubleDouble;
+    fn div(self, o: f64) -> DoubleDouble {
+        return self / DoubleDouble::from(o);
+    }
+}
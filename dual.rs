@@ -0,0 +1,195 @@
+// SPDX-License-Identifier: MIT
+// dual numbers re + du ε with ε^2 = 0, for forward-mode derivatives: write the integrand in terms
+// of Dual::var(p) for the parameter p of interest, and the du part of the integral is its
+// derivative with respect to p.
+
+use crate::value::Value;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Dual {
+    pub re: f64,
+    pub du: f64,
+}
+
+impl Dual {
+    pub fn new(re: f64, du: f64) -> Dual {
+        return Dual { re, du };
+    }
+
+    // the variable we're differentiating with respect to
+    pub fn var(re: f64) -> Dual {
+        return Dual { re, du: 1.0 };
+    }
+
+    pub fn constant(re: f64) -> Dual {
+        return Dual { re, du: 0.0 };
+    }
+
+    // f(re) + f'(re) du ε
+    fn chain(self, f: f64, df: f64) -> Dual {
+        return Dual {
+            re: f,
+            du: df * self.du,
+        };
+    }
+
+    pub fn exp(self) -> Dual {
+        let e = self.re.exp();
+        return self.chain(e, e);
+    }
+
+    pub fn ln(self) -> Dual {
+        return self.chain(self.re.ln(), 1.0 / self.re);
+    }
+
+    pub fn sqrt(self) -> Dual {
+        let s = self.re.sqrt();
+        return self.chain(s, 0.5 / s);
+    }
+
+    pub fn sin(self) -> Dual {
+        return self.chain(self.re.sin(), self.re.cos());
+    }
+
+    pub fn cos(self) -> Dual {
+        return self.chain(self.re.cos(), -self.re.sin());
+    }
+
+    pub fn powi(self, n: i32) -> Dual {
+        return self.chain(self.re.powi(n), (n as f64) * self.re.powi(n - 1));
+    }
+
+    pub fn powf(self, p: f64) -> Dual {
+        return self.chain(self.re.powf(p), p * self.re.powf(p - 1.0));
+    }
+}
+
+impl Value for Dual {
+    #[inline]
+    fn scale(&self, w: f64) -> Self {
+        return Dual {
+            re: w * self.re,
+            du: w * self.du,
+        };
+    }
+
+    #[inline]
+    fn add_scaled(&mut self, w: f64, other: &Self) {
+        self.re += w * other.re;
+        self.du += w * other.du;
+    }
+}
+
+impl Neg for Dual {
+    type Output = Dual;
+    fn neg(self) -> Dual {
+        return Dual {
+            re: -self.re,
+            du: -self.du,
+        };
+    }
+}
+
+impl Add for Dual {
+    type Output = Dual;
+    fn add(self, o: Dual) -> Dual {
+        return Dual {
+            re: self.re + o.re,
+            du: self.du + o.du,
+        };
+    }
+}
+
+impl Sub for Dual {
+    type Output = Dual;
+    fn sub(self, o: Dual) -> Dual {
+        return Dual {
+            re: self.re - o.re,
+            du: self.du - o.du,
+        };
+    }
+}
+
+impl Mul for Dual {
+    type Output = Dual;
+    fn mul(self, o: Dual) -> Dual {
+        return Dual {
+            re: self.re * o.re,
+            du: self.du * o.re + self.re * o.du,
+        };
+    }
+}
+
+impl Div for Dual {
+    type Output = Dual;
+    fn div(self, o: Dual) -> Dual {
+        return Dual {
+            re: self.re / o.re,
+            du: (self.du * o.re - self.re * o.du) / (o.re * o.re),
+        };
+    }
+}
+
+// mixing with plain f64 constants
+impl Add<f64> for Dual {
+    type Output = Dual;
+    fn add(self, o: f64) -> Dual {
+        return self + Dual::constant(o);
+    }
+}
+
+impl Sub<f64> for Dual {
+    type Output = Dual;
+    fn sub(self, o: f64) -> Dual {
+        return self - Dual::constant(o);
+    }
+}
+
+impl Mul<f64> for Dual {
+    type Output = Dual;
+    fn mul(self, o: f64) -> Dual {
+        return Dual {
+            re: self.re * o,
+            du: self.du * o,
+        };
+    }
+}
+
+impl Div<f64> for Dual {
+    type Output = Dual;
+    fn div(self, o: f64) -> Dual {
+        return Dual {
+            re: self.re / o,
+            du: self.du / o,
+        };
+    }
+}
+
+impl Mul<Dual> for f64 {
+    type Output = Dual;
+    fn mul(self, o: Dual) -> Dual {
+        return o * self;
+    }
+}
+
+impl Add<Dual> for f64 {
+    type Output = Dual;
+    fn add(self, o: Dual) -> Dual {
+        return o + self;
+    }
+}
+
+impl Sub<Dual> for f64 {
+    type Output = Dual;
+    fn sub(self, o: Dual) -> Dual {
+        return Dual::constant(self) - o;
+    }
+}
+
+impl Div<Dual> for f64 {
+    type Output = Dual;
+    fn div(self, o: Dual) -> Dual {
+        return Dual::constant(self) / o;
+    }
+}
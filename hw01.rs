@@ -1,12 +1,29 @@
 // SPDX-License-Identifier: MIT
+#![allow(clippy::needless_return)]
 extern crate plotters;
 extern crate statrs;
 
+use integrator::{Budget, Tolerance};
 use plotters::prelude::*;
 use statrs::function::factorial::factorial;
 use std::f64::consts::PI;
+use summation::{Accumulator, Summation};
 
+mod antiderivative;
+mod clenshaw;
+mod cubature;
+mod dd;
+mod dual;
+mod integrator;
+mod montecarlo;
+mod oscillatory;
 mod quadgl;
+mod romberg;
+mod summation;
+mod tabulated;
+mod tanhsinh;
+mod trapezoid;
+mod value;
 
 // for my diagnostics only
 #[allow(dead_code)]
@@ -15,11 +32,17 @@ fn type_of<T>(_: T) -> &'static str {
 }
 
 fn exponential_series(n: u64, x: f64) -> f64 {
-    let mut ret: f64 = 1.0;
+    return exponential_series_summed(n, x, Summation::Naive).0;
+}
+
+// the same partial sum with a choice of summation; returns (ret, rounding error bound on the sum)
+fn exponential_series_summed(n: u64, x: f64, method: Summation) -> (f64, f64) {
+    let mut acc = Accumulator::new(method);
+    acc.add(1.0);
     for j in 1..=n {
-        ret += x.powi(j.try_into().unwrap()) / factorial(j);
+        acc.add(x.powi(j.try_into().unwrap()) / factorial(j));
     }
-    return ret;
+    return (acc.value(), acc.error());
 }
 
 fn exponential_series_alt(n: u64, x: f64) -> f64 {
@@ -40,66 +63,6 @@ fn ffffocd_sinx(dx: f64, x: f64) -> f64 {
         / (12.0 * dx);
 }
 
-fn trapezoid_integrate(eps: f64, f: fn(f64) -> f64, x0: f64, x1: f64) -> (u64, f64) {
-    let mut subs: u64 = 1;
-    let mut ret: f64 = (x1 - x0) * (f(x0) + f(x1)) / 2.0;
-    let mut error: f64 = f64::INFINITY;
-    while error.abs() >= eps {
-        error = -ret / 3.0;
-        subs *= 2;
-        ret /= 2.0;
-        let h: f64 = (x1 - x0) / (subs as f64);
-        ret += h
-            * (1..subs)
-                .step_by(2)
-                .map(|k| f(x0 + (k as f64) * h))
-                .sum::<f64>();
-        error += ret / 3.0;
-        if subs > (u16::MAX as u64) {
-            eprintln!("maximum iterations hit in trapezoid_integrate; error = {error}");
-            break;
-        }
-    }
-    return (subs, ret);
-}
-
-fn romberg_integrate(eps: f64, f: fn(f64) -> f64, x0: f64, x1: f64) -> (usize, f64) {
-    // If we keep a 2D array for R_(i,m), it's O(n^2) in space; instead, we squash them into one
-    // array `r` (O(n) in space) and keep a record `t` of the index of the last R_(i,1).
-    let mut r: Vec<f64> = Vec::new();
-    let mut t: usize = 0;
-    let mut i: usize = 1;
-    let mut subs: usize = 1;
-    let mut err: f64 = f64::INFINITY;
-    // R_(1,1)
-    r.push((x1 - x0) * (f(x0) + f(x1)) / 2.0);
-    while err.abs() >= eps {
-        i += 1;
-        subs *= 2;
-        let h: f64 = (x1 - x0) / (subs as f64);
-        // R_(i+1, 1)
-        r.push(
-            r[t] / 2.0
-                + h * (1..subs)
-                    .step_by(2)
-                    .map(|k| f(x0 + (k as f64) * h))
-                    .sum::<f64>(),
-        );
-        for m in 1..i {
-            let pre = *(r.last().unwrap());
-            err = (pre - r[t + m - 1]) / (((1_usize << (2 * m)) - 1) as f64);
-            // R_(i, m+1)
-            r.push(pre + err);
-        }
-        t = r.len() - i;
-        if subs > (u16::MAX as usize) {
-            eprintln!("maximum iterations hit in romberg_integrate; err = {err}");
-            break;
-        }
-    }
-    return (subs, r.pop().unwrap());
-}
-
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     // 2(b)
     // ----
@@ -132,18 +95,18 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .build_cartesian_2d((1f64..100f64).log_scale(), (0f64..1000f64).log_scale())?;
     chart.configure_mesh().draw()?;
     chart
-        .draw_series(LineSeries::new(eps_p20.clone(), &BLUE))?
+        .draw_series(LineSeries::new(eps_p20.clone(), BLUE))?
         .label("x=+20")
-        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &BLUE));
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLUE));
     chart
-        .draw_series(LineSeries::new(eps_n20, &RED))?
+        .draw_series(LineSeries::new(eps_n20, RED))?
         .label("x=-20")
-        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &RED));
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], RED));
     chart
         .configure_series_labels()
         .position(SeriesLabelPosition::UpperLeft)
-        .border_style(&BLACK)
-        .background_style(&WHITE.mix(0.8))
+        .border_style(BLACK)
+        .background_style(WHITE.mix(0.8))
         .draw()?;
     f.present()?;
 
@@ -167,27 +130,36 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .build_cartesian_2d((1f64..100f64).log_scale(), (0f64..1000f64).log_scale())?;
     chart.configure_mesh().draw()?;
     chart
-        .draw_series(LineSeries::new(eps_p20, &BLUE))?
+        .draw_series(LineSeries::new(eps_p20, BLUE))?
         .label("x=+20")
-        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &BLUE));
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLUE));
     chart
-        .draw_series(LineSeries::new(eps_n20_alt, &RED))?
+        .draw_series(LineSeries::new(eps_n20_alt, RED))?
         .label("x=-20")
-        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &RED));
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], RED));
     chart
         .configure_series_labels()
         .position(SeriesLabelPosition::UpperLeft)
-        .border_style(&BLACK)
-        .background_style(&WHITE.mix(0.8))
+        .border_style(BLACK)
+        .background_style(WHITE.mix(0.8))
         .draw()?;
     f.present()?;
+    // the direct series at x = -20 is mostly cancellation: the biggest term is ~4e7, so the terms'
+    // own rounding swamps e^-20 whatever order we add them in. The bound covers the summation only.
+    for method in summation::ALL {
+        let (ret, round) = exponential_series_summed(100, -20.0, method);
+        println!(
+            "2c: n = 100, x = -20, {method:?}, ret = {ret:e}, rounding <= {round:e}, exact = {:e}",
+            (-20_f64).exp()
+        );
+    }
 
     // 3(a) (first order)
     // ------------------
     let fofd_err = (1..1000)
         .map(|n| {
             (
-                (0.001_f64 * (n as f64)) as f64,
+                0.001_f64 * (n as f64),
                 (fofd_sinx(0.001_f64 * (n as f64), PI / 4.0) - (PI / 4.0).cos()).abs()
                     / (PI / 4.0).cos(),
             )
@@ -203,7 +175,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .build_cartesian_2d((0.001f64..1f64).log_scale(), (0f64..0.5f64).log_scale())?;
     chart.configure_mesh().x_desc("Δx").draw()?;
     chart
-        .draw_series(LineSeries::new(fofd_err, &BLUE))?
+        .draw_series(LineSeries::new(fofd_err, BLUE))?
         .label("x=-20");
     f.present()?;
 
@@ -212,7 +184,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let socd_err = (1..1000)
         .map(|n| {
             (
-                (0.001_f64 * (n as f64)) as f64,
+                0.001_f64 * (n as f64),
                 (socd_sinx(0.001_f64 * (n as f64), PI / 4.0) - (PI / 4.0).cos()).abs()
                     / (PI / 4.0).cos(),
             )
@@ -228,7 +200,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .build_cartesian_2d((0.001f64..1f64).log_scale(), (0f64..0.5f64).log_scale())?;
     chart.configure_mesh().x_desc("Δx").draw()?;
     chart
-        .draw_series(LineSeries::new(socd_err, &BLUE))?
+        .draw_series(LineSeries::new(socd_err, BLUE))?
         .label("x=-20");
     f.present()?;
 
@@ -237,7 +209,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let ffffocd_err = (1..1000)
         .map(|n| {
             (
-                (0.001_f64 * (n as f64)) as f64,
+                0.001_f64 * (n as f64),
                 (ffffocd_sinx(0.001_f64 * (n as f64), PI / 4.0) - (PI / 4.0).cos()).abs()
                     / (PI / 4.0).cos(),
             )
@@ -252,7 +224,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .caption("ffffocd fractional error", ("Libertinus Serif", 20))
         .build_cartesian_2d((0.001f64..1f64).log_scale(), (0f64..0.5f64).log_scale())?;
     chart.configure_mesh().x_desc("Δx").draw()?;
-    chart.draw_series(LineSeries::new(ffffocd_err, &BLUE))?;
+    chart.draw_series(LineSeries::new(ffffocd_err, BLUE))?;
     f.present()?;
 
     // 4(a)
@@ -275,19 +247,108 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .draw()?;
     chart.draw_series(LineSeries::new(
         (0..10000).map(|x| (0.0001 * (x as f64), inner4(0.0001 * (x as f64)))),
-        &BLUE,
+        BLUE,
     ))?;
     f.present()?;
 
     // 4(b)
     // ----
-    let (subs, ret) = trapezoid_integrate(1E-6, inner4, 0.0, 1.0);
+    let (subs, ret) = trapezoid::integrate(1E-6, inner4, 0.0, 1.0)?;
     println!("4b: subintervals = {subs}, ret = {ret}");
+    // the same from 201 samples only: evenly spaced, then crowded toward 0 where inner4 wiggles
+    let samples = (0..=200)
+        .map(|k| (0.005 * (k as f64), inner4(0.005 * (k as f64))))
+        .collect::<Vec<(f64, f64)>>();
+    println!(
+        "4b: 201 samples, trapezoid = {}, simpson = {}, spline = {}",
+        tabulated::trapezoid(&samples),
+        tabulated::simpson(&samples),
+        tabulated::spline(&samples)
+    );
+    let samples = (0..=200)
+        .map(|k| {
+            (
+                (0.005 * (k as f64)).powi(2),
+                inner4((0.005 * (k as f64)).powi(2)),
+            )
+        })
+        .collect::<Vec<(f64, f64)>>();
+    let running = tabulated::cumulative(tabulated::Method::Spline, &samples);
+    println!(
+        "4b: 201 samples at x = (k/200)^2, simpson = {}, spline = {}, spline up to x = 1/4: {}",
+        tabulated::simpson_nonuniform(&samples),
+        running[200],
+        running[100]
+    );
 
     // 4(c)
     // ----
-    let (subs, ret) = romberg_integrate(1E-6, inner4, 0.0, 1.0);
+    let (subs, ret) = romberg::integrate(1E-6, inner4, 0.0, 1.0)?;
     println!("4c: subintervals = {subs}, ret = {ret}");
+    let tol = Tolerance::new(1E-12, 0.0);
+    for method in summation::ALL {
+        let (subs, ret, _, round) =
+            romberg::integrate_summed(&tol, inner4, 0.0, 1.0, method, &Budget::default())?;
+        println!(
+            "4c: eps = 1e-12, {method:?}, subintervals = {subs}, ret = {ret}, rounding <= {round:e}"
+        );
+    }
+    // the full tableau behind the 1e-6 result: each column converges faster than the one before.
+    // With x = t^2, ∫ sin(sqrt(100x))^2 dx = ∫ 2t sin(10t)^2 dt, which integrates in closed form.
+    let exact_inner4 = 0.5 - 20_f64.sin() / 20.0 + (1.0 - 20_f64.cos()) / 400.0;
+    let (tableau, _, _) = romberg::tableau_summed(
+        &Tolerance::new(1E-6, 0.0),
+        inner4,
+        0.0,
+        1.0,
+        Summation::Naive,
+        &Budget::default(),
+    )?;
+    println!("4c: romberg tableau\n{tableau:.12}");
+    tableau.plot("4c.svg", "4(c) Romberg columns", Some(exact_inner4))?;
+    // a relative tolerance means the same for an integral of size 1e-8 as for one of size 1
+    let tol = Tolerance::new(0.0, 1E-10);
+    let (subs, ret, err, _) = romberg::integrate_summed(
+        &tol,
+        |x| 1E-8 * inner4(x),
+        0.0,
+        1.0,
+        Summation::Naive,
+        &Budget::default(),
+    )?;
+    println!(
+        "4c: 1e-8 inner4, rel_tol = 1e-10, subintervals = {subs}, ret = {ret:e}, err = {err:e}"
+    );
+    // sin(2πx)^2 vanishes at x = 0, 1/2, 1, so the first two levels agree on 0; min_level keeps
+    // going until the grid can see it
+    for min_level in [0, 3] {
+        let tol = Tolerance {
+            min_level,
+            ..Tolerance::new(1E-6, 0.0)
+        };
+        let (subs, ret, _, _) = trapezoid::integrate_summed(
+            &tol,
+            |x| (2.0 * PI * x).sin().powi(2),
+            0.0,
+            1.0,
+            Summation::Naive,
+            &Budget::default(),
+        )?;
+        println!("4c: sin(2πx)^2, min_level = {min_level}, subintervals = {subs}, ret = {ret}");
+    }
+    // nested refinement to 1e-6, and a single 33-point rule of each kind for comparison
+    for rule in [
+        clenshaw::Rule::ClenshawCurtis,
+        clenshaw::Rule::Fejer1,
+        clenshaw::Rule::Fejer2,
+    ] {
+        let res = clenshaw::integrate_nested(rule, inner4, 0.0, 1.0, 1E-6, 0.0);
+        let single = clenshaw::integrate(rule, 33, inner4, 0.0, 1.0);
+        println!(
+            "4c: {rule:?}, ret = {}, err = {:e}, evals = {}, converged = {}, n = 33: {single}",
+            res.value, res.error, res.evaluations, res.converged
+        );
+    }
 
     // 4(d)
     // ----
@@ -296,6 +357,185 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         ret = quadgl::integrate(n, inner4, 0.0, 1.0);
         println!("4d: n = {n}, ret = {ret}");
     }
+    for method in summation::ALL {
+        let (ret, round) = quadgl::integrate_summed(64, inner4, 0.0, 1.0, method);
+        println!("4d: n = 64, {method:?}, ret = {ret}, rounding <= {round:e}");
+    }
+    // the same n = 12 rule with the order fixed at compile time, and on a dual-valued integrand
+    // for d/dk ∫ sin(sqrt(kx))^2 dx at k = 100 in the same pass
+    ret = quadgl::integrate_const::<12>(inner4, 0.0, 1.0);
+    let res = quadgl::integrate_const_value::<12, _>(
+        |x| (dual::Dual::var(100.0) * x).sqrt().sin().powi(2),
+        0.0,
+        1.0,
+    );
+    println!(
+        "4d: const n = 12, ret = {ret}, dual ret = {}, d/dk = {}",
+        res.re, res.du
+    );
+    for m in [1, 2, 4, 8, 16] {
+        ret = quadgl::integrate_composite(8, m, inner4, 0.0, 1.0);
+        println!("4d: n = 8, panels = {m}, ret = {ret}");
+    }
+    let (n, ret, err) = quadgl::integrate_auto(inner4, 0.0, 1.0, 1E-10);
+    println!("4d: auto, n = {n}, ret = {ret}, err = {err:e}");
+    for rule in [quadgl::Kronrod::G7K15, quadgl::Kronrod::G10K21] {
+        let res = quadgl::integrate_adaptive(rule, inner4, 0.0, 1.0, 1E-10, 0.0);
+        println!(
+            "4d: {rule:?}, ret = {}, err = {:e}, evals = {}, converged = {}",
+            res.value, res.error, res.evaluations, res.converged
+        );
+    }
+
+    // in two dimensions: ∫∫ inner4(x) inner4(y) over the square is the 1-D integral squared, and
+    // over the triangle 0 < y < x < 1 it is ∫ (1 - y) inner4(y) dy
+    let ret = cubature::integrate_box(
+        &[20, 20],
+        |x| inner4(x[0]) * inner4(x[1]),
+        &[0.0; 2],
+        &[1.0; 2],
+    );
+    let sq = quadgl::integrate(20, inner4, 0.0, 1.0).powi(2);
+    println!("4d: square, n = 20 x 20, ret = {ret}, 1-D squared = {sq}");
+    let ret = cubature::integrate_2d(20, 20, |_, y| inner4(y), 0.0, 1.0, |_| 0.0, |x| x);
+    let tri = quadgl::integrate(20, |y| (1.0 - y) * inner4(y), 0.0, 1.0);
+    println!("4d: triangle, n = 20 x 20, ret = {ret}, 1-D = {tri}");
+    // in eight, where even 3 points per axis is 6561 evaluations; ∏ e^-x_i has integral (1 - 1/e)^8
+    let f8 = |x: &[f64]| x.iter().map(|xi| (-xi).exp()).product::<f64>();
+    let exact = (1.0 - (-1_f64).exp()).powi(8);
+    let ret = cubature::integrate_box(&[3; 8], f8, &[0.0; 8], &[1.0; 8]);
+    println!(
+        "4d: d = 8, tensor 3^8, points = 6561, err = {:e}",
+        (ret - exact).abs()
+    );
+    for rule in [
+        cubature::SparseRule::ClenshawCurtis,
+        cubature::SparseRule::Fejer2,
+        cubature::SparseRule::GaussLegendre,
+    ] {
+        let (points, ret) = cubature::integrate_sparse(rule, 8, 4, f8);
+        println!(
+            "4d: d = 8, sparse {rule:?}, level = 4, points = {points}, err = {:e}",
+            (ret - exact).abs()
+        );
+    }
+
+    // Monte Carlo on the square from above, against the 1-D Gauss-Legendre result squared, and in
+    // 21 dimensions (all of the Sobol direction numbers) against (1 - 1/e)^21. The errors should
+    // be on the order of the standard errors; stratifying only makes sense in low dimension.
+    let exact21 = (1.0 - (-1_f64).exp()).powi(21);
+    for (d, exact) in [(2, sq), (21, exact21)] {
+        let f = |x: &[f64]| {
+            if d == 2 {
+                inner4(x[0]) * inner4(x[1])
+            } else {
+                x.iter().map(|xi| (-xi).exp()).product::<f64>()
+            }
+        };
+        let (lo, hi) = (vec![0.0; d], vec![1.0; d]);
+        let mut results = vec![
+            ("plain", montecarlo::plain(1 << 14, f, &lo, &hi, 1)),
+            (
+                "halton",
+                montecarlo::quasi(montecarlo::Sequence::Halton, 1 << 11, 8, f, &lo, &hi, 1),
+            ),
+            (
+                "sobol",
+                montecarlo::quasi(montecarlo::Sequence::Sobol, 1 << 11, 8, f, &lo, &hi, 1),
+            ),
+        ];
+        if d == 2 {
+            results.push((
+                "stratified 32^2",
+                montecarlo::stratified(32, 16, f, &lo, &hi, 1),
+            ));
+        }
+        for (name, res) in results {
+            println!(
+                "4d: d = {d}, {name}, evals = {}, ret = {}, std error = {:e}, err = {:e}",
+                res.evaluations,
+                res.value,
+                res.std_error,
+                (res.value - exact).abs()
+            );
+        }
+    }
+    // the same comparison through the common interface; the last one can't reach 1e-12 within its
+    // evaluation budget and says so
+    let integrators: Vec<Box<dyn integrator::Integrator>> = vec![
+        Box::new(integrator::Trapezoid {
+            tol: Tolerance::new(1E-6, 0.0),
+            summation: Summation::Naive,
+            budget: Budget::default(),
+        }),
+        Box::new(integrator::Romberg {
+            tol: Tolerance::new(1E-6, 0.0),
+            summation: Summation::Naive,
+            budget: Budget::default(),
+        }),
+        Box::new(integrator::GaussLegendre { n: 12 }),
+        Box::new(integrator::GaussKronrod {
+            rule: quadgl::Kronrod::G10K21,
+            abs_tol: 1E-10,
+            rel_tol: 0.0,
+        }),
+        Box::new(integrator::ClenshawCurtis {
+            rule: clenshaw::Rule::ClenshawCurtis,
+            abs_tol: 1E-10,
+            rel_tol: 0.0,
+        }),
+        Box::new(integrator::TanhSinh {
+            abs_tol: 1E-10,
+            rel_tol: 0.0,
+        }),
+        Box::new(integrator::Trapezoid {
+            tol: Tolerance::new(1E-12, 0.0),
+            summation: Summation::Naive,
+            budget: Budget {
+                max_evaluations: Some(10000),
+                ..Budget::default()
+            },
+        }),
+    ];
+    for integrator in &integrators {
+        match integrator.integrate(&inner4, 0.0, 1.0) {
+            Ok(res) => println!(
+                "4d: {integrator:?}, ret = {}, err = {:e}, evals = {}, subdivisions = {}, converged = {}",
+                res.value, res.abs_error, res.evaluations, res.subdivisions, res.converged
+            ),
+            Err(e) => println!("4d: {integrator:?}, {e}"),
+        }
+    }
+    // reference value in double-double: with x = t^2 the integrand is 2t sin(10t)^2, which is
+    // smooth, and the closed form is 1/2 - sin(20)/20 + (1 - cos(20))/400
+    let ref_dd = quadgl::integrate_dd(
+        64,
+        |t| t * (t * 10.0).sin().powi(2) * 2.0,
+        0.0.into(),
+        1.0.into(),
+    );
+    let twenty = dd::DoubleDouble::from(20.0);
+    let exact = -twenty.sin() / 20.0 + (-twenty.cos() + 1.0) / 400.0 + 0.5;
+    println!("4d: double-double, n = 64, ret = {ref_dd}, exact = {exact}");
+    // sin(sqrt(100x))^2 = (1 - cos(20 sqrt(x)))/2. Filon does the t = sqrt(x) form, where the
+    // amplitude 2t is linear and so one panel is exact; Levin takes the phase 20 sqrt(x) as is, on
+    // panels graded geometrically toward the branch point at 0.
+    let ret = 0.5 - oscillatory::filon(1, |t| t, 20.0, oscillatory::Weight::Cos, 0.0, 1.0);
+    println!("4d: filon, evals = 3, ret = {ret}");
+    // and the sine weight on the same linear amplitude, ∫ t sin(20t) dt = (sin 20 - 20 cos 20)/400
+    let ret = oscillatory::filon(1, |t| t, 20.0, oscillatory::Weight::Sin, 0.0, 1.0);
+    println!(
+        "4d: filon sin, evals = 3, ret = {ret}, exact = {}",
+        (20_f64.sin() - 20.0 * 20_f64.cos()) / 400.0
+    );
+    let mut points = (0..=8).map(|k| 0.25_f64.powi(8 - k)).collect::<Vec<f64>>();
+    points[0] = 0.0;
+    let ret = oscillatory::levin_breakpoints(12, |_| 1.0, |x| x.sqrt() * 20.0, &points);
+    println!(
+        "4d: levin, evals = {}, ret = {}",
+        12 * (points.len() - 1),
+        0.5 - 0.5 * ret.re
+    );
 
     // 5(a)
     // ----
@@ -314,29 +554,29 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     chart
         .draw_series(LineSeries::new(
             (0..10000).map(|x| (0.001 * (x as f64), phi(2, 0.001 * (x as f64)))),
-            &RED,
+            RED,
         ))?
         .label("a=2")
-        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &RED));
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], RED));
     chart
         .draw_series(LineSeries::new(
             (0..10000).map(|x| (0.001 * (x as f64), phi(3, 0.001 * (x as f64)))),
-            &GREEN,
+            GREEN,
         ))?
         .label("a=3")
-        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &GREEN));
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], GREEN));
     chart
         .draw_series(LineSeries::new(
             (0..10000).map(|x| (0.001 * (x as f64), phi(4, 0.001 * (x as f64)))),
-            &BLUE,
+            BLUE,
         ))?
         .label("a=4")
-        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &BLUE));
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLUE));
     chart
         .configure_series_labels()
         .position(SeriesLabelPosition::UpperRight)
-        .border_style(&BLACK)
-        .background_style(&WHITE.mix(0.8))
+        .border_style(BLACK)
+        .background_style(WHITE.mix(0.8))
         .draw()?;
     f.present()?;
 
@@ -349,10 +589,156 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             * (1.0 - z).powi(-2 - c as i32)
     }
     let mut ret: f64;
+    // all three a in one pass over the nodes
+    let rets = quadgl::integrate(50, |z| [1, 2, 3].map(|c| phiz(c, z)), 0.0, 1.0);
     for c in 1..=3 {
-        ret = quadgl::integrate(50, |z| phiz(c, z), 0.0, 1.0);
-        println!("5d: a = {c}+1, ret = {ret}");
+        println!("5d: a = {c}+1, ret = {}", rets[c as usize - 1]);
+        let (n, ret, err) = quadgl::integrate_auto(|z| phiz(c, z), 0.0, 1.0, 1E-10);
+        println!("5d: a = {c}+1, auto, n = {n}, ret = {ret}, err = {err:e}");
+    }
+    let res = quadgl::integrate_adaptive(
+        quadgl::Kronrod::G10K21,
+        |z| [1, 2, 3].map(|c| phiz(c, z)),
+        0.0,
+        1.0,
+        1E-10,
+        1E-10,
+    );
+    println!(
+        "5d: a = 2..=4, adaptive, ret = {:?}, err = {:?}, evals = {}, converged = {}",
+        res.value, res.error, res.evaluations, res.converged
+    );
+    // or without the change of variables, straight on [0, ∞)
+    for a in 2..=4 {
+        ret = quadgl::integrate_semi_infinite(20, |x| phi(a, x), 0.0, 1.0);
+        println!("5d: a = {a}, laguerre ret = {ret}");
+        ret = quadgl::integrate_laguerre(20, (a - 1) as f64, |_| 1.0, 0.0, 1.0);
+        println!("5d: a = {a}, laguerre (alpha = a-1) ret = {ret}");
+        // x = e^u puts it on the whole line, as ∫ exp(a u - e^u) du
+        ret = quadgl::integrate_whole_line(
+            100,
+            |u| ((a as f64) * u - u.exp()).exp(),
+            (a as f64).ln(),
+            1.0,
+        );
+        println!("5d: a = {a}, hermite ret = {ret}");
+        let res = tanhsinh::integrate(|x| phi(a, x), 0.0, f64::INFINITY, 1E-12, 1E-12);
+        println!(
+            "5d: a = {a}, tanh-sinh ret = {}, err = {:e}, evals = {}, levels = {}, converged = {}",
+            res.value, res.error, res.evaluations, res.levels, res.converged
+        );
+    }
+    // the whole line: sinh-sinh, with the centre node at x = 0
+    for (name, res, exact) in [
+        (
+            "e^(-x^2)",
+            tanhsinh::integrate(
+                |x| (-x * x).exp(),
+                f64::NEG_INFINITY,
+                f64::INFINITY,
+                1E-12,
+                1E-12,
+            ),
+            PI.sqrt(),
+        ),
+        (
+            "1/(1 + x^2)",
+            tanhsinh::integrate(
+                |x| 1.0 / (1.0 + x * x),
+                f64::NEG_INFINITY,
+                f64::INFINITY,
+                1E-12,
+                1E-12,
+            ),
+            PI,
+        ),
+    ] {
+        println!(
+            "5d: ∫ {name} over the line, tanh-sinh ret = {}, exact {exact}, evals = {}, converged = {}",
+            res.value, res.evaluations, res.converged
+        );
+    }
+    // Gauss-Hermite with the normal density as the weight: moments are polynomials, so exact, and
+    // E[e^X] = sqrt(e) converges fast
+    let fourth = quadgl::expectation_normal(3, |x| x.powi(4), 0.0, 1.0);
+    let lognormal = quadgl::expectation_normal(12, f64::exp, 0.0, 1.0);
+    println!(
+        "5d: X ~ N(0, 1), hermite n = 3, E[X^4] = {fourth}; n = 12, E[e^X] = {lognormal}, exact {}",
+        0.5_f64.exp()
+    );
+    // shifted to [1, 2], a = 3/10 puts an x^-0.7 singularity at 1; from x alone, x - 1 has lost
+    // most of its digits by the time tanh-sinh's points get there, so f takes the distance too
+    let shifted = |x: f64, xc: f64| {
+        let t = if xc > 0.0 { xc } else { x - 1.0 };
+        return t.powf(-0.7) * (-t).exp();
+    };
+    let exact = statrs::function::gamma::gamma_lr(0.3, 1.0) * statrs::function::gamma::gamma(0.3);
+    for (how, res) in [
+        (
+            "x",
+            tanhsinh::integrate(|x| shifted(x, 0.0), 1.0, 2.0, 1E-12, 1E-12),
+        ),
+        (
+            "x and x - 1",
+            tanhsinh::integrate_with_distance(shifted, 1.0, 2.0, 1E-12, 1E-12),
+        ),
+    ] {
+        println!(
+            "5d: a = 3/10 on [1, 2], f of {how}, tanh-sinh err = {:e}, evals = {}, converged = {}",
+            (res.value - exact).abs(),
+            res.evaluations,
+            res.converged
+        );
+    }
+    // a = 3/2 has a sqrt(x) at 0, which costs Gauss-Legendre most of its digits; Gauss-Jacobi with
+    // beta = 1/2 puts it in the weight and only has e^-x left to integrate
+    let exact = statrs::function::gamma::gamma_lr(1.5, 1.0) * statrs::function::gamma::gamma(1.5);
+    for n in [4, 8] {
+        let legendre = quadgl::integrate(n, |x| x.sqrt() * (-x).exp(), 0.0, 1.0);
+        let jacobi = quadgl::integrate_jacobi(n, 0.0, 0.5, |x| (-x).exp(), 0.0, 1.0);
+        println!(
+            "5d: a = 3/2, ∫_0^1 phi, n = {n}, legendre err = {:e}, jacobi err = {:e}",
+            (legendre - exact).abs(),
+            (jacobi - exact).abs()
+        );
+    }
+    // the gamma(3) CDF, F(x) = ∫_0^x phi(3, t)/2! dt, tabulated once and then evaluated and inverted
+    let cdf = antiderivative::Antiderivative::uniform(8, |x| phi(3, x) / 2.0, 0.0, 40.0, 200);
+    println!(
+        "5d: a = 3, CDF(2) = {}, expected {}, total = {}",
+        cdf.eval(2.0),
+        statrs::function::gamma::gamma_lr(3.0, 2.0),
+        cdf.total()
+    );
+    // F(0) = 0 by construction, and gamma_lr wants x > 0
+    let worst = cdf
+        .grid()
+        .iter()
+        .zip(cdf.values())
+        .skip(1)
+        .map(|(x, f)| (f - statrs::function::gamma::gamma_lr(3.0, *x)).abs())
+        .fold(0.0, f64::max);
+    println!(
+        "5d: a = 3, max CDF err over the {} grid points = {worst:e}",
+        cdf.grid().len()
+    );
+    for p in [0.1, 0.5, 0.9] {
+        println!("5d: a = 3, quantile {p} = {}", cdf.inverse(p));
     }
+    // principal value with the pole at the peak of phi(2, x)
+    let res = quadgl::integrate_cauchy(
+        quadgl::Kronrod::G10K21,
+        |x| phi(2, x),
+        1.0,
+        0.0,
+        4.0,
+        1E-12,
+        1E-12,
+    );
+    println!(
+        "5d: a = 2, PV ∫_0^4 phi/(x - 1), ret = {}, err = {:e}, evals = {}, converged = {}",
+        res.value, res.error, res.evaluations, res.converged
+    );
 
     Ok(())
 }
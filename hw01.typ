@@ -180,7 +180,7 @@ As instructed:
     runs over _odd numbers_ from 1 to $N_i - 1$.
 ]
 
-#ans[Done] in `hw01.rs` (see `trapezoid_integrate`):
+#ans[Done] in `trapezoid.rs` (see `trapezoid::integrate`):
 
 ```
 4b: subintervals = 4096, ret = 0.455832058278271
@@ -213,7 +213,7 @@ As instructed:
     subintervals necessary compared to the approach of part (b)?
 ]
 
-#ans[Done] in `hw01.rs` (see `romberg_integrate`):
+#ans[Done] in `romberg.rs` (see `romberg::integrate`):
 
 ```
 4c: subintervals = 64, ret = 0.45583249446137863
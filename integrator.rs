@@ -0,0 +1,325 @@
+// SPDX-License-Identifier: MIT
+// one interface over the 1-D integrators, so they can be swapped and compared: each method is a
+// small struct holding its settings (which Debug prints), and every one reports the same
+// IntegrationResult. f comes in as &dyn Fn so that a list of Box<dyn Integrator> works. The
+// modules' own result types (quadgl::AdaptiveResult, tanhsinh::TanhSinhResult) stay for what only
+// they can carry, vector values and levels; here they come back as an IntegrationResult, and a
+// result that didn't converge comes back as the error for whichever limit stopped it.
+
+use crate::clenshaw;
+use crate::quadgl;
+use crate::romberg;
+use crate::summation::Summation;
+use crate::tanhsinh;
+use crate::trapezoid;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Copy, Debug)]
+pub struct IntegrationResult {
+    pub value: f64,
+    pub abs_error: f64,
+    pub evaluations: usize,
+    pub subdivisions: usize,
+    pub converged: bool,
+}
+
+// when an iterative method may stop: once the error estimate is within max(abs_tol, rel_tol |I|),
+// but not before `min_level` refinements (2^min_level subintervals for trapezoid and romberg), since
+// coarse grids can agree by accident, e.g. on periodic integrands sampled only at their zeros
+#[derive(Clone, Copy, Debug)]
+pub struct Tolerance {
+    pub abs_tol: f64,
+    pub rel_tol: f64,
+    pub min_level: u32,
+}
+
+// at least 8 subintervals by default, so that the first coarse grids can't end things by agreeing
+// by accident (on 1 and 2 subintervals sin(2πx)^2 is only sampled at its zeros, for instance)
+const DEFAULT_MIN_LEVEL: u32 = 3;
+
+impl Tolerance {
+    pub fn new(abs_tol: f64, rel_tol: f64) -> Tolerance {
+        return Tolerance {
+            abs_tol,
+            rel_tol,
+            min_level: DEFAULT_MIN_LEVEL,
+        };
+    }
+
+    pub fn met(&self, level: u32, value: f64, error: f64) -> bool {
+        return level >= self.min_level
+            && error.abs() <= self.abs_tol.max(self.rel_tol * value.abs());
+    }
+}
+
+// when an iterative method should give up. The defaults are the old hard-coded cap of 2^16
+// subintervals and no limit on evaluations or time.
+#[derive(Clone, Copy, Debug)]
+pub struct Budget {
+    pub max_subdivisions: usize,
+    pub max_evaluations: Option<usize>,
+    pub max_time: Option<Duration>,
+}
+
+impl Default for Budget {
+    fn default() -> Budget {
+        return Budget {
+            max_subdivisions: 1 << 16,
+            max_evaluations: None,
+            max_time: None,
+        };
+    }
+}
+
+impl Budget {
+    // the first limit that taking the next step (to `subdivisions` and `evaluations` in total)
+    // would break, if any, given the best result so far
+    pub fn check(
+        &self,
+        start: Instant,
+        subdivisions: usize,
+        evaluations: usize,
+        best: IntegrationResult,
+    ) -> Result<(), IntegrationError> {
+        if subdivisions > self.max_subdivisions {
+            return Err(IntegrationError::MaxSubdivisions(best));
+        } else if self.max_evaluations.is_some_and(|m| evaluations > m) {
+            return Err(IntegrationError::MaxEvaluations(best));
+        } else if self.max_time.is_some_and(|t| start.elapsed() > t) {
+            return Err(IntegrationError::OutOfTime(best));
+        }
+        return Ok(());
+    }
+}
+
+// ran out of budget before meeting the tolerance; each carries the best estimate and the error
+// reached by then (with converged = false)
+#[derive(Clone, Copy, Debug)]
+pub enum IntegrationError {
+    MaxSubdivisions(IntegrationResult),
+    MaxEvaluations(IntegrationResult),
+    OutOfTime(IntegrationResult),
+}
+
+impl IntegrationError {
+    pub fn best(&self) -> &IntegrationResult {
+        return match self {
+            IntegrationError::MaxSubdivisions(r)
+            | IntegrationError::MaxEvaluations(r)
+            | IntegrationError::OutOfTime(r) => r,
+        };
+    }
+}
+
+impl fmt::Display for IntegrationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let what = match self {
+            IntegrationError::MaxSubdivisions(_) => "maximum subdivisions",
+            IntegrationError::MaxEvaluations(_) => "maximum evaluations",
+            IntegrationError::OutOfTime(_) => "time limit",
+        };
+        let best = self.best();
+        return write!(
+            f,
+            "{what} hit after {} subdivisions and {} evaluations; best estimate {} with error {:e}",
+            best.subdivisions, best.evaluations, best.value, best.abs_error
+        );
+    }
+}
+
+impl std::error::Error for IntegrationError {}
+
+pub trait Integrator: std::fmt::Debug {
+    fn integrate(
+        &self,
+        f: &dyn Fn(f64) -> f64,
+        x0: f64,
+        x1: f64,
+    ) -> Result<IntegrationResult, IntegrationError>;
+}
+
+// trapezoid::integrate: keep halving until successive estimates agree to within tol
+#[derive(Clone, Copy, Debug)]
+pub struct Trapezoid {
+    pub tol: Tolerance,
+    pub summation: Summation,
+    pub budget: Budget,
+}
+
+impl Integrator for Trapezoid {
+    fn integrate(
+        &self,
+        f: &dyn Fn(f64) -> f64,
+        x0: f64,
+        x1: f64,
+    ) -> Result<IntegrationResult, IntegrationError> {
+        let (subs, value, error, _) =
+            trapezoid::integrate_summed(&self.tol, f, x0, x1, self.summation, &self.budget)?;
+        return Ok(IntegrationResult {
+            value,
+            abs_error: error.abs(),
+            evaluations: subs as usize + 1,
+            subdivisions: subs as usize,
+            converged: true,
+        });
+    }
+}
+
+// romberg::integrate: Richardson extrapolation of the same trapezoid sequence
+#[derive(Clone, Copy, Debug)]
+pub struct Romberg {
+    pub tol: Tolerance,
+    pub summation: Summation,
+    pub budget: Budget,
+}
+
+impl Integrator for Romberg {
+    fn integrate(
+        &self,
+        f: &dyn Fn(f64) -> f64,
+        x0: f64,
+        x1: f64,
+    ) -> Result<IntegrationResult, IntegrationError> {
+        let (subs, value, error, _) =
+            romberg::integrate_summed(&self.tol, f, x0, x1, self.summation, &self.budget)?;
+        return Ok(IntegrationResult {
+            value,
+            abs_error: error.abs(),
+            evaluations: subs + 1,
+            subdivisions: subs,
+            converged: true,
+        });
+    }
+}
+
+// quadgl::integrate with a fixed n. A single rule has no error estimate of its own, so we also run
+// the (n-1)-point rule and take the difference, as integrate_auto does between orders; that
+// overstates the error of the n-point result. There is no tolerance or budget, so it always
+// "converges".
+#[derive(Clone, Copy, Debug)]
+pub struct GaussLegendre {
+    pub n: usize,
+}
+
+impl Integrator for GaussLegendre {
+    fn integrate(
+        &self,
+        f: &dyn Fn(f64) -> f64,
+        x0: f64,
+        x1: f64,
+    ) -> Result<IntegrationResult, IntegrationError> {
+        assert!(self.n >= 2);
+        let value: f64 = quadgl::integrate(self.n, f, x0, x1);
+        let coarse: f64 = quadgl::integrate(self.n - 1, f, x0, x1);
+        return Ok(IntegrationResult {
+            value,
+            abs_error: (value - coarse).abs(),
+            evaluations: 2 * self.n - 1,
+            subdivisions: 1,
+            converged: true,
+        });
+    }
+}
+
+// a result from one of the modules that report `converged` rather than failing: the
+// IntegrationResult, or if it didn't converge, `limit` with it
+fn checked(
+    res: IntegrationResult,
+    limit: fn(IntegrationResult) -> IntegrationError,
+) -> Result<IntegrationResult, IntegrationError> {
+    if res.converged {
+        return Ok(res);
+    }
+    return Err(limit(res));
+}
+
+// quadgl::integrate_adaptive, which gives up at its cap on subintervals
+#[derive(Clone, Copy, Debug)]
+pub struct GaussKronrod {
+    pub rule: quadgl::Kronrod,
+    pub abs_tol: f64,
+    pub rel_tol: f64,
+}
+
+impl Integrator for GaussKronrod {
+    fn integrate(
+        &self,
+        f: &dyn Fn(f64) -> f64,
+        x0: f64,
+        x1: f64,
+    ) -> Result<IntegrationResult, IntegrationError> {
+        let res = quadgl::integrate_adaptive(self.rule, f, x0, x1, self.abs_tol, self.rel_tol);
+        // one call of the pair to start with, then two per bisection
+        let per_call = self.rule.points();
+        return checked(
+            IntegrationResult {
+                value: res.value,
+                abs_error: res.error,
+                evaluations: res.evaluations,
+                subdivisions: (res.evaluations / per_call).div_ceil(2),
+                converged: res.converged,
+            },
+            IntegrationError::MaxSubdivisions,
+        );
+    }
+}
+
+// clenshaw::integrate_nested: one rule over the whole range, refined in place until its cap on
+// points
+#[derive(Clone, Copy, Debug)]
+pub struct ClenshawCurtis {
+    pub rule: clenshaw::Rule,
+    pub abs_tol: f64,
+    pub rel_tol: f64,
+}
+
+impl Integrator for ClenshawCurtis {
+    fn integrate(
+        &self,
+        f: &dyn Fn(f64) -> f64,
+        x0: f64,
+        x1: f64,
+    ) -> Result<IntegrationResult, IntegrationError> {
+        let res = clenshaw::integrate_nested(self.rule, f, x0, x1, self.abs_tol, self.rel_tol);
+        return checked(
+            IntegrationResult {
+                value: res.value,
+                abs_error: res.error,
+                evaluations: res.evaluations,
+                subdivisions: 1,
+                converged: res.converged,
+            },
+            IntegrationError::MaxEvaluations,
+        );
+    }
+}
+
+// tanhsinh::integrate, on finite or infinite ranges. It halves the step in t rather than
+// subdividing x, so subdivisions counts those halvings (its levels).
+#[derive(Clone, Copy, Debug)]
+pub struct TanhSinh {
+    pub abs_tol: f64,
+    pub rel_tol: f64,
+}
+
+impl Integrator for TanhSinh {
+    fn integrate(
+        &self,
+        f: &dyn Fn(f64) -> f64,
+        x0: f64,
+        x1: f64,
+    ) -> Result<IntegrationResult, IntegrationError> {
+        let res = tanhsinh::integrate(f, x0, x1, self.abs_tol, self.rel_tol);
+        return checked(
+            IntegrationResult {
+                value: res.value,
+                abs_error: res.error,
+                evaluations: res.evaluations,
+                subdivisions: res.levels,
+                converged: res.converged,
+            },
+            IntegrationError::MaxSubdivisions,
+        );
+    }
+}
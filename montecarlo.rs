@@ -0,0 +1,245 @@
+// SPDX-License-Identifier: MIT
+// Monte Carlo and quasi-Monte Carlo over the box [lo_0, hi_0] x ... x [lo_(d-1), hi_(d-1)]. Every
+// routine takes an explicit seed, so runs are reproducible.
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+#[derive(Clone, Copy, Debug)]
+pub struct McResult {
+    pub value: f64,
+    pub std_error: f64,
+    pub evaluations: usize,
+}
+
+fn volume(lo: &[f64], hi: &[f64]) -> f64 {
+    return lo.iter().zip(hi).map(|(a, b)| b - a).product();
+}
+
+// u in [0, 1)^d to x in the box
+fn to_box(u: &[f64], lo: &[f64], hi: &[f64], x: &mut [f64]) {
+    for k in 0..x.len() {
+        x[k] = lo[k] + (hi[k] - lo[k]) * u[k];
+    }
+}
+
+// (mean, standard error of the mean) of a sample
+fn mean_and_error(ys: &[f64]) -> (f64, f64) {
+    let n = ys.len() as f64;
+    let mean = ys.iter().sum::<f64>() / n;
+    let var = ys.iter().map(|y| (y - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    return (mean, (var / n).sqrt());
+}
+
+pub fn plain(n: usize, f: impl Fn(&[f64]) -> f64, lo: &[f64], hi: &[f64], seed: u64) -> McResult {
+    assert!(n >= 2 && lo.len() == hi.len());
+    let d = lo.len();
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut u: Vec<f64> = vec![0.0; d];
+    let mut x: Vec<f64> = vec![0.0; d];
+    let ys = (0..n)
+        .map(|_| {
+            u.iter_mut().for_each(|ui| *ui = rng.r#gen::<f64>());
+            to_box(&u, lo, hi, &mut x);
+            f(&x)
+        })
+        .collect::<Vec<f64>>();
+    let (mean, err) = mean_and_error(&ys);
+    let vol = volume(lo, hi);
+    return McResult {
+        value: vol * mean,
+        std_error: vol * err,
+        evaluations: n,
+    };
+}
+
+// split every axis into `strata` equal pieces and take `per_cell` (>= 2, for the variance) samples
+// in each of the strata^d cells; only sensible for small d
+pub fn stratified(
+    strata: usize,
+    per_cell: usize,
+    f: impl Fn(&[f64]) -> f64,
+    lo: &[f64],
+    hi: &[f64],
+    seed: u64,
+) -> McResult {
+    assert!(strata >= 1 && per_cell >= 2 && lo.len() == hi.len());
+    let d = lo.len();
+    let cells = strata.pow(d as u32);
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut u: Vec<f64> = vec![0.0; d];
+    let mut x: Vec<f64> = vec![0.0; d];
+    let mut ys: Vec<f64> = vec![0.0; per_cell];
+    let (mut value, mut var): (f64, f64) = (0.0, 0.0);
+    for cell in 0..cells {
+        for y in ys.iter_mut() {
+            // the digits of `cell` in base `strata` pick the stratum on each axis
+            let mut c = cell;
+            for ui in u.iter_mut() {
+                *ui = ((c % strata) as f64 + rng.r#gen::<f64>()) / (strata as f64);
+                c /= strata;
+            }
+            to_box(&u, lo, hi, &mut x);
+            *y = f(&x);
+        }
+        let (mean, err) = mean_and_error(&ys);
+        value += mean;
+        var += err * err;
+    }
+    let cell_vol = volume(lo, hi) / (cells as f64);
+    return McResult {
+        value: cell_vol * value,
+        std_error: cell_vol * var.sqrt(),
+        evaluations: cells * per_cell,
+    };
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Sequence {
+    Halton,
+    Sobol,
+}
+
+const PRIMES: [u64; 32] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+    101, 103, 107, 109, 113, 127, 131,
+];
+
+fn radical_inverse(base: u64, mut i: u64) -> f64 {
+    let mut ret: f64 = 0.0;
+    let mut scale = 1.0 / (base as f64);
+    while i > 0 {
+        ret += ((i % base) as f64) * scale;
+        i /= base;
+        scale /= base as f64;
+    }
+    return ret;
+}
+
+// Sobol direction numbers from Joe & Kuo (new-joe-kuo-6.21201), dimensions 2 through 21, as
+// (degree s, coefficients a, initial m_1..m_s); dimension 1 is van der Corput
+static SOBOL_JOE_KUO: [(u32, u32, &[u32]); 20] = [
+    (1, 0, &[1]),
+    (2, 1, &[1, 3]),
+    (3, 1, &[1, 3, 1]),
+    (3, 2, &[1, 1, 1]),
+    (4, 1, &[1, 1, 3, 3]),
+    (4, 4, &[1, 3, 5, 13]),
+    (5, 2, &[1, 1, 5, 5, 17]),
+    (5, 4, &[1, 1, 5, 5, 5]),
+    (5, 7, &[1, 1, 7, 11, 19]),
+    (5, 11, &[1, 1, 5, 1, 1]),
+    (5, 13, &[1, 1, 1, 3, 11]),
+    (5, 14, &[1, 3, 5, 5, 31]),
+    (6, 1, &[1, 3, 3, 9, 7, 49]),
+    (6, 13, &[1, 1, 1, 15, 21, 21]),
+    (6, 16, &[1, 3, 1, 13, 27, 49]),
+    (6, 19, &[1, 1, 1, 15, 7, 5]),
+    (6, 22, &[1, 3, 1, 15, 13, 25]),
+    (6, 25, &[1, 1, 5, 5, 19, 61]),
+    (7, 1, &[1, 3, 7, 11, 23, 15, 103]),
+    (7, 4, &[1, 3, 7, 13, 13, 15, 69]),
+];
+
+const SOBOL_BITS: usize = 32;
+
+fn sobol_directions(d: usize) -> Vec<[u32; SOBOL_BITS]> {
+    assert!(
+        d <= SOBOL_JOE_KUO.len() + 1,
+        "no Sobol direction numbers past dimension 21"
+    );
+    let mut ret: Vec<[u32; SOBOL_BITS]> = Vec::with_capacity(d);
+    for j in 0..d {
+        let mut v: [u32; SOBOL_BITS] = [0; SOBOL_BITS];
+        if j == 0 {
+            for (k, vk) in v.iter_mut().enumerate() {
+                *vk = 1 << (SOBOL_BITS - 1 - k);
+            }
+        } else {
+            let (s, a, m) = SOBOL_JOE_KUO[j - 1];
+            let s = s as usize;
+            for k in 0..SOBOL_BITS {
+                if k < s {
+                    v[k] = m[k] << (SOBOL_BITS - 1 - k);
+                } else {
+                    v[k] = v[k - s] ^ (v[k - s] >> s);
+                    for i in 1..s {
+                        if (a >> (s - 1 - i)) & 1 == 1 {
+                            v[k] ^= v[k - i];
+                        }
+                    }
+                }
+            }
+        }
+        ret.push(v);
+    }
+    return ret;
+}
+
+// randomized QMC: `replicates` independently shifted copies of the first n points (a random
+// shift mod 1 for Halton, a random digital shift for Sobol), with the error bar taken from the
+// spread of the replicate estimates
+pub fn quasi(
+    seq: Sequence,
+    n: usize,
+    replicates: usize,
+    f: impl Fn(&[f64]) -> f64,
+    lo: &[f64],
+    hi: &[f64],
+    seed: u64,
+) -> McResult {
+    assert!(n >= 1 && replicates >= 2 && lo.len() == hi.len());
+    let d = lo.len();
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut u: Vec<f64> = vec![0.0; d];
+    let mut x: Vec<f64> = vec![0.0; d];
+    let mut estimates: Vec<f64> = Vec::with_capacity(replicates);
+    match seq {
+        Sequence::Halton => {
+            assert!(d <= PRIMES.len(), "not enough Halton bases");
+            for _ in 0..replicates {
+                let shift = (0..d).map(|_| rng.r#gen::<f64>()).collect::<Vec<f64>>();
+                let mut sum: f64 = 0.0;
+                for i in 0..n {
+                    for k in 0..d {
+                        u[k] = (radical_inverse(PRIMES[k], i as u64 + 1) + shift[k]).fract();
+                    }
+                    to_box(&u, lo, hi, &mut x);
+                    sum += f(&x);
+                }
+                estimates.push(sum / (n as f64));
+            }
+        }
+        Sequence::Sobol => {
+            let v = sobol_directions(d);
+            for _ in 0..replicates {
+                let shift = (0..d).map(|_| rng.r#gen::<u32>()).collect::<Vec<u32>>();
+                let mut p: Vec<u32> = vec![0; d];
+                let mut sum: f64 = 0.0;
+                for i in 0..n {
+                    // Gray-code order: point i differs from point i-1 in the direction numbers for
+                    // the lowest zero bit of i-1
+                    if i > 0 {
+                        let c = (!(i - 1)).trailing_zeros() as usize;
+                        for k in 0..d {
+                            p[k] ^= v[k][c];
+                        }
+                    }
+                    for k in 0..d {
+                        u[k] = ((p[k] ^ shift[k]) as f64) / 2_f64.powi(SOBOL_BITS as i32);
+                    }
+                    to_box(&u, lo, hi, &mut x);
+                    sum += f(&x);
+                }
+                estimates.push(sum / (n as f64));
+            }
+        }
+    }
+    let (mean, err) = mean_and_error(&estimates);
+    let vol = volume(lo, hi);
+    return McResult {
+        value: vol * mean,
+        std_error: vol * err,
+        evaluations: n * replicates,
+    };
+}
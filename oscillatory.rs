@@ -0,0 +1,181 @@
+// SPDX-License-Identifier: MIT
+// highly oscillatory integrals, where ordinary rules need several points per wavelength. Filon
+// handles ∫ f(x) cos(ωx) dx and ∫ f(x) sin(ωx) dx by integrating the oscillating factor exactly
+// against a piecewise quadratic through f; Levin handles ∫ f(x) e^(i g(x)) dx for a general phase
+// g by collocating the non-oscillatory antiderivative instead of the integrand. Either way the
+// cost depends on how wiggly f is, not on ω.
+
+use crate::dual::Dual;
+use num_complex::Complex;
+use std::f64::consts::PI;
+
+#[derive(Clone, Copy, Debug)]
+pub enum Weight {
+    Cos,
+    Sin,
+}
+
+// Filon's α, β, γ (Abramowitz & Stegun 25.4.47), by their Taylor series for small θ where the
+// closed forms cancel
+fn filon_coefficients(theta: f64) -> (f64, f64, f64) {
+    if theta.abs() < 1.0 / 6.0 {
+        let t2 = theta * theta;
+        let t3 = t2 * theta;
+        let alpha = t3 * (2.0 / 45.0 - t2 * (2.0 / 315.0 - t2 * 2.0 / 4725.0));
+        let beta = 2.0 / 3.0 + t2 * (2.0 / 15.0 - t2 * (4.0 / 105.0 - t2 * 2.0 / 567.0));
+        let gamma = 4.0 / 3.0 - t2 * (2.0 / 15.0 - t2 * (1.0 / 210.0 - t2 / 11340.0));
+        return (alpha, beta, gamma);
+    }
+    let (s, c) = theta.sin_cos();
+    let t3 = theta.powi(3);
+    let alpha = (theta * theta + theta * s * c - 2.0 * s * s) / t3;
+    let beta = 2.0 * (theta * (1.0 + c * c) - 2.0 * s * c) / t3;
+    let gamma = 4.0 * (s - theta * c) / t3;
+    return (alpha, beta, gamma);
+}
+
+// composite Filon-Simpson: ∫_x0^x1 f(x) w(ωx) dx over n panels of two subintervals each, so 2n + 1
+// evaluations of f. Exact when f is piecewise quadratic on the panels; as ω -> 0 it turns into
+// Simpson's rule.
+pub fn filon(
+    n: usize,
+    f: impl Fn(f64) -> f64,
+    omega: f64,
+    weight: Weight,
+    x0: f64,
+    x1: f64,
+) -> f64 {
+    assert!(n >= 1);
+    let h = (x1 - x0) / ((2 * n) as f64);
+    let (alpha, beta, gamma) = filon_coefficients(omega * h);
+    let w = |x: f64| match weight {
+        Weight::Cos => (omega * x).cos(),
+        Weight::Sin => (omega * x).sin(),
+    };
+    // the antiderivative of w(ωx), times ω, at the ends
+    let big_w = |x: f64| match weight {
+        Weight::Cos => (omega * x).sin(),
+        Weight::Sin => -(omega * x).cos(),
+    };
+    let (fa, fb) = (f(x0), f(x1));
+    // even points, with the ends at half weight; then odd points
+    let mut even: f64 = -0.5 * (fa * w(x0) + fb * w(x1));
+    let mut odd: f64 = 0.0;
+    for k in 0..=(2 * n) {
+        let x = x0 + (k as f64) * h;
+        let y = match k {
+            0 => fa,
+            _ if k == 2 * n => fb,
+            _ => f(x),
+        };
+        if k.is_multiple_of(2) {
+            even += y * w(x);
+        } else {
+            odd += y * w(x);
+        }
+    }
+    return h * (alpha * (fb * big_w(x1) - fa * big_w(x0)) + beta * even + gamma * odd);
+}
+
+// solve a x = b in place by Gaussian elimination with partial pivoting; a is row-major n x n
+fn solve(n: usize, a: &mut [Complex<f64>], b: &mut [Complex<f64>]) {
+    for col in 0..n {
+        let pivot = (col..n)
+            .max_by(|&i, &j| a[i * n + col].norm().total_cmp(&a[j * n + col].norm()))
+            .unwrap();
+        if pivot != col {
+            for k in 0..n {
+                a.swap(col * n + k, pivot * n + k);
+            }
+            b.swap(col, pivot);
+        }
+        let d = a[col * n + col];
+        for row in (col + 1)..n {
+            let m = a[row * n + col] / d;
+            if m == Complex::new(0.0, 0.0) {
+                continue;
+            }
+            for k in col..n {
+                let t = a[col * n + k];
+                a[row * n + k] -= m * t;
+            }
+            let t = b[col];
+            b[row] -= m * t;
+        }
+    }
+    for row in (0..n).rev() {
+        let mut s = b[row];
+        for k in (row + 1)..n {
+            s -= a[row * n + k] * b[k];
+        }
+        b[row] = s / a[row * n + row];
+    }
+}
+
+// Levin's method for ∫_x0^x1 f(x) e^(i g(x)) dx. If p solves p' + i g' p = f then the integrand is
+// (p e^(ig))', so the integral is p(x1) e^(i g(x1)) - p(x0) e^(i g(x0)). We look for p as a
+// Chebyshev series of degree n - 1 and collocate at the n Chebyshev points of the first kind, which
+// keeps f and g away from the endpoints. g is written over Dual so that g' comes for free. There
+// must be no stationary points (g' = 0) in the range; split there and handle that piece otherwise.
+pub fn levin(
+    n: usize,
+    f: impl Fn(f64) -> f64,
+    g: impl Fn(Dual) -> Dual,
+    x0: f64,
+    x1: f64,
+) -> Complex<f64> {
+    assert!(n >= 1);
+    let half = (x1 - x0) / 2.0;
+    let mid = (x1 + x0) / 2.0;
+    let i = Complex::new(0.0, 1.0);
+    let mut a: Vec<Complex<f64>> = vec![Complex::new(0.0, 0.0); n * n];
+    let mut c: Vec<Complex<f64>> = vec![Complex::new(0.0, 0.0); n];
+    for j in 0..n {
+        let s = (PI * ((j as f64) + 0.5) / (n as f64)).cos();
+        let x = mid + half * s;
+        let dg = g(Dual::var(x)).du;
+        // T_k(s) and T_k'(s) = k U_(k-1)(s), by their recurrences
+        let (mut t0, mut t1) = (1.0, s);
+        let (mut u0, mut u1) = (0.0, 1.0);
+        for k in 0..n {
+            let (t, dt) = match k {
+                0 => (1.0, 0.0),
+                _ => {
+                    if k >= 2 {
+                        (t0, t1) = (t1, 2.0 * s * t1 - t0);
+                        (u0, u1) = (u1, 2.0 * s * u1 - u0);
+                    }
+                    (t1, (k as f64) * u1)
+                }
+            };
+            // d/dx = d/ds / half
+            a[j * n + k] = dt / half + i * dg * t;
+        }
+        c[j] = Complex::new(f(x), 0.0);
+    }
+    solve(n, &mut a, &mut c);
+    // T_k(1) = 1, T_k(-1) = (-1)^k
+    let p1: Complex<f64> = c.iter().sum();
+    let p0: Complex<f64> = c
+        .iter()
+        .enumerate()
+        .map(|(k, ck)| if k.is_multiple_of(2) { *ck } else { -ck })
+        .sum();
+    let e = |x: f64| Complex::from_polar(1.0, g(Dual::constant(x)).re);
+    return p1 * e(x1) - p0 * e(x0);
+}
+
+// levin on each panel between consecutive breakpoints; grade them toward any endpoint where f or
+// g' misbehaves
+pub fn levin_breakpoints(
+    n: usize,
+    f: impl Fn(f64) -> f64,
+    g: impl Fn(Dual) -> Dual,
+    points: &[f64],
+) -> Complex<f64> {
+    assert!(points.len() >= 2);
+    return points
+        .windows(2)
+        .map(|p| levin(n, &f, &g, p[0], p[1]))
+        .sum();
+}
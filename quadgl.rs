@@ -1,19 +1,449 @@
 // SPDX-License-Identifier: MIT
 
+use crate::dd::DoubleDouble;
+use crate::summation::Summation;
+use crate::value::{Value, Vector, sum2};
+use std::f64::consts::PI;
+
+mod gauss_gen;
+mod hermite;
+mod jacobi;
+mod laguerre;
+mod quadgk_data;
 mod quadgl_data;
+mod quadgl_dd;
+mod quadgl_gen;
 
-fn _integrate_impl(n: usize, f: impl Fn(f64) -> f64) -> f64 {
-    let mut ret: f64 = 0.0;
-    let ws = quadgl_data::ws(n);
-    let xs = quadgl_data::xs(n);
+pub use hermite::Hermite;
+
+// the static tables are the fast path; anything outside them gets generated (and cached)
+#[inline]
+pub fn xs(n: usize) -> &'static [f64] {
+    if (2..=64).contains(&n) {
+        return quadgl_data::xs(n);
+    }
+    return quadgl_gen::xs(n);
+}
+
+#[inline]
+pub fn ws(n: usize) -> &'static [f64] {
+    if (2..=64).contains(&n) {
+        return quadgl_data::ws(n);
+    }
+    return quadgl_gen::ws(n);
+}
+
+#[inline]
+pub fn xs_const<const N: usize>() -> &'static [f64; N] {
+    return quadgl_data::xs_const::<N>();
+}
+
+#[inline]
+pub fn ws_const<const N: usize>() -> &'static [f64; N] {
+    return quadgl_data::ws_const::<N>();
+}
+
+// generic over what f returns (see value::Value); for f64 this is the same loop as ever
+fn _integrate_impl<T: Value>(n: usize, f: impl Fn(f64) -> T) -> T {
+    return _rule_impl(xs(n), ws(n), f);
+}
+
+// _integrate_impl with the order fixed at compile time, so the trip count is a constant
+fn _integrate_impl_const<const N: usize, T: Value>(f: impl Fn(f64) -> T) -> T {
+    return _rule_impl(xs_const::<N>(), ws_const::<N>(), f);
+}
+
+// Σ w_i f(x_i); always inlined, so that a rule of known length can be unrolled
+#[inline(always)]
+fn _rule_impl<T: Value>(xs: &[f64], ws: &[f64], f: impl Fn(f64) -> T) -> T {
+    let mut ret = f(xs[0]).scale(ws[0]);
+    for i in 1..xs.len() {
+        ret.add_scaled(ws[i], &f(xs[i]));
+    }
+    return ret;
+}
+
+pub fn integrate<T: Value>(n: usize, f: impl Fn(f64) -> T, x0: f64, x1: f64) -> T {
+    let half = (x1 - x0) / 2.0;
+    let mid = (x1 + x0) / 2.0;
+    return _integrate_impl(n, |x| f(mid + half * x)).scale(half);
+}
+
+// _integrate_impl for f64 integrands with the sum taken by `method`; returns (sum, rounding error
+// bound on the sum)
+fn _integrate_impl_summed(n: usize, f: impl Fn(f64) -> f64, method: Summation) -> (f64, f64) {
+    return method.sum(ws(n).iter().zip(xs(n)).map(|(w, x)| w * f(*x)));
+}
+
+// integrate, with a choice of summation; returns (ret, rounding error bound)
+pub fn integrate_summed(
+    n: usize,
+    f: impl Fn(f64) -> f64,
+    x0: f64,
+    x1: f64,
+    method: Summation,
+) -> (f64, f64) {
+    let half = (x1 - x0) / 2.0;
+    let mid = (x1 + x0) / 2.0;
+    let (ret, err) = _integrate_impl_summed(n, |x| f(mid + half * x), method);
+    return (half * ret, half.abs() * err);
+}
+
+// largest order integrate_auto will go to before giving up
+const MAX_AUTO_ORDER: usize = 1024;
+
+// raise n (by about half each time, into the generated rules past the tables) until two successive
+// results agree to within eps; returns (n, ret, |difference|). If they never do, the last n tried
+// comes back with a difference above eps.
+pub fn integrate_auto(f: impl Fn(f64) -> f64, x0: f64, x1: f64, eps: f64) -> (usize, f64, f64) {
+    let mut n: usize = 2;
+    let mut ret = integrate(n, &f, x0, x1);
+    let mut err: f64 = f64::INFINITY;
+    while err >= eps && n < MAX_AUTO_ORDER {
+        n = (n + (n / 2).max(1)).min(MAX_AUTO_ORDER);
+        let prev = ret;
+        ret = integrate(n, &f, x0, x1);
+        err = (ret - prev).abs();
+    }
+    return (n, ret, err);
+}
+
+// integrate with the order as a const generic, e.g. integrate_const::<8>(f, x0, x1): the loop has a
+// fixed trip count and can be unrolled, and a missing table is a compile error
+pub fn integrate_const<const N: usize>(f: impl Fn(f64) -> f64, x0: f64, x1: f64) -> f64 {
+    return integrate_const_value::<N, f64>(f, x0, x1);
+}
+
+// the same for any Value, e.g. integrate_const_value::<8, Dual>(f, x0, x1)
+pub fn integrate_const_value<const N: usize, T: Value>(
+    f: impl Fn(f64) -> T,
+    x0: f64,
+    x1: f64,
+) -> T {
+    let half = (x1 - x0) / 2.0;
+    let mid = (x1 + x0) / 2.0;
+    return _integrate_impl_const::<N, T>(|x| f(mid + half * x)).scale(half);
+}
+
+// the tabulated rules (2..=64) to double-double precision, from the full-digit literals
+pub fn xs_dd(n: usize) -> &'static [DoubleDouble] {
+    return quadgl_dd::xs(n);
+}
+
+pub fn ws_dd(n: usize) -> &'static [DoubleDouble] {
+    return quadgl_dd::ws(n);
+}
+
+// integrate carried out in double-double throughout, for reference values good to ~1e-30 when f is
+// smooth enough for an n <= 64 rule and is itself evaluated in double-double
+pub fn integrate_dd(
+    n: usize,
+    f: impl Fn(DoubleDouble) -> DoubleDouble,
+    x0: DoubleDouble,
+    x1: DoubleDouble,
+) -> DoubleDouble {
+    let half = (x1 - x0) * 0.5;
+    let mid = (x1 + x0) * 0.5;
+    let ws = ws_dd(n);
+    let xs = xs_dd(n);
+    let mut ret = DoubleDouble::ZERO;
     for i in 0..n {
-        ret += ws[i] * f(xs[i]);
+        ret = ret + ws[i] * f(mid + half * xs[i]);
+    }
+    return half * ret;
+}
+
+// an n-point rule on each panel between consecutive breakpoints, e.g. at known kinks or
+// discontinuities of f; the first and last breakpoints are the limits of integration
+pub fn integrate_breakpoints<T: Value>(n: usize, f: impl Fn(f64) -> T, points: &[f64]) -> T {
+    assert!(points.len() >= 2);
+    let mut ret = integrate(n, &f, points[0], points[1]);
+    for p in points[1..].windows(2) {
+        ret.add_scaled(1.0, &integrate(n, &f, p[0], p[1]));
     }
     return ret;
 }
 
-pub fn integrate(n: usize, f: impl Fn(f64) -> f64, x0: f64, x1: f64) -> f64 {
+// an n-point rule on each of m equal panels of [x0, x1]
+pub fn integrate_composite<T: Value>(
+    n: usize,
+    m: usize,
+    f: impl Fn(f64) -> T,
+    x0: f64,
+    x1: f64,
+) -> T {
+    assert!(m >= 1);
+    let h = (x1 - x0) / (m as f64);
+    let points = (0..=m)
+        .map(|k| if k == m { x1 } else { x0 + (k as f64) * h })
+        .collect::<Vec<f64>>();
+    return integrate_breakpoints(n, f, &points);
+}
+
+// ∫ (x1 - x)^alpha (x - x0)^beta g(x) dx over [x0, x1], by Gauss-Jacobi; the endpoint behaviour
+// goes in the weight so that g only has to be smooth
+pub fn integrate_jacobi(
+    n: usize,
+    alpha: f64,
+    beta: f64,
+    g: impl Fn(f64) -> f64,
+    x0: f64,
+    x1: f64,
+) -> f64 {
+    let half = (x1 - x0) / 2.0;
+    let mid = (x1 + x0) / 2.0;
+    let mut ret: f64 = 0.0;
+    let ws = jacobi::ws(n, alpha, beta);
+    let xs = jacobi::xs(n, alpha, beta);
+    for i in 0..n {
+        ret += ws[i] * g(mid + half * xs[i]);
+    }
+    return half.powf(alpha + beta + 1.0) * ret;
+}
+
+// ∫_a^∞ t^alpha e^-t g(x) dx with t = scale * (x - a), by generalized Gauss-Laguerre
+pub fn integrate_laguerre(n: usize, alpha: f64, g: impl Fn(f64) -> f64, a: f64, scale: f64) -> f64 {
+    let mut ret: f64 = 0.0;
+    let ws = laguerre::ws(n, alpha);
+    let xs = laguerre::xs(n, alpha);
+    for i in 0..n {
+        ret += ws[i] * g(a + xs[i] / scale);
+    }
+    return ret / scale;
+}
+
+// ∫_a^∞ f(x) dx, for f decaying roughly like e^(-scale * x); exact when f is a polynomial
+// times that exponential
+pub fn integrate_semi_infinite(n: usize, f: impl Fn(f64) -> f64, a: f64, scale: f64) -> f64 {
+    let mut ret: f64 = 0.0;
+    let ws = laguerre::ws_unweighted(n);
+    let xs = laguerre::xs(n, 0.0);
+    for i in 0..n {
+        ret += ws[i] * f(a + xs[i] / scale);
+    }
+    return ret / scale;
+}
+
+// ∫ w((x - mid) / scale) g(x) dx over the whole line, by Gauss-Hermite, where w is the kind's
+// weight function
+pub fn integrate_hermite(
+    n: usize,
+    kind: Hermite,
+    g: impl Fn(f64) -> f64,
+    mid: f64,
+    scale: f64,
+) -> f64 {
+    let mut ret: f64 = 0.0;
+    let ws = hermite::ws(n, kind);
+    let xs = hermite::xs(n, kind);
+    for i in 0..n {
+        ret += ws[i] * g(mid + scale * xs[i]);
+    }
+    return scale * ret;
+}
+
+// E[g(X)] for X ~ N(mean, sd^2)
+pub fn expectation_normal(n: usize, g: impl Fn(f64) -> f64, mean: f64, sd: f64) -> f64 {
+    return integrate_hermite(n, Hermite::Probabilists, g, mean, sd) / (2.0 * PI).sqrt() / sd;
+}
+
+// ∫ f(x) dx over the whole line, for f centred near mid with a Gaussian-ish width of scale
+pub fn integrate_whole_line(n: usize, f: impl Fn(f64) -> f64, mid: f64, scale: f64) -> f64 {
+    let mut ret: f64 = 0.0;
+    let ws = hermite::ws_unweighted(n);
+    let xs = hermite::xs(n, Hermite::Physicists);
+    for i in 0..n {
+        ret += ws[i] * f(mid + scale * xs[i]);
+    }
+    return scale * ret;
+}
+
+// Gauss-Kronrod pairs for integrate_adaptive
+#[derive(Clone, Copy, Debug)]
+pub enum Kronrod {
+    G7K15,
+    G10K21,
+}
+
+impl Kronrod {
+    // evaluations of f per interval
+    pub fn points(self) -> usize {
+        return 2 * self.tables().0.len() - 1;
+    }
+
+    fn tables(self) -> (&'static [f64], &'static [f64], &'static [f64]) {
+        return match self {
+            Kronrod::G7K15 => (
+                &quadgk_data::XGK_15,
+                &quadgk_data::WGK_15,
+                &quadgk_data::WG_7,
+            ),
+            Kronrod::G10K21 => (
+                &quadgk_data::XGK_21,
+                &quadgk_data::WGK_21,
+                &quadgk_data::WG_10,
+            ),
+        };
+    }
+}
+
+// error is per component, with the same shape as value
+#[derive(Clone, Copy, Debug)]
+pub struct AdaptiveResult<T = f64> {
+    pub value: T,
+    pub error: T,
+    pub evaluations: usize,
+    pub converged: bool,
+}
+
+// give up bisecting past this many subintervals
+const MAX_SUBINTERVALS: usize = 1000;
+
+// one Kronrod/Gauss pair on [x0, x1]; returns (kronrod estimate, error estimate)
+fn _kronrod_impl<T: Vector>(rule: Kronrod, f: &impl Fn(f64) -> T, x0: f64, x1: f64) -> (T, T) {
+    let (xgk, wgk, wg) = rule.tables();
     let half = (x1 - x0) / 2.0;
     let mid = (x1 + x0) / 2.0;
-    return half * _integrate_impl(n, |x| f(mid + half * x));
+    let n = xgk.len() - 1;
+    let fc = f(mid);
+    let mut fs: Vec<(T, T)> = Vec::with_capacity(n);
+    let mut resk = fc.scale(wgk[n]);
+    let mut resabs = fc.map(f64::abs).scale(wgk[n]);
+    // the centre is a Gauss node only when the Gauss rule has odd order
+    let mut resg = fc.scale(if n % 2 == 1 { wg[n / 2] } else { 0.0 });
+    for j in 0..n {
+        let dx = half * xgk[j];
+        let (f1, f2) = (f(mid - dx), f(mid + dx));
+        let both = sum2(&f1, &f2);
+        resk.add_scaled(wgk[j], &both);
+        resabs.add_scaled(wgk[j], &sum2(&f1.map(f64::abs), &f2.map(f64::abs)));
+        if j % 2 == 1 {
+            resg.add_scaled(wg[j / 2], &both);
+        }
+        fs.push((f1, f2));
+    }
+    // QUADPACK's error heuristic, per component: scale |K - G| against how much f varies over
+    // the interval
+    let mut err = resk.clone();
+    for i in 0..err.components().len() {
+        let (k, g) = (resk.components()[i], resg.components()[i]);
+        let mean = k / 2.0;
+        let mut resasc = wgk[n] * (fc.components()[i] - mean).abs();
+        for j in 0..n {
+            resasc += wgk[j]
+                * ((fs[j].0.components()[i] - mean).abs() + (fs[j].1.components()[i] - mean).abs());
+        }
+        let resasc = resasc * half.abs();
+        let resabs = resabs.components()[i] * half.abs();
+        let mut e = ((k - g) * half).abs();
+        if resasc != 0.0 && e != 0.0 {
+            e = resasc * (200.0 * e / resasc).powf(1.5).min(1.0);
+        }
+        if resabs > f64::MIN_POSITIVE / (50.0 * f64::EPSILON) {
+            e = e.max(50.0 * f64::EPSILON * resabs);
+        }
+        err.components_mut()[i] = e;
+    }
+    return (resk.scale(half), err);
+}
+
+// globally adaptive Gauss-Kronrod: keep bisecting whichever subinterval has the largest error
+// until the total error is below max(abs_tol, rel_tol * |value|). For vector-valued f every
+// component shares the same evaluations and subdivision, and all of them must meet the tolerance.
+pub fn integrate_adaptive<T: Vector>(
+    rule: Kronrod,
+    f: impl Fn(f64) -> T,
+    x0: f64,
+    x1: f64,
+    abs_tol: f64,
+    rel_tol: f64,
+) -> AdaptiveResult<T> {
+    let per_call = rule.points();
+    // (left, right, value, error)
+    let mut parts: Vec<(f64, f64, T, T)> = Vec::new();
+    let (value, error) = _kronrod_impl(rule, &f, x0, x1);
+    parts.push((x0, x1, value, error));
+    let mut evaluations = per_call;
+    // the largest error among the components
+    let worst_of = |e: &T| e.components().iter().copied().fold(0.0, f64::max);
+    loop {
+        let mut value = parts[0].2.clone();
+        let mut error = parts[0].3.clone();
+        for p in &parts[1..] {
+            value.add_scaled(1.0, &p.2);
+            error.add_scaled(1.0, &p.3);
+        }
+        let converged = value
+            .components()
+            .iter()
+            .zip(error.components())
+            .all(|(v, e)| *e <= abs_tol.max(rel_tol * v.abs()));
+        if converged || parts.len() >= MAX_SUBINTERVALS {
+            return AdaptiveResult {
+                value,
+                error,
+                evaluations,
+                converged,
+            };
+        }
+        let worst = (0..parts.len())
+            .max_by(|&i, &j| worst_of(&parts[i].3).total_cmp(&worst_of(&parts[j].3)))
+            .unwrap();
+        let (a, b, _, _) = parts.swap_remove(worst);
+        let m = (a + b) / 2.0;
+        let (vl, el) = _kronrod_impl(rule, &f, a, m);
+        let (vr, er) = _kronrod_impl(rule, &f, m, b);
+        parts.push((a, m, vl, el));
+        parts.push((m, b, vr, er));
+        evaluations += 2 * per_call;
+    }
+}
+
+// Cauchy principal value PV ∫_x0^x1 f(x)/(x - c) dx for x0 < c < x1. Over [c - d, c + d], with d
+// the distance to the nearer endpoint, the two halves fold together into
+//     ∫_0^d (f(c + t) - f(c - t))/t dt,
+// which is regular (and never needs f at c itself); whatever is left of [x0, x1] is an ordinary
+// integral at least d away from the pole. Both go to integrate_adaptive with half the tolerance
+// each; `converged` is rechecked against the total.
+pub fn integrate_cauchy(
+    rule: Kronrod,
+    f: impl Fn(f64) -> f64,
+    c: f64,
+    x0: f64,
+    x1: f64,
+    abs_tol: f64,
+    rel_tol: f64,
+) -> AdaptiveResult {
+    assert!(
+        x0 < c && c < x1,
+        "the pole must lie strictly inside the range"
+    );
+    let d = (c - x0).min(x1 - c);
+    let sym = integrate_adaptive(
+        rule,
+        |t| (f(c + t) - f(c - t)) / t,
+        0.0,
+        d,
+        abs_tol / 2.0,
+        rel_tol / 2.0,
+    );
+    // each evaluation of the folded integrand is two of f
+    let mut value = sym.value;
+    let mut error = sym.error;
+    let mut evaluations = 2 * sym.evaluations;
+    let mut converged = sym.converged;
+    let (a, b) = if c - d > x0 { (x0, c - d) } else { (c + d, x1) };
+    if a < b {
+        let tail = integrate_adaptive(rule, |x| f(x) / (x - c), a, b, abs_tol / 2.0, rel_tol / 2.0);
+        value += tail.value;
+        error += tail.error;
+        evaluations += tail.evaluations;
+        converged &= tail.converged;
+    }
+    return AdaptiveResult {
+        value,
+        error,
+        evaluations,
+        converged: converged && error <= abs_tol.max(rel_tol * value.abs()),
+    };
 }
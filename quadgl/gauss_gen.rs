@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: MIT
+// Gauss rules for any weight function with a known three-term recurrence
+//     p_(k+1)(x) = (x - a(k)) p_k(x) - b(k) p_(k-1)(x)
+// for its monic orthogonal polynomials, and total mass mu0 = ∫ w(x) dx.
+// Nodes start out as eigenvalues of the Jacobi matrix (Golub-Welsch, without the eigenvectors)
+// and are then polished by Newton iteration on p_n. Weights come from the Christoffel sum
+// 1/w_i = Σ_k q_k(x_i)^2 over orthonormal q_k, which we carry in log space so that large n
+// (where q_k overflows and w_i underflows) still works.
+
+// (q_n(x), q_n'(x), ln Σ_(k<n) q_k(x)^2), with q_0 = 1 rather than 1/sqrt(mu0)
+fn eval(n: usize, x: f64, a: &impl Fn(usize) -> f64, b: &impl Fn(usize) -> f64) -> (f64, f64, f64) {
+    const BIG: f64 = 1E150;
+    let (mut q0, mut q1): (f64, f64) = (0.0, 1.0);
+    let (mut dq0, mut dq1): (f64, f64) = (0.0, 0.0);
+    let mut sum: f64 = 0.0;
+    let mut ln_scale: f64 = 0.0;
+    for k in 0..n {
+        sum += q1 * q1;
+        let sb = if k > 0 { b(k).sqrt() } else { 0.0 };
+        let sb_next = b(k + 1).sqrt();
+        let q2 = ((x - a(k)) * q1 - sb * q0) / sb_next;
+        let dq2 = (q1 + (x - a(k)) * dq1 - sb * dq0) / sb_next;
+        (q0, q1) = (q1, q2);
+        (dq0, dq1) = (dq1, dq2);
+        if q1.abs() > BIG {
+            q0 /= BIG;
+            q1 /= BIG;
+            dq0 /= BIG;
+            dq1 /= BIG;
+            sum /= BIG * BIG;
+            ln_scale += 2.0 * BIG.ln();
+        }
+    }
+    return (q1, dq1, sum.ln() + ln_scale);
+}
+
+// eigenvalues of the symmetric tridiagonal matrix with diagonal d and off-diagonal e (e[i]
+// couples i and i+1), by implicit QL; this is tqli from Numerical Recipes minus the vectors
+fn tridiagonal_eigenvalues(mut d: Vec<f64>, mut e: Vec<f64>) -> Vec<f64> {
+    let n = d.len();
+    e.push(0.0);
+    for l in 0..n {
+        let mut iter = 0;
+        loop {
+            let mut m = l;
+            while m + 1 < n {
+                let dd = d[m].abs() + d[m + 1].abs();
+                if e[m].abs() <= f64::EPSILON * dd {
+                    break;
+                }
+                m += 1;
+            }
+            if m == l {
+                break;
+            }
+            iter += 1;
+            // a rule built from unconverged eigenvalues would be cached and silently wrong
+            assert!(
+                iter <= 100,
+                "QL iteration did not converge for eigenvalue {l} of {n}"
+            );
+            let mut g = (d[l + 1] - d[l]) / (2.0 * e[l]);
+            let mut r = g.hypot(1.0);
+            g = d[m] - d[l] + e[l] / (g + r.copysign(g));
+            let (mut s, mut c, mut p): (f64, f64, f64) = (1.0, 1.0, 0.0);
+            let mut underflow = false;
+            for i in (l..m).rev() {
+                let f = s * e[i];
+                let b = c * e[i];
+                r = f.hypot(g);
+                e[i + 1] = r;
+                if r == 0.0 {
+                    d[i + 1] -= p;
+                    e[m] = 0.0;
+                    underflow = true;
+                    break;
+                }
+                s = f / r;
+                c = g / r;
+                g = d[i + 1] - p;
+                r = (d[i] - g) * s + 2.0 * c * b;
+                p = s * r;
+                d[i + 1] = g + p;
+                g = c * r - b;
+            }
+            if underflow {
+                continue;
+            }
+            d[l] -= p;
+            e[l] = g;
+            e[m] = 0.0;
+        }
+    }
+    return d;
+}
+
+// returns (nodes, ln weights), nodes ascending
+pub fn rule(
+    n: usize,
+    a: impl Fn(usize) -> f64,
+    b: impl Fn(usize) -> f64,
+    ln_mu0: f64,
+) -> (Vec<f64>, Vec<f64>) {
+    assert!(n >= 1);
+    let d = (0..n).map(&a).collect::<Vec<f64>>();
+    let e = (1..n).map(|k| b(k).sqrt()).collect::<Vec<f64>>();
+    let mut xs = tridiagonal_eigenvalues(d, e);
+    xs.sort_by(f64::total_cmp);
+    let mut ln_ws: Vec<f64> = Vec::with_capacity(n);
+    for x in xs.iter_mut() {
+        for _ in 0..20 {
+            let (q, dq, _) = eval(n, *x, &a, &b);
+            let dx = q / dq;
+            if !dx.is_finite() {
+                break;
+            }
+            *x -= dx;
+            if dx.abs() <= 4.0 * f64::EPSILON * x.abs() {
+                break;
+            }
+        }
+        ln_ws.push(ln_mu0 - eval(n, *x, &a, &b).2);
+    }
+    return (xs, ln_ws);
+}
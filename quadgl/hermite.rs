@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: MIT
+// Gauss-Hermite rules on (-∞, ∞), cached per (n, kind)
+
+use super::gauss_gen;
+use std::collections::HashMap;
+use std::f64::consts::PI;
+use std::sync::{LazyLock, Mutex};
+
+// physicists' weight e^(-x^2), or probabilists' e^(-x^2/2)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Hermite {
+    Physicists,
+    Probabilists,
+}
+
+// (nodes, weights, weights / w(node))
+type Rule = (&'static [f64], &'static [f64], &'static [f64]);
+
+static CACHE: LazyLock<Mutex<HashMap<(usize, Hermite), Rule>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+fn rule(n: usize, kind: Hermite) -> Rule {
+    let mut cache = CACHE.lock().unwrap();
+    return *cache.entry((n, kind)).or_insert_with(|| {
+        // the probabilists' polynomials are the physicists' ones with x scaled by sqrt(2)
+        let (c, mu0) = match kind {
+            Hermite::Physicists => (0.5, PI.sqrt()),
+            Hermite::Probabilists => (1.0, (2.0 * PI).sqrt()),
+        };
+        let (xs, ln_ws) = gauss_gen::rule(n, |_| 0.0, |k| c * (k as f64), mu0.ln());
+        let ws = ln_ws.iter().map(|l| l.exp()).collect::<Vec<f64>>();
+        let wes = ln_ws
+            .iter()
+            .zip(&xs)
+            .map(|(l, x)| (l + x * x / (2.0 * c)).exp())
+            .collect::<Vec<f64>>();
+        (xs.leak(), ws.leak(), wes.leak())
+    });
+}
+
+#[inline]
+pub fn xs(n: usize, kind: Hermite) -> &'static [f64] {
+    return rule(n, kind).0;
+}
+
+#[inline]
+pub fn ws(n: usize, kind: Hermite) -> &'static [f64] {
+    return rule(n, kind).1;
+}
+
+// weights for ∫ f(x) dx with no weight function, i.e. w_i e^(x_i^2)
+#[inline]
+pub fn ws_unweighted(n: usize) -> &'static [f64] {
+    return rule(n, Hermite::Physicists).2;
+}
@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: MIT
+// Gauss-Jacobi rules, weight (1-x)^alpha (1+x)^beta on [-1, 1], cached per (n, alpha, beta)
+
+use super::gauss_gen;
+use statrs::function::gamma::ln_gamma;
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+
+type Rule = (&'static [f64], &'static [f64]);
+// (n, alpha bits, beta bits)
+type Key = (usize, u64, u64);
+
+static CACHE: LazyLock<Mutex<HashMap<Key, Rule>>> = LazyLock::new(|| Mutex::new(HashMap::new()));
+
+// recurrence coefficients for the monic Jacobi polynomials; k = 0 and k = 1 are split out
+// because the general forms are 0/0 when alpha + beta is 0 or -1
+fn a(k: usize, alpha: f64, beta: f64) -> f64 {
+    let ab = alpha + beta;
+    if k == 0 {
+        return (beta - alpha) / (ab + 2.0);
+    }
+    let t = 2.0 * (k as f64) + ab;
+    return (beta * beta - alpha * alpha) / (t * (t + 2.0));
+}
+
+fn b(k: usize, alpha: f64, beta: f64) -> f64 {
+    let ab = alpha + beta;
+    if k == 1 {
+        return 4.0 * (1.0 + alpha) * (1.0 + beta) / ((2.0 + ab).powi(2) * (3.0 + ab));
+    }
+    let k = k as f64;
+    let t = 2.0 * k + ab;
+    return 4.0 * k * (k + alpha) * (k + beta) * (k + ab) / (t * t * (t + 1.0) * (t - 1.0));
+}
+
+fn rule(n: usize, alpha: f64, beta: f64) -> Rule {
+    assert!(alpha > -1.0 && beta > -1.0);
+    let mut cache = CACHE.lock().unwrap();
+    return *cache
+        .entry((n, alpha.to_bits(), beta.to_bits()))
+        .or_insert_with(|| {
+            let ln_mu0 =
+                (alpha + beta + 1.0) * 2_f64.ln() + ln_gamma(alpha + 1.0) + ln_gamma(beta + 1.0)
+                    - ln_gamma(alpha + beta + 2.0);
+            let (xs, ln_ws) =
+                gauss_gen::rule(n, |k| a(k, alpha, beta), |k| b(k, alpha, beta), ln_mu0);
+            let ws = ln_ws.iter().map(|l| l.exp()).collect::<Vec<f64>>();
+            (xs.leak(), ws.leak())
+        });
+}
+
+#[inline]
+pub fn xs(n: usize, alpha: f64, beta: f64) -> &'static [f64] {
+    return rule(n, alpha, beta).0;
+}
+
+#[inline]
+pub fn ws(n: usize, alpha: f64, beta: f64) -> &'static [f64] {
+    return rule(n, alpha, beta).1;
+}
@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: MIT
+// generalized Gauss-Laguerre rules, weight x^alpha e^-x on [0, ∞), cached per (n, alpha)
+
+use super::gauss_gen;
+use statrs::function::gamma::ln_gamma;
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+
+// (nodes, weights, weights * e^node)
+type Rule = (&'static [f64], &'static [f64], &'static [f64]);
+
+static CACHE: LazyLock<Mutex<HashMap<(usize, u64), Rule>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+fn rule(n: usize, alpha: f64) -> Rule {
+    assert!(alpha > -1.0);
+    let mut cache = CACHE.lock().unwrap();
+    return *cache.entry((n, alpha.to_bits())).or_insert_with(|| {
+        let (xs, ln_ws) = gauss_gen::rule(
+            n,
+            |k| 2.0 * (k as f64) + alpha + 1.0,
+            |k| (k as f64) * ((k as f64) + alpha),
+            ln_gamma(alpha + 1.0),
+        );
+        // the e^x-scaled weights would overflow if formed as w * e^x for large n
+        let ws = ln_ws.iter().map(|l| l.exp()).collect::<Vec<f64>>();
+        let wes = ln_ws
+            .iter()
+            .zip(&xs)
+            .map(|(l, x)| (l + x).exp())
+            .collect::<Vec<f64>>();
+        (xs.leak(), ws.leak(), wes.leak())
+    });
+}
+
+#[inline]
+pub fn xs(n: usize, alpha: f64) -> &'static [f64] {
+    return rule(n, alpha).0;
+}
+
+#[inline]
+pub fn ws(n: usize, alpha: f64) -> &'static [f64] {
+    return rule(n, alpha).1;
+}
+
+// weights for ∫_0^∞ f(x) dx with no weight function, i.e. w_i e^(x_i) for alpha = 0
+#[inline]
+pub fn ws_unweighted(n: usize) -> &'static [f64] {
+    return rule(n, 0.0).2;
+}
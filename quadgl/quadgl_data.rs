@@ -1,21 +1,37 @@
 // SPDX-License-Identifier: MIT
 // vim: set nowrap :
+#![allow(clippy::excessive_precision)]
 // from https://pomax.github.io/bezierinfo/legendre-gauss.html
 
 #[inline]
 pub fn xs(n: usize) -> &'static [f64] {
-    assert!(2 <= n && n <= 64);
+    assert!((2..=64).contains(&n));
     let start = TRIANGULAR[n - 1] - 1;
     return &ABSCISSAE[start..(start + n)];
 }
 
 #[inline]
 pub fn ws(n: usize) -> &'static [f64] {
-    assert!(2 <= n && n <= 64);
+    assert!((2..=64).contains(&n));
     let start = TRIANGULAR[n - 1] - 1;
     return &WEIGHTS[start..(start + n)];
 }
 
+// the same rules with the order fixed at compile time; orders outside 2..=64 fail to build
+#[inline]
+pub fn xs_const<const N: usize>() -> &'static [f64; N] {
+    const { assert!(2 <= N && N <= 64, "no Gauss-Legendre table for this order") };
+    let start = N * (N - 1) / 2 - 1;
+    return ABSCISSAE[start..].first_chunk::<N>().unwrap();
+}
+
+#[inline]
+pub fn ws_const<const N: usize>() -> &'static [f64; N] {
+    const { assert!(2 <= N && N <= 64, "no Gauss-Legendre table for this order") };
+    let start = N * (N - 1) / 2 - 1;
+    return WEIGHTS[start..].first_chunk::<N>().unwrap();
+}
+
 static ABSCISSAE: [f64; 2079] = [
     -0.5773502691896257645091487805019574556476017512701268760186023264839776723029333456937153955857495252252087138051355676766566483649996508262705518373647912161760310773007685273559916067003615583077550051041144223011076288835574182229739459904090157105534559538626730166621791266197964892168,
     0.5773502691896257645091487805019574556476017512701268760186023264839776723029333456937153955857495252252087138051355676766566483649996508262705518373647912161760310773007685273559916067003615583077550051041144223011076288835574182229739459904090157105534559538626730166621791266197964892168,
@@ -0,0 +1,4192 @@
+// SPDX-License-Identifier: MIT
+// vim: set nowrap :
+// the quadgl_data tables again, but kept to double-double precision: the f64 statics there round
+// the ~200-digit literals, so here each literal was split once into hi = the nearest f64 and lo =
+// the nearest f64 to the remainder, which carries about 32 of its digits
+use crate::dd::DoubleDouble;
+
+pub fn xs(n: usize) -> &'static [DoubleDouble] {
+    assert!(
+        (2..=64).contains(&n),
+        "no Gauss-Legendre table for this order"
+    );
+    let start = n * (n - 1) / 2 - 1;
+    return &ABSCISSAE[start..(start + n)];
+}
+
+pub fn ws(n: usize) -> &'static [DoubleDouble] {
+    assert!(
+        (2..=64).contains(&n),
+        "no Gauss-Legendre table for this order"
+    );
+    let start = n * (n - 1) / 2 - 1;
+    return &WEIGHTS[start..(start + n)];
+}
+
+const fn dd(hi: f64, lo: f64) -> DoubleDouble {
+    return DoubleDouble { hi, lo };
+}
+
+static ABSCISSAE: [DoubleDouble; 2079] = [
+    dd(-0.5773502691896257, -3.3450280739356345e-17),
+    dd(0.5773502691896257, 3.3450280739356345e-17),
+    dd(0.0, 0.0),
+    dd(-0.7745966692414834, 2.7242061734927363e-17),
+    dd(0.7745966692414834, -2.7242061734927363e-17),
+    dd(-0.33998104358485626, -7.491321706962178e-18),
+    dd(0.33998104358485626, 7.491321706962178e-18),
+    dd(-0.8611363115940526, -2.686141298670239e-18),
+    dd(0.8611363115940526, 2.686141298670239e-18),
+    dd(0.0, 0.0),
+    dd(-0.5384693101056831, 1.6678154894696646e-17),
+    dd(0.5384693101056831, -1.6678154894696646e-17),
+    dd(-0.906179845938664, -2.909730553174891e-17),
+    dd(0.906179845938664, 2.909730553174891e-17),
+    dd(0.6612093864662645, 3.212031102377179e-17),
+    dd(-0.6612093864662645, -3.212031102377179e-17),
+    dd(-0.2386191860831969, -3.917524250872457e-18),
+    dd(0.2386191860831969, 3.917524250872457e-18),
+    dd(-0.932469514203152, 2.22457639152902e-17),
+    dd(0.932469514203152, -2.22457639152902e-17),
+    dd(0.0, 0.0),
+    dd(0.4058451513773972, -1.72492754475471e-17),
+    dd(-0.4058451513773972, 1.72492754475471e-17),
+    dd(-0.7415311855993945, 2.0220134774069897e-17),
+    dd(0.7415311855993945, -2.0220134774069897e-17),
+    dd(-0.9491079123427585, -3.82579658786657e-17),
+    dd(0.9491079123427585, 3.82579658786657e-17),
+    dd(-0.1834346424956498, 2.8967682069859046e-18),
+    dd(0.1834346424956498, -2.8967682069859046e-18),
+    dd(-0.525532409916329, 4.999907577775897e-18),
+    dd(0.525532409916329, -4.999907577775897e-18),
+    dd(-0.7966664774136267, -1.1625719829749724e-17),
+    dd(0.7966664774136267, 1.1625719829749724e-17),
+    dd(-0.9602898564975363, 5.54885067908428e-17),
+    dd(0.9602898564975363, -5.54885067908428e-17),
+    dd(0.0, 0.0),
+    dd(-0.8360311073266358, -2.4760604672182287e-17),
+    dd(0.8360311073266358, 2.4760604672182287e-17),
+    dd(-0.9681602395076261, -3.982500310530219e-18),
+    dd(0.9681602395076261, 3.982500310530219e-18),
+    dd(-0.3242534234038089, -1.3223788034443487e-17),
+    dd(0.3242534234038089, 1.3223788034443487e-17),
+    dd(-0.6133714327005904, -3.959701239077378e-17),
+    dd(0.6133714327005904, 3.959701239077378e-17),
+    dd(-0.14887433898163122, 4.8210770585131585e-18),
+    dd(0.14887433898163122, -4.8210770585131585e-18),
+    dd(-0.4333953941292472, 2.2600214699526867e-17),
+    dd(0.4333953941292472, -2.2600214699526867e-17),
+    dd(-0.6794095682990244, 2.9354889953805544e-17),
+    dd(0.6794095682990244, -2.9354889953805544e-17),
+    dd(-0.8650633666889845, 2.561358899462181e-17),
+    dd(0.8650633666889845, -2.561358899462181e-17),
+    dd(-0.9739065285171717, 2.3352971736535508e-17),
+    dd(0.9739065285171717, -2.3352971736535508e-17),
+    dd(0.0, 0.0),
+    dd(-0.26954315595234496, -1.293972324237976e-17),
+    dd(0.26954315595234496, 1.293972324237976e-17),
+    dd(-0.5190961292068118, -8.781619372839907e-18),
+    dd(0.5190961292068118, 8.781619372839907e-18),
+    dd(-0.7301520055740494, 3.2346597728287176e-17),
+    dd(0.7301520055740494, -3.2346597728287176e-17),
+    dd(-0.8870625997680953, 1.7679410333320107e-17),
+    dd(0.8870625997680953, -1.7679410333320107e-17),
+    dd(-0.978228658146057, -1.981549111409329e-17),
+    dd(0.978228658146057, 1.981549111409329e-17),
+    dd(-0.1252334085114689, -2.1901695274281555e-18),
+    dd(0.1252334085114689, 2.1901695274281555e-18),
+    dd(-0.3678314989981802, -9.618137198627985e-18),
+    dd(0.3678314989981802, 9.618137198627985e-18),
+    dd(-0.5873179542866175, 3.563183175402957e-17),
+    dd(0.5873179542866175, -3.563183175402957e-17),
+    dd(-0.7699026741943047, 5.497380348312871e-18),
+    dd(0.7699026741943047, -5.497380348312871e-18),
+    dd(-0.9041172563704749, 5.209915770219317e-17),
+    dd(0.9041172563704749, -5.209915770219317e-17),
+    dd(-0.9815606342467192, -7.134192985330875e-18),
+    dd(0.9815606342467192, 7.134192985330875e-18),
+    dd(0.0, 0.0),
+    dd(-0.2304583159551348, 7.434864306344432e-18),
+    dd(0.2304583159551348, -7.434864306344432e-18),
+    dd(-0.44849275103644687, 1.5473335588248726e-17),
+    dd(0.44849275103644687, -1.5473335588248726e-17),
+    dd(-0.6423493394403402, 7.258497121965477e-18),
+    dd(0.6423493394403402, -7.258497121965477e-18),
+    dd(-0.8015780907333099, -3.4647777816520386e-17),
+    dd(0.8015780907333099, 3.4647777816520386e-17),
+    dd(-0.9175983992229779, -4.2288826720109245e-17),
+    dd(0.9175983992229779, 4.2288826720109245e-17),
+    dd(-0.9841830547185881, -1.442700914007431e-17),
+    dd(0.9841830547185881, 1.442700914007431e-17),
+    dd(-0.10805494870734367, 5.569183013988857e-18),
+    dd(0.10805494870734367, -5.569183013988857e-18),
+    dd(-0.31911236892788974, -1.5817018457098693e-17),
+    dd(0.31911236892788974, 1.5817018457098693e-17),
+    dd(-0.5152486363581541, 7.602905497290265e-18),
+    dd(0.5152486363581541, -7.602905497290265e-18),
+    dd(-0.6872929048116855, 8.735001202439097e-18),
+    dd(0.6872929048116855, -8.735001202439097e-18),
+    dd(-0.827201315069765, 2.6482082128493074e-17),
+    dd(0.827201315069765, -2.6482082128493074e-17),
+    dd(-0.9284348836635735, 7.058645884000818e-19),
+    dd(0.9284348836635735, -7.058645884000818e-19),
+    dd(-0.9862838086968123, -2.4709778500376712e-17),
+    dd(0.9862838086968123, 2.4709778500376712e-17),
+    dd(0.0, 0.0),
+    dd(-0.20119409399743451, -7.913604507205427e-18),
+    dd(0.20119409399743451, 7.913604507205427e-18),
+    dd(-0.3941513470775634, 1.5493212254783433e-17),
+    dd(0.3941513470775634, -1.5493212254783433e-17),
+    dd(-0.5709721726085388, -1.7063336834903418e-17),
+    dd(0.5709721726085388, 1.7063336834903418e-17),
+    dd(-0.7244177313601701, 2.220487673927142e-17),
+    dd(0.7244177313601701, -2.220487673927142e-17),
+    dd(-0.8482065834104272, -1.0018456681246771e-17),
+    dd(0.8482065834104272, 1.0018456681246771e-17),
+    dd(-0.937273392400706, 4.708055882377859e-17),
+    dd(0.937273392400706, -4.708055882377859e-17),
+    dd(-0.9879925180204854, -5.108381742345454e-17),
+    dd(0.9879925180204854, 5.108381742345454e-17),
+    dd(-0.09501250983763744, 3.275947755433097e-19),
+    dd(0.09501250983763744, -3.275947755433097e-19),
+    dd(-0.2816035507792589, 2.1958791252592132e-18),
+    dd(0.2816035507792589, -2.1958791252592132e-18),
+    dd(-0.45801677765722737, -1.6662404170959257e-17),
+    dd(0.45801677765722737, 1.6662404170959257e-17),
+    dd(-0.6178762444026438, 2.2123521973463665e-17),
+    dd(0.6178762444026438, -2.2123521973463665e-17),
+    dd(-0.755404408355003, -3.5241085894430354e-17),
+    dd(0.755404408355003, 3.5241085894430354e-17),
+    dd(-0.8656312023878318, 1.1315677979849837e-17),
+    dd(0.8656312023878318, -1.1315677979849837e-17),
+    dd(-0.9445750230732326, 2.4190068142444825e-17),
+    dd(0.9445750230732326, -2.4190068142444825e-17),
+    dd(-0.9894009349916499, 5.914095566469922e-18),
+    dd(0.9894009349916499, -5.914095566469922e-18),
+    dd(0.0, 0.0),
+    dd(-0.17848418149584785, -1.3245730235575952e-18),
+    dd(0.17848418149584785, 1.3245730235575952e-18),
+    dd(-0.3512317634538763, -1.52564952778384e-17),
+    dd(0.3512317634538763, 1.52564952778384e-17),
+    dd(-0.5126905370864769, -2.943089825082713e-17),
+    dd(0.5126905370864769, 2.943089825082713e-17),
+    dd(-0.6576711592166907, -3.9759953654504616e-17),
+    dd(0.6576711592166907, 3.9759953654504616e-17),
+    dd(-0.7815140038968014, -3.888207325641688e-17),
+    dd(0.7815140038968014, 3.888207325641688e-17),
+    dd(-0.8802391537269859, 1.0184201228418715e-17),
+    dd(0.8802391537269859, -1.0184201228418715e-17),
+    dd(-0.9506755217687678, 3.379396883752148e-17),
+    dd(0.9506755217687678, -3.379396883752148e-17),
+    dd(-0.9905754753144174, 2.842785810964392e-17),
+    dd(0.9905754753144174, -2.842785810964392e-17),
+    dd(-0.0847750130417353, 4.698620630419597e-18),
+    dd(0.0847750130417353, -4.698620630419597e-18),
+    dd(-0.2518862256915055, -2.6451539425003548e-17),
+    dd(0.2518862256915055, 2.6451539425003548e-17),
+    dd(-0.41175116146284263, -1.6290180938469612e-17),
+    dd(0.41175116146284263, 1.6290180938469612e-17),
+    dd(-0.5597708310739475, 4.417099316759229e-18),
+    dd(0.5597708310739475, -4.417099316759229e-18),
+    dd(-0.6916870430603532, 1.5947398610890917e-17),
+    dd(0.6916870430603532, -1.5947398610890917e-17),
+    dd(-0.8037049589725231, 2.6752889453722812e-17),
+    dd(0.8037049589725231, -2.6752889453722812e-17),
+    dd(-0.8926024664975557, -3.706537347391958e-17),
+    dd(0.8926024664975557, 3.706537347391958e-17),
+    dd(-0.9558239495713977, -4.221580323615994e-17),
+    dd(0.9558239495713977, 4.221580323615994e-17),
+    dd(-0.9915651684209309, -4.870000806634652e-17),
+    dd(0.9915651684209309, 4.870000806634652e-17),
+    dd(0.0, 0.0),
+    dd(-0.16035864564022537, -9.044012962735113e-18),
+    dd(0.16035864564022537, 9.044012962735113e-18),
+    dd(-0.31656409996362983, -1.7090529143287478e-18),
+    dd(0.31656409996362983, 1.7090529143287478e-18),
+    dd(-0.46457074137596094, -7.393126022982134e-18),
+    dd(0.46457074137596094, 7.393126022982134e-18),
+    dd(-0.600545304661681, -3.3681229995685294e-17),
+    dd(0.600545304661681, 3.3681229995685294e-17),
+    dd(-0.7209661773352294, 7.030512208297124e-18),
+    dd(0.7209661773352294, -7.030512208297124e-18),
+    dd(-0.8227146565371428, -6.1304709890973985e-18),
+    dd(0.8227146565371428, 6.1304709890973985e-18),
+    dd(-0.9031559036148179, -7.05321582986106e-19),
+    dd(0.9031559036148179, 7.05321582986106e-19),
+    dd(-0.96020815213483, -1.3364935661718309e-17),
+    dd(0.96020815213483, 1.3364935661718309e-17),
+    dd(-0.9924068438435844, -5.119499274226345e-17),
+    dd(0.9924068438435844, 5.119499274226345e-17),
+    dd(-0.07652652113349734, 4.557072655796525e-18),
+    dd(0.07652652113349734, -4.557072655796525e-18),
+    dd(-0.22778585114164507, -9.884156488012629e-18),
+    dd(0.22778585114164507, 9.884156488012629e-18),
+    dd(-0.37370608871541955, -1.191005070671823e-17),
+    dd(0.37370608871541955, 1.191005070671823e-17),
+    dd(-0.5108670019508271, 2.84952683625147e-17),
+    dd(0.5108670019508271, -2.84952683625147e-17),
+    dd(-0.636053680726515, -4.73785846574601e-19),
+    dd(0.636053680726515, 4.73785846574601e-19),
+    dd(-0.7463319064601508, 3.109202074074545e-18),
+    dd(0.7463319064601508, -3.109202074074545e-18),
+    dd(-0.8391169718222188, -4.1065867315850824e-17),
+    dd(0.8391169718222188, 4.1065867315850824e-17),
+    dd(-0.912234428251326, 4.0267600310095046e-17),
+    dd(0.912234428251326, -4.0267600310095046e-17),
+    dd(-0.9639719272779138, 1.8016704796146567e-17),
+    dd(0.9639719272779138, -1.8016704796146567e-17),
+    dd(-0.9931285991850949, -4.0125692717995897e-17),
+    dd(0.9931285991850949, 4.0125692717995897e-17),
+    dd(0.0, 0.0),
+    dd(-0.1455618541608951, 2.38712631880139e-18),
+    dd(0.1455618541608951, -2.38712631880139e-18),
+    dd(-0.2880213168024011, 2.0617786913326358e-17),
+    dd(0.2880213168024011, -2.0617786913326358e-17),
+    dd(-0.4243421202074388, -5.8833125422599815e-18),
+    dd(0.4243421202074388, 5.8833125422599815e-18),
+    dd(-0.5516188358872198, 2.012637158942404e-17),
+    dd(0.5516188358872198, -2.012637158942404e-17),
+    dd(-0.6671388041974123, 1.909772765074169e-17),
+    dd(0.6671388041974123, -1.909772765074169e-17),
+    dd(-0.7684399634756779, -1.8989808023806195e-17),
+    dd(0.7684399634756779, 1.8989808023806195e-17),
+    dd(-0.8533633645833173, 1.2838381048076852e-17),
+    dd(0.8533633645833173, -1.2838381048076852e-17),
+    dd(-0.9200993341504008, -3.4937689306540596e-17),
+    dd(0.9200993341504008, 3.4937689306540596e-17),
+    dd(-0.9672268385663063, 1.8511069511944515e-17),
+    dd(0.9672268385663063, -1.8511069511944515e-17),
+    dd(-0.9937521706203895, -4.8000029343302106e-17),
+    dd(0.9937521706203895, 4.8000029343302106e-17),
+    dd(-0.06973927331972223, 4.10557516776595e-18),
+    dd(0.06973927331972223, -4.10557516776595e-18),
+    dd(-0.20786042668822127, -1.2927941606107902e-17),
+    dd(0.20786042668822127, 1.2927941606107902e-17),
+    dd(-0.34193582089208424, 1.6082235459850668e-17),
+    dd(0.34193582089208424, -1.6082235459850668e-17),
+    dd(-0.469355837986757, -1.901010941365353e-17),
+    dd(0.469355837986757, 1.901010941365353e-17),
+    dd(-0.5876404035069116, 8.67988554915717e-18),
+    dd(0.5876404035069116, -8.67988554915717e-18),
+    dd(-0.6944872631866827, -3.3898489128760644e-17),
+    dd(0.6944872631866827, 3.3898489128760644e-17),
+    dd(-0.7878168059792081, -4.962827803813215e-17),
+    dd(0.7878168059792081, 4.962827803813215e-17),
+    dd(-0.8658125777203002, 4.395849109380292e-17),
+    dd(0.8658125777203002, -4.395849109380292e-17),
+    dd(-0.926956772187174, -1.7585344235354742e-17),
+    dd(0.926956772187174, 1.7585344235354742e-17),
+    dd(-0.9700604978354287, -3.487577903103986e-17),
+    dd(0.9700604978354287, 3.487577903103986e-17),
+    dd(-0.9942945854823992, -5.186696747055235e-17),
+    dd(0.9942945854823992, 5.186696747055235e-17),
+    dd(0.0, 0.0),
+    dd(-0.1332568242984661, -2.051608098172456e-18),
+    dd(0.1332568242984661, 2.051608098172456e-18),
+    dd(-0.26413568097034495, 2.4320442398004248e-17),
+    dd(0.26413568097034495, -2.4320442398004248e-17),
+    dd(-0.3903010380302908, -1.69813958005456e-17),
+    dd(0.3903010380302908, 1.69813958005456e-17),
+    dd(-0.5095014778460075, -2.74798340414351e-17),
+    dd(0.5095014778460075, 2.74798340414351e-17),
+    dd(-0.6196098757636461, -3.34369944548962e-17),
+    dd(0.6196098757636461, 3.34369944548962e-17),
+    dd(-0.7186613631319502, -2.397079968076623e-17),
+    dd(0.7186613631319502, 2.397079968076623e-17),
+    dd(-0.8048884016188399, 7.16967134771408e-18),
+    dd(0.8048884016188399, -7.16967134771408e-18),
+    dd(-0.8767523582704416, -4.4422086226222885e-17),
+    dd(0.8767523582704416, 4.4422086226222885e-17),
+    dd(-0.9329710868260161, 4.7024488959337655e-17),
+    dd(0.9329710868260161, -4.7024488959337655e-17),
+    dd(-0.9725424712181152, -1.9916645074003336e-17),
+    dd(0.9725424712181152, 1.9916645074003336e-17),
+    dd(-0.9947693349975522, 3.353880221341454e-17),
+    dd(0.9947693349975522, -3.353880221341454e-17),
+    dd(-0.06405689286260563, 3.8940572030843924e-18),
+    dd(0.06405689286260563, -3.8940572030843924e-18),
+    dd(-0.1911188674736163, 1.511796925720138e-18),
+    dd(0.1911188674736163, -1.511796925720138e-18),
+    dd(-0.3150426796961634, 2.2454009015222363e-17),
+    dd(0.3150426796961634, -2.2454009015222363e-17),
+    dd(-0.4337935076260451, -1.1230353338563027e-17),
+    dd(0.4337935076260451, 1.1230353338563027e-17),
+    dd(-0.5454214713888396, 2.7041126422104026e-17),
+    dd(0.5454214713888396, -2.7041126422104026e-17),
+    dd(-0.6480936519369755, -2.372806271361407e-17),
+    dd(0.6480936519369755, 2.372806271361407e-17),
+    dd(-0.7401241915785544, -6.326231640742655e-18),
+    dd(0.7401241915785544, 6.326231640742655e-18),
+    dd(-0.820001985973903, 2.5126255321982337e-17),
+    dd(0.820001985973903, -2.5126255321982337e-17),
+    dd(-0.8864155270044011, 3.7273784348231524e-17),
+    dd(0.8864155270044011, -3.7273784348231524e-17),
+    dd(-0.9382745520027328, 3.9371485806932436e-17),
+    dd(0.9382745520027328, -3.9371485806932436e-17),
+    dd(-0.9747285559713095, -2.4394038263943126e-17),
+    dd(0.9747285559713095, 2.4394038263943126e-17),
+    dd(-0.9951872199970213, -4.953319652513121e-17),
+    dd(0.9951872199970213, 4.953319652513121e-17),
+    dd(0.0, 0.0),
+    dd(-0.1228646926107104, 1.0466436905640788e-19),
+    dd(0.1228646926107104, -1.0466436905640788e-19),
+    dd(-0.24386688372098844, 1.0085016045567305e-17),
+    dd(0.24386688372098844, -1.0085016045567305e-17),
+    dd(-0.36117230580938786, 2.3594784298659218e-17),
+    dd(0.36117230580938786, -2.3594784298659218e-17),
+    dd(-0.473002731445715, 1.4000860313690944e-17),
+    dd(0.473002731445715, -1.4000860313690944e-17),
+    dd(-0.577662930241223, -1.8311329065134928e-17),
+    dd(0.577662930241223, 1.8311329065134928e-17),
+    dd(-0.6735663684734684, 3.753765399270031e-17),
+    dd(0.6735663684734684, -3.753765399270031e-17),
+    dd(-0.7592592630373576, -5.1525837765155335e-17),
+    dd(0.7592592630373576, 5.1525837765155335e-17),
+    dd(-0.833442628760834, -3.135136641169422e-17),
+    dd(0.833442628760834, 3.135136641169422e-17),
+    dd(-0.8949919978782753, -4.392192159116486e-17),
+    dd(0.8949919978782753, 4.392192159116486e-17),
+    dd(-0.9429745712289743, -1.628021321733525e-17),
+    dd(0.9429745712289743, 1.628021321733525e-17),
+    dd(-0.9766639214595175, 1.407122267913109e-17),
+    dd(0.9766639214595175, -1.407122267913109e-17),
+    dd(-0.9955569697904981, 2.7319099482583952e-17),
+    dd(0.9955569697904981, -2.7319099482583952e-17),
+    dd(-0.05923009342931321, 4.37731727469105e-19),
+    dd(0.05923009342931321, -4.37731727469105e-19),
+    dd(-0.17685882035689018, 1.999373091130135e-20),
+    dd(0.17685882035689018, -1.999373091130135e-20),
+    dd(-0.2920048394859569, 6.724944906661765e-18),
+    dd(0.2920048394859569, -6.724944906661765e-18),
+    dd(-0.4030517551234863, -1.8179684189304862e-17),
+    dd(0.4030517551234863, 1.8179684189304862e-17),
+    dd(-0.5084407148245057, -1.593244551671629e-17),
+    dd(0.5084407148245057, 1.593244551671629e-17),
+    dd(-0.6066922930176181, 4.042575758357977e-18),
+    dd(0.6066922930176181, -4.042575758357977e-18),
+    dd(-0.6964272604199573, 1.872431875449893e-17),
+    dd(0.6964272604199573, -1.872431875449893e-17),
+    dd(-0.7763859488206789, 4.9930792796313817e-17),
+    dd(0.7763859488206789, -4.9930792796313817e-17),
+    dd(-0.845445942788498, 3.167109704437702e-17),
+    dd(0.845445942788498, -3.167109704437702e-17),
+    dd(-0.9026378619843071, -8.129914062688428e-18),
+    dd(0.9026378619843071, 8.129914062688428e-18),
+    dd(-0.9471590666617142, -1.7242783035381357e-17),
+    dd(0.9471590666617142, 1.7242783035381357e-17),
+    dd(-0.978385445956471, 4.2645446578619344e-17),
+    dd(0.978385445956471, -4.2645446578619344e-17),
+    dd(-0.9958857011456169, -9.520255665925668e-18),
+    dd(0.9958857011456169, 9.520255665925668e-18),
+    dd(0.0, 0.0),
+    dd(-0.11397258560952997, 2.486389627961268e-18),
+    dd(0.11397258560952997, -2.486389627961268e-18),
+    dd(-0.22645936543953685, -1.1692891174911794e-17),
+    dd(0.22645936543953685, 1.1692891174911794e-17),
+    dd(-0.3359939036385089, -4.659405793719132e-18),
+    dd(0.3359939036385089, 4.659405793719132e-18),
+    dd(-0.44114825175002687, -1.5393762866221178e-17),
+    dd(0.44114825175002687, 1.5393762866221178e-17),
+    dd(-0.5405515645794569, -3.9125592540119104e-17),
+    dd(0.5405515645794569, 3.9125592540119104e-17),
+    dd(-0.6329079719464952, 3.272632236056809e-17),
+    dd(0.6329079719464952, -3.272632236056809e-17),
+    dd(-0.7170134737394237, 1.930532183740413e-18),
+    dd(0.7170134737394237, -1.930532183740413e-18),
+    dd(-0.7917716390705082, -4.522093947527348e-17),
+    dd(0.7917716390705082, 4.522093947527348e-17),
+    dd(-0.8562079080182945, 4.264875450856574e-18),
+    dd(0.8562079080182945, -4.264875450856574e-18),
+    dd(-0.9094823206774911, 2.5200745769272105e-17),
+    dd(0.9094823206774911, -2.5200745769272105e-17),
+    dd(-0.9509005578147051, 5.394764581783902e-17),
+    dd(0.9509005578147051, -5.394764581783902e-17),
+    dd(-0.9799234759615012, -2.2596055576946114e-17),
+    dd(0.9799234759615012, 2.2596055576946114e-17),
+    dd(-0.9961792628889886, 4.781585001910452e-17),
+    dd(0.9961792628889886, -4.781585001910452e-17),
+    dd(-0.05507928988403427, 2.4684805034475747e-18),
+    dd(0.05507928988403427, -2.4684805034475747e-18),
+    dd(-0.16456928213338076, -9.224953612809368e-18),
+    dd(0.16456928213338076, 9.224953612809368e-18),
+    dd(-0.2720616276351781, 2.7201295629040448e-17),
+    dd(0.2720616276351781, -2.7201295629040448e-17),
+    dd(-0.3762515160890787, -1.3285258387221771e-17),
+    dd(0.3762515160890787, 1.3285258387221771e-17),
+    dd(-0.4758742249551183, 1.354817650810255e-17),
+    dd(0.4758742249551183, -1.354817650810255e-17),
+    dd(-0.5697204718114017, 1.0359064855595005e-17),
+    dd(0.5697204718114017, -1.0359064855595005e-17),
+    dd(-0.656651094038865, 5.1827358803409966e-17),
+    dd(0.656651094038865, -5.1827358803409966e-17),
+    dd(-0.7356108780136318, 1.3926229243773648e-17),
+    dd(0.7356108780136318, -1.3926229243773648e-17),
+    dd(-0.8056413709171791, -3.769966549007555e-17),
+    dd(0.8056413709171791, 3.769966549007555e-17),
+    dd(-0.8658925225743951, 3.5924439538205653e-17),
+    dd(0.8658925225743951, -3.5924439538205653e-17),
+    dd(-0.9156330263921321, -8.295686159865963e-18),
+    dd(0.9156330263921321, 8.295686159865963e-18),
+    dd(-0.9542592806289382, -3.041911712650031e-17),
+    dd(0.9542592806289382, 3.041911712650031e-17),
+    dd(-0.9813031653708727, -5.501388898113071e-17),
+    dd(0.9813031653708727, 5.501388898113071e-17),
+    dd(-0.9964424975739544, -2.7820620324038694e-17),
+    dd(0.9964424975739544, 2.7820620324038694e-17),
+    dd(0.0, 0.0),
+    dd(-0.10627823013267923, -1.4653180287692396e-19),
+    dd(0.10627823013267923, 1.4653180287692396e-19),
+    dd(-0.21135228616600107, -1.8737715288473492e-19),
+    dd(0.21135228616600107, 1.8737715288473492e-19),
+    dd(-0.31403163786763993, -4.677166541499497e-19),
+    dd(0.31403163786763993, 4.677166541499497e-19),
+    dd(-0.41315288817400864, -2.7144924544487754e-17),
+    dd(0.41315288817400864, 2.7144924544487754e-17),
+    dd(-0.5075929551242276, -1.5849966759168813e-18),
+    dd(0.5075929551242276, 1.5849966759168813e-18),
+    dd(-0.5962817971382278, -3.3422850408266254e-21),
+    dd(0.5962817971382278, 3.3422850408266254e-21),
+    dd(-0.6782145376026865, -5.266431059694946e-17),
+    dd(0.6782145376026865, 5.266431059694946e-17),
+    dd(-0.7524628517344771, -1.0009023506699218e-20),
+    dd(0.7524628517344771, 1.0009023506699218e-20),
+    dd(-0.8181854876152524, 4.177685749712126e-18),
+    dd(0.8181854876152524, -4.177685749712126e-18),
+    dd(-0.8746378049201028, 2.244603845617825e-20),
+    dd(0.8746378049201028, -2.244603845617825e-20),
+    dd(-0.9211802329530587, -5.4322208420924045e-17),
+    dd(0.9211802329530587, 5.4322208420924045e-17),
+    dd(-0.9572855957780877, -3.1257549078549954e-17),
+    dd(0.9572855957780877, 3.1257549078549954e-17),
+    dd(-0.9825455052614132, -2.0285755456201284e-17),
+    dd(0.9825455052614132, 2.0285755456201284e-17),
+    dd(-0.9966794422605966, -1.5294833119995565e-17),
+    dd(0.9966794422605966, 1.5294833119995565e-17),
+    dd(-0.0514718425553177, 2.5314224518047992e-18),
+    dd(0.0514718425553177, -2.5314224518047992e-18),
+    dd(-0.15386991360858354, -5.243984899758083e-18),
+    dd(0.15386991360858354, 5.243984899758083e-18),
+    dd(-0.25463692616788985, 7.904589832209049e-18),
+    dd(0.25463692616788985, -7.904589832209049e-18),
+    dd(-0.3527047255308781, 2.486696391317402e-18),
+    dd(0.3527047255308781, -2.486696391317402e-18),
+    dd(-0.44703376953808915, -2.2719754010484432e-17),
+    dd(0.44703376953808915, 2.2719754010484432e-17),
+    dd(-0.5366241481420199, -3.591409801999972e-17),
+    dd(0.5366241481420199, 3.591409801999972e-17),
+    dd(-0.6205261829892429, 3.0389590834717044e-17),
+    dd(0.6205261829892429, -3.0389590834717044e-17),
+    dd(-0.6978504947933158, 4.838875432067803e-17),
+    dd(0.6978504947933158, -4.838875432067803e-17),
+    dd(-0.7677774321048262, -9.729448743183057e-18),
+    dd(0.7677774321048262, 9.729448743183057e-18),
+    dd(-0.8295657623827684, -4.055666688840394e-17),
+    dd(0.8295657623827684, 4.055666688840394e-17),
+    dd(-0.8825605357920527, 5.45273289997162e-17),
+    dd(0.8825605357920527, -5.45273289997162e-17),
+    dd(-0.9262000474292743, -1.6805036846959143e-17),
+    dd(0.9262000474292743, 1.6805036846959143e-17),
+    dd(-0.9600218649683075, 3.558831088624457e-17),
+    dd(0.9600218649683075, -3.558831088624457e-17),
+    dd(-0.9836681232797472, -3.47450918343211e-17),
+    dd(0.9836681232797472, 3.47450918343211e-17),
+    dd(-0.9968934840746495, -3.5082771339583354e-17),
+    dd(0.9968934840746495, 3.5082771339583354e-17),
+    dd(0.0, 0.0),
+    dd(-0.09955531215234152, 9.8937213368536e-19),
+    dd(0.09955531215234152, -9.8937213368536e-19),
+    dd(-0.19812119933557062, -4.0946415619249805e-18),
+    dd(0.19812119933557062, 4.0946415619249805e-18),
+    dd(-0.29471806998170164, 1.921093739260491e-17),
+    dd(0.29471806998170164, -1.921093739260491e-17),
+    dd(-0.38838590160823294, -1.2217912352158942e-18),
+    dd(0.38838590160823294, 1.2217912352158942e-18),
+    dd(-0.4781937820449025, 1.8571494784907803e-18),
+    dd(0.4781937820449025, -1.8571494784907803e-18),
+    dd(-0.5632491614071493, 4.973175743744745e-17),
+    dd(0.5632491614071493, -4.973175743744745e-17),
+    dd(-0.6427067229242603, -3.1849153213658546e-18),
+    dd(0.6427067229242603, 3.1849153213658546e-18),
+    dd(-0.7157767845868532, -5.19772994171078e-17),
+    dd(0.7157767845868532, 5.19772994171078e-17),
+    dd(-0.7817331484166249, -5.0352776634742754e-17),
+    dd(0.7817331484166249, 5.0352776634742754e-17),
+    dd(-0.8399203201462674, 4.503273823173667e-17),
+    dd(0.8399203201462674, -4.503273823173667e-17),
+    dd(-0.8897600299482711, 3.7238961562921083e-17),
+    dd(0.8897600299482711, -3.7238961562921083e-17),
+    dd(-0.9307569978966481, -4.550649900305622e-17),
+    dd(0.9307569978966481, 4.550649900305622e-17),
+    dd(-0.9625039250929497, 2.411533416563998e-17),
+    dd(0.9625039250929497, -2.411533416563998e-17),
+    dd(-0.9846859096651525, -2.5010865806573923e-17),
+    dd(0.9846859096651525, 2.5010865806573923e-17),
+    dd(-0.997087481819477, -3.457593325093095e-17),
+    dd(0.997087481819477, 3.457593325093095e-17),
+    dd(-0.04830766568773832, 1.0823887937355691e-18),
+    dd(0.04830766568773832, -1.0823887937355691e-18),
+    dd(-0.1444719615827965, -5.896477317927168e-18),
+    dd(0.1444719615827965, 5.896477317927168e-18),
+    dd(-0.23928736225213706, -9.866977009938267e-18),
+    dd(0.23928736225213706, 9.866977009938267e-18),
+    dd(-0.33186860228212767, 1.7322512507942286e-17),
+    dd(0.33186860228212767, -1.7322512507942286e-17),
+    dd(-0.42135127613063533, -1.2669012582310482e-17),
+    dd(0.42135127613063533, 1.2669012582310482e-17),
+    dd(-0.5068999089322294, -3.0608658253958064e-17),
+    dd(0.5068999089322294, 3.0608658253958064e-17),
+    dd(-0.5877157572407623, -2.4748697774509385e-17),
+    dd(0.5877157572407623, 2.4748697774509385e-17),
+    dd(-0.6630442669302152, 3.045078950748031e-17),
+    dd(0.6630442669302152, -3.045078950748031e-17),
+    dd(-0.7321821187402897, 3.0924248426473855e-17),
+    dd(0.7321821187402897, -3.0924248426473855e-17),
+    dd(-0.7944837959679424, -2.1411189707536002e-17),
+    dd(0.7944837959679424, 2.1411189707536002e-17),
+    dd(-0.84936761373257, 2.9688180781309036e-19),
+    dd(0.84936761373257, -2.9688180781309036e-19),
+    dd(-0.8963211557660521, -3.27914172951693e-17),
+    dd(0.8963211557660521, 3.27914172951693e-17),
+    dd(-0.9349060759377397, -2.2400720424980087e-17),
+    dd(0.9349060759377397, 2.2400720424980087e-17),
+    dd(-0.9647622555875064, -4.088711358450183e-17),
+    dd(0.9647622555875064, 4.088711358450183e-17),
+    dd(-0.9856115115452684, 4.629527539902715e-17),
+    dd(0.9856115115452684, -4.629527539902715e-17),
+    dd(-0.9972638618494816, 6.33174860761419e-18),
+    dd(0.9972638618494816, -6.33174860761419e-18),
+    dd(0.0, 0.0),
+    dd(-0.09363106585473338, -4.944071880419147e-18),
+    dd(0.09363106585473338, 4.944071880419147e-18),
+    dd(-0.18643929882799157, -4.838179274180084e-18),
+    dd(0.18643929882799157, 4.838179274180084e-18),
+    dd(-0.27760909715249704, 1.2479420259863488e-17),
+    dd(0.27760909715249704, -1.2479420259863488e-17),
+    dd(-0.36633925774807335, 1.3666596840818503e-17),
+    dd(0.36633925774807335, -1.3666596840818503e-17),
+    dd(-0.4518500172724507, 2.5613545126967956e-17),
+    dd(0.4518500172724507, -2.5613545126967956e-17),
+    dd(-0.5333899047863476, -1.9616053631352864e-17),
+    dd(0.5333899047863476, 1.9616053631352864e-17),
+    dd(-0.610242345836379, 1.363915248643251e-17),
+    dd(0.610242345836379, -1.363915248643251e-17),
+    dd(-0.6817319599697428, -1.7240493219367196e-18),
+    dd(0.6817319599697428, 1.7240493219367196e-18),
+    dd(-0.7472304964495622, 2.920776226205102e-17),
+    dd(0.7472304964495622, -2.920776226205102e-17),
+    dd(-0.8061623562741665, -4.2608624600266046e-17),
+    dd(0.8061623562741665, 4.2608624600266046e-17),
+    dd(-0.8580096526765041, 2.1878385854088768e-17),
+    dd(0.8580096526765041, -2.1878385854088768e-17),
+    dd(-0.9023167677434336, 2.8985151701179234e-17),
+    dd(0.9023167677434336, -2.8985151701179234e-17),
+    dd(-0.9386943726111684, 4.2416805220992643e-17),
+    dd(0.9386943726111684, -4.2416805220992643e-17),
+    dd(-0.9668229096899927, -3.1203271653275016e-17),
+    dd(0.9668229096899927, 3.1203271653275016e-17),
+    dd(-0.9864557262306425, -5.810860248269684e-18),
+    dd(0.9864557262306425, 5.810860248269684e-18),
+    dd(-0.9974246942464552, -2.7105351944250053e-17),
+    dd(0.9974246942464552, 2.7105351944250053e-17),
+    dd(-0.04550982195310254, -2.5006275773446803e-18),
+    dd(0.04550982195310254, 2.5006275773446803e-18),
+    dd(-0.13615235725918298, 6.368683824648468e-18),
+    dd(0.13615235725918298, -6.368683824648468e-18),
+    dd(-0.22566669161644948, -6.481988947244091e-18),
+    dd(0.22566669161644948, 6.481988947244091e-18),
+    dd(-0.3133110813394632, -2.0877839425984293e-17),
+    dd(0.3133110813394632, 2.0877839425984293e-17),
+    dd(-0.39835927775864594, -5.15710389951769e-18),
+    dd(0.39835927775864594, 5.15710389951769e-18),
+    dd(-0.480106545190327, -6.6105815227132305e-18),
+    dd(0.480106545190327, 6.6105815227132305e-18),
+    dd(-0.5578755006697467, 2.844201286600093e-17),
+    dd(0.5578755006697467, -2.844201286600093e-17),
+    dd(-0.6310217270805285, -4.029052979980266e-17),
+    dd(0.6310217270805285, 4.029052979980266e-17),
+    dd(-0.6989391132162629, -1.0700588673651796e-17),
+    dd(0.6989391132162629, 1.0700588673651796e-17),
+    dd(-0.761064876629873, -2.5820155385559366e-17),
+    dd(0.761064876629873, 2.5820155385559366e-17),
+    dd(-0.8168842279009336, -4.0791095432544704e-17),
+    dd(0.8168842279009336, 4.0791095432544704e-17),
+    dd(-0.8659346383345645, 5.223659748535799e-17),
+    dd(0.8659346383345645, -5.223659748535799e-17),
+    dd(-0.9078096777183244, -3.177738832512588e-17),
+    dd(0.9078096777183244, 3.177738832512588e-17),
+    dd(-0.9421623974051071, 7.692408148980368e-18),
+    dd(0.9421623974051071, -7.692408148980368e-18),
+    dd(-0.9687082625333443, 1.8018164930917795e-17),
+    dd(0.9687082625333443, -1.8018164930917795e-17),
+    dd(-0.9872278164063095, 3.6774163679328035e-17),
+    dd(0.9872278164063095, -3.6774163679328035e-17),
+    dd(-0.997571753790842, 3.3747913797009866e-17),
+    dd(0.997571753790842, -3.3747913797009866e-17),
+    dd(0.0, 0.0),
+    dd(-0.08837134327565926, 6.815661084225218e-19),
+    dd(0.08837134327565926, -6.815661084225218e-19),
+    dd(-0.17605106116598956, -5.857518652001105e-18),
+    dd(0.17605106116598956, 5.857518652001105e-18),
+    dd(-0.26235294120929603, -2.684887275926014e-17),
+    dd(0.26235294120929603, 2.684887275926014e-17),
+    dd(-0.3466015544308139, -2.7415483975483653e-17),
+    dd(0.3466015544308139, 2.7415483975483653e-17),
+    dd(-0.42813754151781425, -7.904085273102817e-18),
+    dd(0.42813754151781425, 7.904085273102817e-18),
+    dd(-0.5063227732414887, 5.112731313110118e-17),
+    dd(0.5063227732414887, -5.112731313110118e-17),
+    dd(-0.5805453447497645, -1.8502370700087873e-17),
+    dd(0.5805453447497645, 1.8502370700087873e-17),
+    dd(-0.6502243646658904, 8.820846443510228e-18),
+    dd(0.6502243646658904, -8.820846443510228e-18),
+    dd(-0.7148145015566287, -4.9128652100192646e-17),
+    dd(0.7148145015566287, 4.9128652100192646e-17),
+    dd(-0.7738102522869126, 2.5731577956465917e-17),
+    dd(0.7738102522869126, -2.5731577956465917e-17),
+    dd(-0.8267498990922254, -2.5383754509009455e-18),
+    dd(0.8267498990922254, 2.5383754509009455e-18),
+    dd(-0.8732191250252224, 3.3132237064503993e-17),
+    dd(0.8732191250252224, -3.3132237064503993e-17),
+    dd(-0.9128542613593176, -3.861253585821723e-17),
+    dd(0.9128542613593176, 3.861253585821723e-17),
+    dd(-0.9453451482078273, 5.368723327065443e-18),
+    dd(0.9453451482078273, -5.368723327065443e-18),
+    dd(-0.9704376160392298, 1.1273929760317196e-17),
+    dd(0.9704376160392298, -1.1273929760317196e-17),
+    dd(-0.9879357644438514, -4.8400779775275705e-17),
+    dd(0.9879357644438514, 4.8400779775275705e-17),
+    dd(-0.9977065690996003, 1.8293125825708233e-17),
+    dd(0.9977065690996003, -1.8293125825708233e-17),
+    dd(-0.04301819847370861, 3.8285327378751083e-19),
+    dd(0.04301819847370861, -3.8285327378751083e-19),
+    dd(-0.1287361038093848, 1.1148958849086981e-17),
+    dd(0.1287361038093848, -1.1148958849086981e-17),
+    dd(-0.2135008923168656, 8.05185204550995e-18),
+    dd(0.2135008923168656, -8.05185204550995e-18),
+    dd(-0.29668499534402826, -1.3267348014839387e-17),
+    dd(0.29668499534402826, 1.3267348014839387e-17),
+    dd(-0.37767254711968923, 1.1767330600592223e-17),
+    dd(0.37767254711968923, -1.1767330600592223e-17),
+    dd(-0.45586394443342027, -2.150606426856178e-18),
+    dd(0.45586394443342027, 2.150606426856178e-18),
+    dd(-0.5306802859262452, 3.843551643920972e-18),
+    dd(0.5306802859262452, -3.843551643920972e-18),
+    dd(-0.6015676581359806, 3.0168301446032566e-17),
+    dd(0.6015676581359806, -3.0168301446032566e-17),
+    dd(-0.668001236585521, -4.33012612405346e-17),
+    dd(0.668001236585521, 4.33012612405346e-17),
+    dd(-0.7294891715935565, -5.302954038400262e-17),
+    dd(0.7294891715935565, 5.302954038400262e-17),
+    dd(-0.7855762301322066, 5.26651671138735e-17),
+    dd(0.7855762301322066, -5.26651671138735e-17),
+    dd(-0.8358471669924753, -7.499902147832018e-18),
+    dd(0.8358471669924753, 7.499902147832018e-18),
+    dd(-0.8799298008903972, 5.262709865597144e-17),
+    dd(0.8799298008903972, -5.262709865597144e-17),
+    dd(-0.9174977745156591, -6.9067857212761136e-18),
+    dd(0.9174977745156591, 6.9067857212761136e-18),
+    dd(-0.9482729843995076, 3.393160685516165e-17),
+    dd(0.9482729843995076, -3.393160685516165e-17),
+    dd(-0.972027691049698, 4.562713658332147e-17),
+    dd(0.972027691049698, -4.562713658332147e-17),
+    dd(-0.9885864789022122, -5.3501543513649045e-17),
+    dd(0.9885864789022122, 5.3501543513649045e-17),
+    dd(-0.9978304624840858, -3.5609188997690127e-17),
+    dd(0.9978304624840858, 3.5609188997690127e-17),
+    dd(0.0, 0.0),
+    dd(-0.0836704089547699, 1.754675189226937e-18),
+    dd(0.0836704089547699, -1.754675189226937e-18),
+    dd(-0.16675393023985197, -1.0691329331586258e-17),
+    dd(0.16675393023985197, 1.0691329331586258e-17),
+    dd(-0.24866779279136575, -9.48915540350764e-18),
+    dd(0.24866779279136575, 9.48915540350764e-18),
+    dd(-0.328837429883707, 1.138182865732623e-17),
+    dd(0.328837429883707, -1.138182865732623e-17),
+    dd(-0.40670050931832613, 2.0957609203879206e-17),
+    dd(0.40670050931832613, -2.0957609203879206e-17),
+    dd(-0.48171087780320554, -1.4753576705303357e-17),
+    dd(0.48171087780320554, 1.4753576705303357e-17),
+    dd(-0.5533423918615817, -4.183665262426086e-17),
+    dd(0.5533423918615817, 4.183665262426086e-17),
+    dd(-0.6210926084089244, -4.281514269400933e-17),
+    dd(0.6210926084089244, 4.281514269400933e-17),
+    dd(-0.6844863091309593, -4.3505555142968814e-17),
+    dd(0.6844863091309593, 4.3505555142968814e-17),
+    dd(-0.7430788339819653, 1.847215224755083e-17),
+    dd(0.7430788339819653, -1.847215224755083e-17),
+    dd(-0.7964592005099023, -2.804056649120444e-17),
+    dd(0.7964592005099023, 2.804056649120444e-17),
+    dd(-0.844252987340556, 1.461083284360334e-17),
+    dd(0.844252987340556, -1.461083284360334e-17),
+    dd(-0.8861249621554861, 5.523508216986902e-18),
+    dd(0.8861249621554861, -5.523508216986902e-18),
+    dd(-0.9217814374124638, 2.3965974969228627e-17),
+    dd(0.9217814374124638, -2.3965974969228627e-17),
+    dd(-0.9509723432620948, -3.3276323926797704e-17),
+    dd(0.9509723432620948, 3.3276323926797704e-17),
+    dd(-0.9734930300564858, 5.3123235531890455e-17),
+    dd(0.9734930300564858, -5.3123235531890455e-17),
+    dd(-0.9891859632143192, -1.1732060722351021e-17),
+    dd(0.9891859632143192, 1.1732060722351021e-17),
+    dd(-0.9979445824779136, -2.6897983122132564e-17),
+    dd(0.9979445824779136, 2.6897983122132564e-17),
+    dd(-0.04078514790457824, -4.494145092844436e-19),
+    dd(0.04078514790457824, 4.494145092844436e-19),
+    dd(-0.12208402533786741, -6.473003741187913e-18),
+    dd(0.12208402533786741, 6.473003741187913e-18),
+    dd(-0.2025704538921167, -5.701021368723798e-18),
+    dd(0.2025704538921167, 5.701021368723798e-18),
+    dd(-0.28170880979016527, 9.654103224978265e-18),
+    dd(0.28170880979016527, -9.654103224978265e-18),
+    dd(-0.358972440479435, -1.7803883320934308e-17),
+    dd(0.358972440479435, 1.7803883320934308e-17),
+    dd(-0.4338471694323765, 1.606870513532029e-17),
+    dd(0.4338471694323765, -1.606870513532029e-17),
+    dd(-0.5058347179279311, 6.863577950701839e-18),
+    dd(0.5058347179279311, -6.863577950701839e-18),
+    dd(-0.5744560210478071, 4.9353334893772934e-17),
+    dd(0.5744560210478071, -4.9353334893772934e-17),
+    dd(-0.6392544158296817, -3.02054732625559e-17),
+    dd(0.6392544158296817, 3.02054732625559e-17),
+    dd(-0.6997986803791844, 9.461311761840574e-19),
+    dd(0.6997986803791844, -9.461311761840574e-19),
+    dd(-0.7556859037539707, 3.061181430590089e-17),
+    dd(0.7556859037539707, -3.061181430590089e-17),
+    dd(-0.8065441676053168, -4.033572522331116e-17),
+    dd(0.8065441676053168, 4.033572522331116e-17),
+    dd(-0.8520350219323621, -5.33935283667403e-17),
+    dd(0.8520350219323621, 5.33935283667403e-17),
+    dd(-0.8918557390046322, 2.95138985018542e-18),
+    dd(0.8918557390046322, -2.95138985018542e-18),
+    dd(-0.9257413320485844, 4.530192760181452e-17),
+    dd(0.9257413320485844, -4.530192760181452e-17),
+    dd(-0.9534663309335296, 1.985059595882687e-17),
+    dd(0.9534663309335296, -1.985059595882687e-17),
+    dd(-0.9748463285901535, 1.371312590456358e-17),
+    dd(0.9748463285901535, -1.371312590456358e-17),
+    dd(-0.9897394542663855, -2.870925206383563e-17),
+    dd(0.9897394542663855, 2.870925206383563e-17),
+    dd(-0.9980499305356876, -4.037690315964591e-17),
+    dd(0.9980499305356876, 4.037690315964591e-17),
+    dd(0.0, 0.0),
+    dd(-0.07944380460875548, 5.749966257077356e-18),
+    dd(0.07944380460875548, -5.749966257077356e-18),
+    dd(-0.1583853399978378, -1.4357883864723727e-18),
+    dd(0.1583853399978378, 1.4357883864723727e-18),
+    dd(-0.23632551246183578, 1.2084831539049849e-17),
+    dd(0.23632551246183578, -1.2084831539049849e-17),
+    dd(-0.31277155924818595, 2.6029182703102992e-17),
+    dd(0.31277155924818595, -2.6029182703102992e-17),
+    dd(-0.38724016397156147, 1.2660555399592278e-17),
+    dd(0.38724016397156147, -1.2660555399592278e-17),
+    dd(-0.45926051230913606, 1.105326118301013e-17),
+    dd(0.45926051230913606, -1.105326118301013e-17),
+    dd(-0.5283772686604374, -3.588818417492999e-17),
+    dd(0.5283772686604374, 3.588818417492999e-17),
+    dd(-0.594153454957278, 1.5707332851432248e-17),
+    dd(0.594153454957278, -1.5707332851432248e-17),
+    dd(-0.656173213432011, 4.922124990898132e-17),
+    dd(0.656173213432011, -4.922124990898132e-17),
+    dd(-0.7140444358945347, 2.2291717408071373e-17),
+    dd(0.7140444358945347, -2.2291717408071373e-17),
+    dd(-0.7674012429310635, -1.2314354839100043e-17),
+    dd(0.7674012429310635, 1.2314354839100043e-17),
+    dd(-0.8159062974301431, -1.3965388638030935e-17),
+    dd(0.8159062974301431, 1.3965388638030935e-17),
+    dd(-0.8592529379999062, 5.3886140889991096e-17),
+    dd(0.8592529379999062, -5.3886140889991096e-17),
+    dd(-0.8971671192929929, -2.274997818626821e-17),
+    dd(0.8971671192929929, 2.274997818626821e-17),
+    dd(-0.9294091484867383, 4.9384613871484655e-17),
+    dd(0.9294091484867383, -4.9384613871484655e-17),
+    dd(-0.9557752123246522, -4.2447544980259165e-17),
+    dd(0.9557752123246522, 4.2447544980259165e-17),
+    dd(-0.9760987093334711, 3.9052277299036363e-17),
+    dd(0.9760987093334711, -3.9052277299036363e-17),
+    dd(-0.990251536854686, 4.8290097907390265e-17),
+    dd(0.990251536854686, -4.8290097907390265e-17),
+    dd(-0.9981473830664329, 7.87295734183109e-18),
+    dd(0.9981473830664329, -7.87295734183109e-18),
+    dd(-0.03877241750605082, 8.331790327107182e-19),
+    dd(0.03877241750605082, -8.331790327107182e-19),
+    dd(-0.11608407067525521, 1.9085227190063375e-18),
+    dd(0.11608407067525521, -1.9085227190063375e-18),
+    dd(-0.1926975807013711, 7.565744322583385e-18),
+    dd(0.1926975807013711, -7.565744322583385e-18),
+    dd(-0.2681521850072537, 3.859521100609538e-18),
+    dd(0.2681521850072537, -3.859521100609538e-18),
+    dd(-0.3419940908257585, 1.6941289944747572e-17),
+    dd(0.3419940908257585, -1.6941289944747572e-17),
+    dd(-0.413779204371605, -2.095249418877874e-17),
+    dd(0.413779204371605, 2.095249418877874e-17),
+    dd(-0.4830758016861787, -1.6593811430996348e-17),
+    dd(0.4830758016861787, 1.6593811430996348e-17),
+    dd(-0.5494671250951282, -1.8288834773642444e-17),
+    dd(0.5494671250951282, 1.8288834773642444e-17),
+    dd(-0.6125538896679802, -4.871507301568712e-17),
+    dd(0.6125538896679802, 4.871507301568712e-17),
+    dd(-0.6719566846141796, 1.8470757253910518e-17),
+    dd(0.6719566846141796, -1.8470757253910518e-17),
+    dd(-0.7273182551899271, -4.611496368849061e-18),
+    dd(0.7273182551899271, 4.611496368849061e-18),
+    dd(-0.7783056514265194, 3.04284904008729e-17),
+    dd(0.7783056514265194, -3.04284904008729e-17),
+    dd(-0.8246122308333117, 3.5833443755224796e-17),
+    dd(0.8246122308333117, -3.5833443755224796e-17),
+    dd(-0.8659595032122595, -5.2255223997672983e-17),
+    dd(0.8659595032122595, 5.2255223997672983e-17),
+    dd(-0.9020988069688743, 4.8196560282165127e-17),
+    dd(0.9020988069688743, -4.8196560282165127e-17),
+    dd(-0.9328128082786765, -1.3747581609970865e-17),
+    dd(0.9328128082786765, 1.3747581609970865e-17),
+    dd(-0.9579168192137917, 2.5715324051633767e-17),
+    dd(0.9579168192137917, -2.5715324051633767e-17),
+    dd(-0.9772599499837743, 3.823338471031511e-17),
+    dd(0.9772599499837743, -3.823338471031511e-17),
+    dd(-0.990726238699457, -3.2839887741772983e-17),
+    dd(0.990726238699457, 3.2839887741772983e-17),
+    dd(-0.9982377097105593, 5.0814263371789e-17),
+    dd(0.9982377097105593, -5.0814263371789e-17),
+    dd(0.0, 0.0),
+    dd(-0.075623258989163, 3.341654839312586e-18),
+    dd(0.075623258989163, -3.341654839312586e-18),
+    dd(-0.15081335486399217, 2.591664384530806e-18),
+    dd(0.15081335486399217, -2.591664384530806e-18),
+    dd(-0.22513960563342278, 3.4904780645414685e-18),
+    dd(0.22513960563342278, -3.4904780645414685e-18),
+    dd(-0.2981762773418249, 2.537523359063492e-17),
+    dd(0.2981762773418249, -2.537523359063492e-17),
+    dd(-0.36950502264048146, 2.0520158152663686e-17),
+    dd(0.36950502264048146, -2.0520158152663686e-17),
+    dd(-0.43871727705140706, -2.663409383941538e-17),
+    dd(0.43871727705140706, 2.663409383941538e-17),
+    dd(-0.5054165991994061, 3.644555873471016e-17),
+    dd(0.5054165991994061, -3.644555873471016e-17),
+    dd(-0.5692209416102159, -1.1535088154961697e-17),
+    dd(0.5692209416102159, 1.1535088154961697e-17),
+    dd(-0.6297648390721963, -5.081276398645145e-17),
+    dd(0.6297648390721963, 5.081276398645145e-17),
+    dd(-0.6867015020349513, -6.3136900654721795e-18),
+    dd(0.6867015020349513, 6.3136900654721795e-18),
+    dd(-0.7397048030699261, -3.878193450657624e-17),
+    dd(0.7397048030699261, 3.878193450657624e-17),
+    dd(-0.7884711450474093, -3.413417046068996e-17),
+    dd(0.7884711450474093, 3.413417046068996e-17),
+    dd(-0.8327212004013613, -3.4523124584384566e-18),
+    dd(0.8327212004013613, 3.4523124584384566e-18),
+    dd(-0.8722015116924414, 1.358498289963487e-17),
+    dd(0.8722015116924414, -1.358498289963487e-17),
+    dd(-0.9066859447581012, -1.4111499041234163e-17),
+    dd(0.9066859447581012, 1.4111499041234163e-17),
+    dd(-0.9359769874978539, 2.6067278012560867e-17),
+    dd(0.9359769874978539, -2.6067278012560867e-17),
+    dd(-0.9599068917303463, 4.1699792587233526e-17),
+    dd(0.9599068917303463, -4.1699792587233526e-17),
+    dd(-0.9783386735610834, 9.501079492671206e-18),
+    dd(0.9783386735610834, -9.501079492671206e-18),
+    dd(-0.9911671096990163, 2.7183282432467614e-17),
+    dd(0.9911671096990163, -2.7183282432467614e-17),
+    dd(-0.9983215885747715, 4.8646271855553234e-17),
+    dd(0.9983215885747715, -4.8646271855553234e-17),
+    dd(-0.03694894316535178, 3.048086927538662e-18),
+    dd(0.03694894316535178, -3.048086927538662e-18),
+    dd(-0.11064502720851987, 6.590318251876712e-18),
+    dd(0.11064502720851987, -6.590318251876712e-18),
+    dd(-0.18373680656485455, 3.682659692503253e-18),
+    dd(0.18373680656485455, -3.682659692503253e-18),
+    dd(-0.25582507934287907, -1.4711224829977966e-17),
+    dd(0.25582507934287907, 1.4711224829977966e-17),
+    dd(-0.3265161244654115, -6.391011602157034e-18),
+    dd(0.3265161244654115, 6.391011602157034e-18),
+    dd(-0.39542385204297503, -2.5137975680450242e-17),
+    dd(0.39542385204297503, 2.5137975680450242e-17),
+    dd(-0.4621719120704219, -2.1167519636644236e-17),
+    dd(0.4621719120704219, 2.1167519636644236e-17),
+    dd(-0.5263957499311923, 2.623789583746214e-17),
+    dd(0.5263957499311923, -2.623789583746214e-17),
+    dd(-0.5877445974851093, -1.5075703484616171e-18),
+    dd(0.5877445974851093, 1.5075703484616171e-18),
+    dd(-0.6458833888692478, -4.63682512680852e-17),
+    dd(0.6458833888692478, 4.63682512680852e-17),
+    dd(-0.7004945905561712, 3.451075629337219e-17),
+    dd(0.7004945905561712, -3.451075629337219e-17),
+    dd(-0.7512799356894805, -7.038271842201804e-18),
+    dd(0.7512799356894805, 7.038271842201804e-18),
+    dd(-0.7979620532554874, 2.6180070184142893e-19),
+    dd(0.7979620532554874, -2.6180070184142893e-19),
+    dd(-0.8402859832618169, 1.4653905282278965e-17),
+    dd(0.8402859832618169, -1.4653905282278965e-17),
+    dd(-0.8780205698121727, -5.177422797884905e-17),
+    dd(0.8780205698121727, 5.177422797884905e-17),
+    dd(-0.9109597249041275, 1.3221611117120018e-17),
+    dd(0.9109597249041275, -1.3221611117120018e-17),
+    dd(-0.9389235573549882, 4.6687719694072627e-17),
+    dd(0.9389235573549882, -4.6687719694072627e-17),
+    dd(-0.9617593653382045, 8.395344362862118e-18),
+    dd(0.9617593653382045, -8.395344362862118e-18),
+    dd(-0.9793425080637482, 3.5687611958044416e-17),
+    dd(0.9793425080637482, -3.5687611958044416e-17),
+    dd(-0.9915772883408609, -2.3593769308286462e-17),
+    dd(0.9915772883408609, 2.3593769308286462e-17),
+    dd(-0.9983996189900625, 5.012855680396225e-17),
+    dd(0.9983996189900625, -5.012855680396225e-17),
+    dd(0.0, 0.0),
+    dd(-0.07215299087458624, 1.5244699065876265e-18),
+    dd(0.07215299087458624, -1.5244699065876265e-18),
+    dd(-0.14392980951071332, 1.3857017950760436e-17),
+    dd(0.14392980951071332, -1.3857017950760436e-17),
+    dd(-0.2149562448605182, -6.004520629737083e-18),
+    dd(0.2149562448605182, 6.004520629737083e-18),
+    dd(-0.2848619980329136, -5.6798461680490544e-18),
+    dd(0.2848619980329136, 5.6798461680490544e-18),
+    dd(-0.3532826128643038, -1.5578432006156e-17),
+    dd(0.3532826128643038, 1.5578432006156e-17),
+    dd(-0.41986137602926926, 9.557328163421522e-18),
+    dd(0.41986137602926926, -9.557328163421522e-18),
+    dd(-0.4842511767857347, -1.9148095693719345e-17),
+    dd(0.4842511767857347, 1.9148095693719345e-17),
+    dd(-0.5461163166600848, 3.602309058176936e-17),
+    dd(0.5461163166600848, -3.602309058176936e-17),
+    dd(-0.605134259639601, 5.5240308176176475e-17),
+    dd(0.605134259639601, -5.5240308176176475e-17),
+    dd(-0.6609973137514982, 3.9086651181522346e-17),
+    dd(0.6609973137514982, -3.9086651181522346e-17),
+    dd(-0.7134142352689571, 3.1659044959087497e-17),
+    dd(0.7134142352689571, -3.1659044959087497e-17),
+    dd(-0.7621117471949551, -7.811355352344154e-18),
+    dd(0.7621117471949551, 7.811355352344154e-18),
+    dd(-0.8068359641369386, -4.7103123459577e-17),
+    dd(0.8068359641369386, 4.7103123459577e-17),
+    dd(-0.8473537162093151, 5.0839675312310397e-17),
+    dd(0.8473537162093151, -5.0839675312310397e-17),
+    dd(-0.8834537652186168, -1.4517701117195286e-17),
+    dd(0.8834537652186168, 1.4517701117195286e-17),
+    dd(-0.9149479072061387, -7.531087837023578e-18),
+    dd(0.9149479072061387, 7.531087837023578e-18),
+    dd(-0.9416719568476378, -3.6335600029770186e-17),
+    dd(0.9416719568476378, 3.6335600029770186e-17),
+    dd(-0.96348661301408, 1.326814634224044e-17),
+    dd(0.96348661301408, -1.326814634224044e-17),
+    dd(-0.9802782209802553, -3.221719872066986e-17),
+    dd(0.9802782209802553, 3.221719872066986e-17),
+    dd(-0.9919595575932442, 2.13628343702962e-17),
+    dd(0.9919595575932442, -2.13628343702962e-17),
+    dd(-0.9984723322425078, 4.0556631535580703e-17),
+    dd(0.9984723322425078, -4.0556631535580703e-17),
+    dd(-0.035289236964135356, -2.569116040853703e-18),
+    dd(0.035289236964135356, 2.569116040853703e-18),
+    dd(-0.10569190170865325, 4.839398517505914e-18),
+    dd(0.10569190170865325, -4.839398517505914e-18),
+    dd(-0.17556801477551678, -1.6037129588123896e-18),
+    dd(0.17556801477551678, 1.6037129588123896e-18),
+    dd(-0.24456945692820126, 4.9775092306187834e-18),
+    dd(0.24456945692820126, -4.9775092306187834e-18),
+    dd(-0.3123524665027858, -3.969893961725271e-18),
+    dd(0.3123524665027858, 3.969893961725271e-18),
+    dd(-0.37857935201470716, 2.751171204469755e-17),
+    dd(0.37857935201470716, -2.751171204469755e-17),
+    dd(-0.4429201745254115, 8.082199702858042e-18),
+    dd(0.4429201745254115, -8.082199702858042e-18),
+    dd(-0.5050543913882023, -2.2928243627661906e-17),
+    dd(0.5050543913882023, 2.2928243627661906e-17),
+    dd(-0.5646724531854708, 6.394841651817039e-20),
+    dd(0.5646724531854708, -6.394841651817039e-20),
+    dd(-0.6214773459035758, -8.188361074072454e-18),
+    dd(0.6214773459035758, 8.188361074072454e-18),
+    dd(-0.6751860706661224, -1.4319816530877398e-17),
+    dd(0.6751860706661224, 1.4319816530877398e-17),
+    dd(-0.725531053660717, 1.537650755096555e-17),
+    dd(0.725531053660717, -1.537650755096555e-17),
+    dd(-0.7722614792487559, -2.1326534790202462e-17),
+    dd(0.7722614792487559, 2.1326534790202462e-17),
+    dd(-0.815144539645135, -8.205931304550996e-18),
+    dd(0.815144539645135, 8.205931304550996e-18),
+    dd(-0.8539665950047104, 1.4995410272454475e-17),
+    dd(0.8539665950047104, -1.4995410272454475e-17),
+    dd(-0.8885342382860432, -3.697153363689042e-17),
+    dd(0.8885342382860432, 3.697153363689042e-17),
+    dd(-0.9186752599841758, -1.1605516782973258e-17),
+    dd(0.9186752599841758, 1.1605516782973258e-17),
+    dd(-0.9442395091181941, -1.1669849738852927e-18),
+    dd(0.9442395091181941, 1.1669849738852927e-18),
+    dd(-0.9650996504224931, -1.2227908038266065e-17),
+    dd(0.9650996504224931, 1.2227908038266065e-17),
+    dd(-0.981151833077914, 1.5154881423499463e-17),
+    dd(0.981151833077914, -1.5154881423499463e-17),
+    dd(-0.9923163921385159, 4.8529865454723107e-17),
+    dd(0.9923163921385159, -4.8529865454723107e-17),
+    dd(-0.9985402006367742, -4.709130007030479e-17),
+    dd(0.9985402006367742, 4.709130007030479e-17),
+    dd(0.0, 0.0),
+    dd(-0.06898698016314417, -4.347095834519151e-18),
+    dd(0.06898698016314417, 4.347095834519151e-18),
+    dd(-0.13764520598325303, -1.5078539524731115e-18),
+    dd(0.13764520598325303, 1.5078539524731115e-18),
+    dd(-0.20564748978326375, 2.56661432554157e-18),
+    dd(0.20564748978326375, -2.56661432554157e-18),
+    dd(-0.27266976975237756, -5.181406989530853e-18),
+    dd(0.27266976975237756, 5.181406989530853e-18),
+    dd(-0.33839265425060217, 3.4346171439394338e-18),
+    dd(0.33839265425060217, -3.4346171439394338e-18),
+    dd(-0.4025029438585419, -7.925492793295258e-18),
+    dd(0.4025029438585419, 7.925492793295258e-18),
+    dd(-0.4646951239196351, -2.0554875730626016e-17),
+    dd(0.4646951239196351, 2.0554875730626016e-17),
+    dd(-0.5246728204629161, 1.1463237678858737e-17),
+    dd(0.5246728204629161, -1.1463237678858737e-17),
+    dd(-0.5821502125693532, -1.0730030405004982e-17),
+    dd(0.5821502125693532, 1.0730030405004982e-17),
+    dd(-0.6368533944532233, -4.9958462288382086e-17),
+    dd(0.6368533944532233, 4.9958462288382086e-17),
+    dd(-0.6885216807712006, 4.74251448587619e-17),
+    dd(0.6885216807712006, -4.74251448587619e-17),
+    dd(-0.7369088489454904, 5.2608412862558015e-18),
+    dd(0.7369088489454904, -5.2608412862558015e-18),
+    dd(-0.7817843125939062, -5.255931336816465e-17),
+    dd(0.7817843125939062, 5.255931336816465e-17),
+    dd(-0.8229342205020863, -2.601369225057883e-17),
+    dd(0.8229342205020863, 2.601369225057883e-17),
+    dd(-0.8601624759606642, -2.855556720034812e-17),
+    dd(0.8601624759606642, 2.855556720034812e-17),
+    dd(-0.8932916717532418, 1.7628539945108325e-17),
+    dd(0.8932916717532418, -1.7628539945108325e-17),
+    dd(-0.9221639367190004, 4.406536202278154e-17),
+    dd(0.9221639367190004, -4.406536202278154e-17),
+    dd(-0.9466416909956291, 5.301733625015725e-17),
+    dd(0.9466416909956291, -5.301733625015725e-17),
+    dd(-0.9666083103968947, 4.9645158061964966e-17),
+    dd(0.9666083103968947, -4.9645158061964966e-17),
+    dd(-0.9819687150345405, -3.9142999922906314e-17),
+    dd(0.9819687150345405, 3.9142999922906314e-17),
+    dd(-0.9926499984472037, -4.537631861136597e-17),
+    dd(0.9926499984472037, 4.537631861136597e-17),
+    dd(-0.9986036451819367, 3.400284745281008e-17),
+    dd(0.9986036451819367, -3.400284745281008e-17),
+    dd(-0.03377219001605204, 6.634123512569874e-19),
+    dd(0.03377219001605204, -6.634123512569874e-19),
+    dd(-0.10116247530558424, 6.546936436775021e-19),
+    dd(0.10116247530558424, -6.546936436775021e-19),
+    dd(-0.16809117946710353, 1.1475714589084429e-18),
+    dd(0.16809117946710353, -1.1475714589084429e-18),
+    dd(-0.23425292220626978, 1.2720620834732781e-17),
+    dd(0.23425292220626978, -1.2720620834732781e-17),
+    dd(-0.29934582270187, -2.5282321412824594e-17),
+    dd(0.29934582270187, 2.5282321412824594e-17),
+    dd(-0.3630728770209957, 1.1118551035657315e-17),
+    dd(0.3630728770209957, -1.1118551035657315e-17),
+    dd(-0.4251433132828284, -2.816205758120556e-18),
+    dd(0.4251433132828284, 2.816205758120556e-18),
+    dd(-0.48527391838816464, -2.4900779985783318e-17),
+    dd(0.48527391838816464, 2.4900779985783318e-17),
+    dd(-0.5431903302618026, -1.1445514421572859e-18),
+    dd(0.5431903302618026, 1.1445514421572859e-18),
+    dd(-0.5986282897127152, 4.7110900913554436e-17),
+    dd(0.5986282897127152, -4.7110900913554436e-17),
+    dd(-0.6513348462019977, -5.142260797834129e-17),
+    dd(0.6513348462019977, 5.142260797834129e-17),
+    dd(-0.7010695120204057, -1.457273859344912e-17),
+    dd(0.7010695120204057, 1.457273859344912e-17),
+    dd(-0.7476053596156661, 4.4526905906096565e-17),
+    dd(0.7476053596156661, -4.4526905906096565e-17),
+    dd(-0.7907300570752742, -3.770732464208268e-17),
+    dd(0.7907300570752742, 3.770732464208268e-17),
+    dd(-0.8302468370660661, 3.7881604773114656e-18),
+    dd(0.8302468370660661, -3.7881604773114656e-18),
+    dd(-0.865975394866858, -2.3693609446101062e-17),
+    dd(0.865975394866858, 2.3693609446101062e-17),
+    dd(-0.897752711533942, 1.705644721268309e-17),
+    dd(0.897752711533942, -1.705644721268309e-17),
+    dd(-0.9254337988067539, -3.539961956361434e-17),
+    dd(0.9254337988067539, 3.539961956361434e-17),
+    dd(-0.9488923634460898, 5.256683319431381e-17),
+    dd(0.9488923634460898, -5.256683319431381e-17),
+    dd(-0.9680213918539919, -1.1926158170691191e-18),
+    dd(0.9680213918539919, 1.1926158170691191e-18),
+    dd(-0.9827336698041669, 1.2627020903562185e-17),
+    dd(0.9827336698041669, -1.2627020903562185e-17),
+    dd(-0.9929623489061744, 4.386338030070698e-17),
+    dd(0.9929623489061744, -4.386338030070698e-17),
+    dd(-0.998663042133818, -2.3615237256902864e-17),
+    dd(0.998663042133818, 2.3615237256902864e-17),
+    dd(0.0, 0.0),
+    dd(-0.06608692391635568, 2.483489640248128e-18),
+    dd(0.06608692391635568, -2.483489640248128e-18),
+    dd(-0.1318848665545149, -1.3448186127344676e-18),
+    dd(0.1318848665545149, 1.3448186127344676e-18),
+    dd(-0.19710611027911182, 1.0067360740534686e-17),
+    dd(0.19710611027911182, -1.0067360740534686e-17),
+    dd(-0.26146545921497455, -2.0966856024817162e-17),
+    dd(0.26146545921497455, 2.0966856024817162e-17),
+    dd(-0.3246814863377359, 1.1727712387177615e-17),
+    dd(0.3246814863377359, -1.1727712387177615e-17),
+    dd(-0.38647776408466716, 2.3996620156552738e-17),
+    dd(0.38647776408466716, -2.3996620156552738e-17),
+    dd(-0.4465840731048557, 2.7908764636588928e-18),
+    dd(0.4465840731048557, -2.7908764636588928e-18),
+    dd(-0.5047375838635779, 1.2763025872326565e-18),
+    dd(0.5047375838635779, -1.2763025872326565e-18),
+    dd(-0.5606840059346642, -2.977771945501381e-17),
+    dd(0.5606840059346642, 2.977771945501381e-17),
+    dd(-0.6141786999563736, 3.2959811391896035e-17),
+    dd(0.6141786999563736, -3.2959811391896035e-17),
+    dd(-0.6649877473903327, -6.224076354163009e-19),
+    dd(0.6649877473903327, 6.224076354163009e-19),
+    dd(-0.7128889734090643, 1.7657959559396844e-17),
+    dd(0.7128889734090643, -1.7657959559396844e-17),
+    dd(-0.7576729184454386, -3.0110936333745364e-17),
+    dd(0.7576729184454386, 3.0110936333745364e-17),
+    dd(-0.799143754167742, 2.80018425455346e-17),
+    dd(0.799143754167742, -2.80018425455346e-17),
+    dd(-0.8371201398999021, -3.865233855749747e-17),
+    dd(0.8371201398999021, 3.865233855749747e-17),
+    dd(-0.8714360157968963, 1.8020744270813257e-17),
+    dd(0.8714360157968963, -1.8020744270813257e-17),
+    dd(-0.9019413294385253, -1.5855914460238494e-17),
+    dd(0.9019413294385253, 1.5855914460238494e-17),
+    dd(-0.9285026930123607, 9.911931759888575e-18),
+    dd(0.9285026930123607, -9.911931759888575e-18),
+    dd(-0.9510039692577085, 2.7640935915894313e-17),
+    dd(0.9510039692577085, -2.7640935915894313e-17),
+    dd(-0.9693467873265645, 4.3553081289672875e-17),
+    dd(0.9693467873265645, -4.3553081289672875e-17),
+    dd(-0.9834510030716237, -1.1526777138377524e-17),
+    dd(0.9834510030716237, 1.1526777138377524e-17),
+    dd(-0.9932552109877686, -4.6054753133706977e-17),
+    dd(0.9932552109877686, 4.6054753133706977e-17),
+    dd(-0.9987187285842121, -4.153904918975536e-17),
+    dd(0.9987187285842121, 4.153904918975536e-17),
+    dd(-0.03238017096286936, -1.615165171161926e-18),
+    dd(0.03238017096286936, 1.615165171161926e-18),
+    dd(-0.0970046992094627, -1.956238146311195e-18),
+    dd(0.0970046992094627, 1.956238146311195e-18),
+    dd(-0.1612223560688917, -9.054193107272835e-18),
+    dd(0.1612223560688917, 9.054193107272835e-18),
+    dd(-0.22476379039468905, -1.0924451316434937e-17),
+    dd(0.22476379039468905, 1.0924451316434937e-17),
+    dd(-0.28736248735545555, -2.3074443470174402e-17),
+    dd(0.28736248735545555, 2.3074443470174402e-17),
+    dd(-0.34875588629216075, 1.6820256051647457e-17),
+    dd(0.34875588629216075, -1.6820256051647457e-17),
+    dd(-0.4086864819907167, -8.691937274322318e-18),
+    dd(0.4086864819907167, 8.691937274322318e-18),
+    dd(-0.4669029047509584, 9.503582851539439e-18),
+    dd(0.4669029047509584, -9.503582851539439e-18),
+    dd(-0.523160974722233, -3.720824202353758e-17),
+    dd(0.523160974722233, 3.720824202353758e-17),
+    dd(-0.5772247260839727, -2.0363105582515247e-17),
+    dd(0.5772247260839727, 2.0363105582515247e-17),
+    dd(-0.6288673967765136, -2.540659998695754e-17),
+    dd(0.6288673967765136, 2.540659998695754e-17),
+    dd(-0.6778723796326639, -1.4586740755241772e-17),
+    dd(0.6778723796326639, 1.4586740755241772e-17),
+    dd(-0.7240341309238146, -2.0778907844904654e-17),
+    dd(0.7240341309238146, 2.0778907844904654e-17),
+    dd(-0.7671590325157404, 1.8562158801255765e-17),
+    dd(0.7671590325157404, -1.8562158801255765e-17),
+    dd(-0.8070662040294426, -2.814410562010173e-18),
+    dd(0.8070662040294426, 2.814410562010173e-18),
+    dd(-0.8435882616243935, -4.342980832163702e-17),
+    dd(0.8435882616243935, 4.342980832163702e-17),
+    dd(-0.8765720202742479, -3.1950874789317805e-17),
+    dd(0.8765720202742479, 3.1950874789317805e-17),
+    dd(-0.9058791367155696, -4.002366881667906e-17),
+    dd(0.9058791367155696, 4.002366881667906e-17),
+    dd(-0.9313866907065543, -9.832679206045463e-19),
+    dd(0.9313866907065543, 9.832679206045463e-19),
+    dd(-0.9529877031604309, 4.938686313282237e-17),
+    dd(0.9529877031604309, -4.938686313282237e-17),
+    dd(-0.9705915925462473, 2.218229932861139e-17),
+    dd(0.9705915925462473, -2.218229932861139e-17),
+    dd(-0.9841245837228269, -6.593628137227424e-18),
+    dd(0.9841245837228269, 6.593628137227424e-18),
+    dd(-0.9935301722663508, 6.428632460316898e-18),
+    dd(0.9935301722663508, -6.428632460316898e-18),
+    dd(-0.9987710072524261, -5.011046051231786e-17),
+    dd(0.9987710072524261, 5.011046051231786e-17),
+    dd(0.0, 0.0),
+    dd(-0.06342068498268678, -1.9141654559250425e-18),
+    dd(0.06342068498268678, 1.9141654559250425e-18),
+    dd(-0.12658599726967204, -1.0712881229558215e-17),
+    dd(0.12658599726967204, 1.0712881229558215e-17),
+    dd(-0.18924159246181357, -1.2851832315366708e-17),
+    dd(0.18924159246181357, 1.2851832315366708e-17),
+    dd(-0.2511351786125773, 7.635947448689549e-18),
+    dd(0.2511351786125773, -7.635947448689549e-18),
+    dd(-0.31201753211974875, -9.413197216497965e-18),
+    dd(0.31201753211974875, 9.413197216497965e-18),
+    dd(-0.3716435012622849, 1.4366702753094662e-17),
+    dd(0.3716435012622849, -1.4366702753094662e-17),
+    dd(-0.42977299334157654, 1.2215686999834233e-17),
+    dd(0.42977299334157654, -1.2215686999834233e-17),
+    dd(-0.48617194145249204, -1.995976785153112e-18),
+    dd(0.48617194145249204, 1.995976785153112e-18),
+    dd(-0.5406132469917261, 1.532213291161001e-17),
+    dd(0.5406132469917261, -1.532213291161001e-17),
+    dd(-0.5928776941089007, -3.556107323266032e-17),
+    dd(0.5928776941089007, 3.556107323266032e-17),
+    dd(-0.6427548324192377, 3.4225331427158505e-17),
+    dd(0.6427548324192377, -3.4225331427158505e-17),
+    dd(-0.6900438244251321, 1.0529369454227208e-17),
+    dd(0.6900438244251321, -1.0529369454227208e-17),
+    dd(-0.7345542542374027, 2.995500775278758e-17),
+    dd(0.7345542542374027, -2.995500775278758e-17),
+    dd(-0.7761068943454467, 2.7856933714440186e-17),
+    dd(0.7761068943454467, -2.7856933714440186e-17),
+    dd(-0.8145344273598555, 5.252368833607972e-17),
+    dd(0.8145344273598555, -5.252368833607972e-17),
+    dd(-0.8496821198441658, 4.9742283285826716e-17),
+    dd(0.8496821198441658, -4.9742283285826716e-17),
+    dd(-0.8814084455730089, -7.096871821327295e-18),
+    dd(0.8814084455730089, 7.096871821327295e-18),
+    dd(-0.9095856558280733, -1.5666512022722475e-17),
+    dd(0.9095856558280733, 1.5666512022722475e-17),
+    dd(-0.9341002947558101, -1.4697287042444006e-17),
+    dd(0.9341002947558101, 1.4697287042444006e-17),
+    dd(-0.9548536586741372, -4.9007540352115306e-17),
+    dd(0.9548536586741372, 4.9007540352115306e-17),
+    dd(-0.9717622009015554, 2.617829382880476e-17),
+    dd(0.9717622009015554, -2.617829382880476e-17),
+    dd(-0.984757895914213, 2.845818678692014e-17),
+    dd(0.984757895914213, -2.845818678692014e-17),
+    dd(-0.9937886619441678, 2.6337081579433462e-17),
+    dd(0.9937886619441678, -2.6337081579433462e-17),
+    dd(-0.9988201506066354, 4.6909564499642185e-17),
+    dd(0.9988201506066354, -4.6909564499642185e-17),
+    dd(-0.031098338327188876, 2.4982144846031473e-19),
+    dd(0.031098338327188876, -2.4982144846031473e-19),
+    dd(-0.09317470156008614, 1.9386319534420773e-18),
+    dd(0.09317470156008614, -1.9386319534420773e-18),
+    dd(-0.1548905899981459, -7.614646302098036e-18),
+    dd(0.1548905899981459, 7.614646302098036e-18),
+    dd(-0.21600723687604176, 1.4194242928140224e-18),
+    dd(0.21600723687604176, -1.4194242928140224e-18),
+    dd(-0.276288193779532, 1.9425196480588158e-17),
+    dd(0.276288193779532, -1.9425196480588158e-17),
+    dd(-0.33550024541943735, -8.3772646771281e-18),
+    dd(0.33550024541943735, 8.3772646771281e-18),
+    dd(-0.39341431189756515, 2.2461663942394132e-17),
+    dd(0.39341431189756515, -2.2461663942394132e-17),
+    dd(-0.44980633497403877, -2.0732103678617998e-17),
+    dd(0.44980633497403877, 2.0732103678617998e-17),
+    dd(-0.5044581449074642, 1.0451861601300162e-17),
+    dd(0.5044581449074642, -1.0451861601300162e-17),
+    dd(-0.5571583045146501, 3.911529355912782e-17),
+    dd(0.5571583045146501, -3.911529355912782e-17),
+    dd(-0.6077029271849502, -1.3521771162264434e-17),
+    dd(0.6077029271849502, 1.3521771162264434e-17),
+    dd(-0.6558964656854394, -4.773478658435925e-18),
+    dd(0.6558964656854394, 4.773478658435925e-18),
+    dd(-0.7015524687068222, -5.458038103419577e-17),
+    dd(0.7015524687068222, 5.458038103419577e-17),
+    dd(-0.7444943022260685, -4.4316580579806446e-17),
+    dd(0.7444943022260685, 4.4316580579806446e-17),
+    dd(-0.7845558329003993, 5.5302146557413877e-17),
+    dd(0.7845558329003993, -5.5302146557413877e-17),
+    dd(-0.821582070859336, 4.0543120459008696e-17),
+    dd(0.821582070859336, -4.0543120459008696e-17),
+    dd(-0.8554297694299461, 6.349231034817928e-19),
+    dd(0.8554297694299461, -6.349231034817928e-19),
+    dd(-0.8859679795236131, 9.752655801408827e-18),
+    dd(0.8859679795236131, -9.752655801408827e-18),
+    dd(-0.9130785566557919, -4.2196313463702057e-17),
+    dd(0.9130785566557919, 4.2196313463702057e-17),
+    dd(-0.936656618944878, 1.62472967064122e-17),
+    dd(0.936656618944878, -1.62472967064122e-17),
+    dd(-0.9566109552428079, -1.7539508493705455e-17),
+    dd(0.9566109552428079, 1.7539508493705455e-17),
+    dd(-0.972864385106692, -3.144235138940351e-17),
+    dd(0.972864385106692, 3.144235138940351e-17),
+    dd(-0.9853540840480058, -4.1829675642393005e-17),
+    dd(0.9853540840480058, 4.1829675642393005e-17),
+    dd(-0.9940319694320907, -7.938470720379374e-19),
+    dd(0.9940319694320907, 7.938470720379374e-19),
+    dd(-0.998866404420071, -3.1153025066514634e-17),
+    dd(0.998866404420071, 3.1153025066514634e-17),
+    dd(0.0, 0.0),
+    dd(-0.06096110015057873, 2.540535832577283e-18),
+    dd(0.06096110015057873, -2.540535832577283e-18),
+    dd(-0.12169542101888876, -3.3436732900126815e-18),
+    dd(0.12169542101888876, 3.3436732900126815e-18),
+    dd(-0.18197702695707754, -3.0443926373117145e-18),
+    dd(0.18197702695707754, 3.0443926373117145e-18),
+    dd(-0.24158166644779872, 1.1389228593589227e-17),
+    dd(0.24158166644779872, -1.1389228593589227e-17),
+    dd(-0.3002876063353319, -2.593179360249058e-17),
+    dd(0.3002876063353319, 2.593179360249058e-17),
+    dd(-0.3578764566884095, -4.250839113115931e-18),
+    dd(0.3578764566884095, 4.250839113115931e-18),
+    dd(-0.4141339832263039, 2.5420873829624e-17),
+    dd(0.4141339832263039, -2.5420873829624e-17),
+    dd(-0.46885090428604104, -2.664914947691763e-17),
+    dd(0.46885090428604104, 2.664914947691763e-17),
+    dd(-0.5218236693661858, 6.891238495421301e-18),
+    dd(0.5218236693661858, -6.891238495421301e-18),
+    dd(-0.5728552163513039, 3.877715516998933e-17),
+    dd(0.5728552163513039, -3.877715516998933e-17),
+    dd(-0.6217557046007233, -4.6951729099299514e-18),
+    dd(0.6217557046007233, 4.6951729099299514e-18),
+    dd(-0.66834322117537, -4.7709021502326146e-17),
+    dd(0.66834322117537, 4.7709021502326146e-17),
+    dd(-0.7124444575770367, 2.707775224510493e-17),
+    dd(0.7124444575770367, -2.707775224510493e-17),
+    dd(-0.7538953544853755, -4.619436232455068e-17),
+    dd(0.7538953544853755, 4.619436232455068e-17),
+    dd(-0.7925417120993812, -2.5977103938686435e-17),
+    dd(0.7925417120993812, 2.5977103938686435e-17),
+    dd(-0.8282397638230649, 2.3363718109544275e-17),
+    dd(0.8282397638230649, -2.3363718109544275e-17),
+    dd(-0.8608567111822923, -2.6920504817839454e-17),
+    dd(0.8608567111822923, 2.6920504817839454e-17),
+    dd(-0.8902712180295274, 5.2574997054402615e-17),
+    dd(0.8902712180295274, -5.2574997054402615e-17),
+    dd(-0.9163738623097802, 9.66352910580824e-18),
+    dd(0.9163738623097802, -9.66352910580824e-18),
+    dd(-0.9390675440029623, -5.4560239655706144e-17),
+    dd(0.9390675440029623, 5.4560239655706144e-17),
+    dd(-0.9582678486139082, 4.066471795334536e-17),
+    dd(0.9582678486139082, -4.066471795334536e-17),
+    dd(-0.9739033680193239, 1.1217272741915552e-19),
+    dd(0.9739033680193239, -1.1217272741915552e-19),
+    dd(-0.9859159917359029, -5.533297266233474e-17),
+    dd(0.9859159917359029, 5.533297266233474e-17),
+    dd(-0.9942612604367526, -1.6009767443897805e-17),
+    dd(0.9942612604367526, 1.6009767443897805e-17),
+    dd(-0.9989099908489035, 3.326857867320696e-17),
+    dd(0.9989099908489035, -3.326857867320696e-17),
+    dd(-0.029914109797338766, -3.598090341857862e-19),
+    dd(0.029914109797338766, 3.598090341857862e-19),
+    dd(-0.08963524464890056, -4.395846825102056e-18),
+    dd(0.08963524464890056, 4.395846825102056e-18),
+    dd(-0.14903550860694917, -1.1913449823084265e-17),
+    dd(0.14903550860694917, 1.1913449823084265e-17),
+    dd(-0.20790226415636606, 2.5889722385464772e-18),
+    dd(0.20790226415636606, -2.5889722385464772e-18),
+    dd(-0.2660247836050018, -1.415936536810001e-17),
+    dd(0.2660247836050018, 1.415936536810001e-17),
+    dd(-0.32319500343480784, 1.1604936255393122e-17),
+    dd(0.32319500343480784, -1.1604936255393122e-17),
+    dd(-0.3792082691160937, 7.68477423548188e-18),
+    dd(0.3792082691160937, -7.68477423548188e-18),
+    dd(-0.4338640677187617, 1.0893634979169262e-17),
+    dd(0.4338640677187617, -1.0893634979169262e-17),
+    dd(-0.48696674569809606, -1.819109390395315e-17),
+    dd(0.48696674569809606, 1.819109390395315e-17),
+    dd(-0.5383262092858274, -2.1114634726975848e-17),
+    dd(0.5383262092858274, 2.1114634726975848e-17),
+    dd(-0.5877586049795791, 3.2432215943190497e-17),
+    dd(0.5877586049795791, -3.2432215943190497e-17),
+    dd(-0.6350869776952459, -1.0931362909539415e-17),
+    dd(0.6350869776952459, 1.0931362909539415e-17),
+    dd(-0.6801419042271677, -9.18323248843472e-18),
+    dd(0.6801419042271677, 9.18323248843472e-18),
+    dd(-0.7227620997499832, -1.58113855595564e-17),
+    dd(0.7227620997499832, 1.58113855595564e-17),
+    dd(-0.7627949951937449, -2.0069698557000512e-17),
+    dd(0.7627949951937449, 2.0069698557000512e-17),
+    dd(-0.8000972834304684, 5.1285378312791103e-17),
+    dd(0.8000972834304684, -5.1285378312791103e-17),
+    dd(-0.8345354323267345, 1.4806605882302772e-17),
+    dd(0.8345354323267345, -1.4806605882302772e-17),
+    dd(-0.8659861628460676, -3.213276320132893e-17),
+    dd(0.8659861628460676, 3.213276320132893e-17),
+    dd(-0.8943368905344953, -4.568203293745331e-17),
+    dd(0.8943368905344953, 4.568203293745331e-17),
+    dd(-0.9194861289164246, 2.1125886176550648e-17),
+    dd(0.9194861289164246, -2.1125886176550648e-17),
+    dd(-0.9413438536413591, 2.8294583570596397e-17),
+    dd(0.9413438536413591, -2.8294583570596397e-17),
+    dd(-0.9598318269330866, -9.97723064585598e-19),
+    dd(0.9598318269330866, 9.97723064585598e-19),
+    dd(-0.9748838842217445, 4.236557650471174e-17),
+    dd(0.9748838842217445, -4.236557650471174e-17),
+    dd(-0.9864461956515499, 5.462157346927567e-17),
+    dd(0.9864461956515499, -5.462157346927567e-17),
+    dd(-0.9944775909292161, 3.058901825338043e-17),
+    dd(0.9944775909292161, -3.058901825338043e-17),
+    dd(-0.9989511111039503, 1.7431263237563007e-17),
+    dd(0.9989511111039503, -1.7431263237563007e-17),
+    dd(0.0, 0.0),
+    dd(-0.058685054300259464, -1.5115761967745646e-18),
+    dd(0.058685054300259464, 1.5115761967745646e-18),
+    dd(-0.11716780907195515, -2.2031959938682315e-18),
+    dd(0.11716780907195515, 2.2031959938682315e-18),
+    dd(-0.17524666215532575, -9.752379650199139e-19),
+    dd(0.17524666215532575, 9.752379650199139e-19),
+    dd(-0.2327214037242726, 1.381721333595567e-17),
+    dd(0.2327214037242726, -1.381721333595567e-17),
+    dd(-0.2893939064516262, 8.53301345032454e-18),
+    dd(0.2893939064516262, -8.53301345032454e-18),
+    dd(-0.34506880849572236, 5.220373261105881e-21),
+    dd(0.34506880849572236, -5.220373261105881e-21),
+    dd(-0.39955418695395295, -2.7358199824522973e-17),
+    dd(0.39955418695395295, 2.7358199824522973e-17),
+    dd(-0.4526622194618458, 2.4765437128384544e-17),
+    dd(0.4526622194618458, -2.4765437128384544e-17),
+    dd(-0.5042098316571334, -3.657701619217342e-17),
+    dd(0.5042098316571334, 3.657701619217342e-17),
+    dd(-0.5540193282770679, 2.0729866395061597e-17),
+    dd(0.5540193282770679, -2.0729866395061597e-17),
+    dd(-0.6019190057137693, 9.163412983634235e-19),
+    dd(0.6019190057137693, -9.163412983634235e-19),
+    dd(-0.64774374391651, -5.1273500447041495e-17),
+    dd(0.64774374391651, 5.1273500447041495e-17),
+    dd(-0.6913355756013667, -9.380570206117643e-18),
+    dd(0.6913355756013667, 9.380570206117643e-18),
+    dd(-0.7325442308075103, 4.550378729670595e-17),
+    dd(0.7325442308075103, -4.550378729670595e-17),
+    dd(-0.7712276549255324, 4.9799893463792615e-17),
+    dd(0.7712276549255324, -4.9799893463792615e-17),
+    dd(-0.8072524984168955, 2.4898920292166536e-17),
+    dd(0.8072524984168955, -2.4898920292166536e-17),
+    dd(-0.8404945765458014, 2.1885799296356378e-17),
+    dd(0.8404945765458014, -2.1885799296356378e-17),
+    dd(-0.8708392975582413, -5.2899358349662944e-17),
+    dd(0.8708392975582413, 5.2899358349662944e-17),
+    dd(-0.8981820578754266, -2.7688663495332542e-17),
+    dd(0.8981820578754266, 2.7688663495332542e-17),
+    dd(-0.9224286030428122, 2.8064000905934126e-17),
+    dd(0.9224286030428122, -2.8064000905934126e-17),
+    dd(-0.9434953534644419, 3.139718821763293e-17),
+    dd(0.9434953534644419, -3.139718821763293e-17),
+    dd(-0.9613096946231363, -1.9684284181689046e-17),
+    dd(0.9613096946231363, 1.9684284181689046e-17),
+    dd(-0.9758102337149845, -3.8747566062773745e-17),
+    dd(0.9758102337149845, 3.8747566062773745e-17),
+    dd(-0.9869470350233716, 3.3264432616730294e-17),
+    dd(0.9869470350233716, -3.3264432616730294e-17),
+    dd(-0.9946819193080071, 1.1138967457467016e-17),
+    dd(0.9946819193080071, -1.1138967457467016e-17),
+    dd(-0.9989899477763282, -1.3940690775912848e-17),
+    dd(0.9989899477763282, 1.3940690775912848e-17),
+    dd(-0.02881674819934178, 1.207033577914501e-18),
+    dd(0.02881674819934178, -1.207033577914501e-18),
+    dd(-0.08635451826324822, 4.714806766181871e-18),
+    dd(0.08635451826324822, -4.714806766181871e-18),
+    dd(-0.14360542731625614, -1.3844821217010425e-17),
+    dd(0.14360542731625614, 1.3844821217010425e-17),
+    dd(-0.20037929360621357, -4.33013185906006e-18),
+    dd(0.20037929360621357, 4.33013185906006e-18),
+    dd(-0.2564875200699973, 1.0046299362137375e-17),
+    dd(0.2564875200699973, -1.0046299362137375e-17),
+    dd(-0.3117437208344682, -1.0199195200583295e-17),
+    dd(0.3117437208344682, 1.0199195200583295e-17),
+    dd(-0.36596434037219117, -1.612218415355275e-17),
+    dd(0.36596434037219117, 1.612218415355275e-17),
+    dd(-0.4189692632552045, -2.3188363098035652e-17),
+    dd(0.4189692632552045, 2.3188363098035652e-17),
+    dd(-0.4705824124813823, -1.0821304868602997e-17),
+    dd(0.4705824124813823, 1.0821304868602997e-17),
+    dd(-0.520632334385933, -3.56255400058084e-17),
+    dd(0.520632334385933, 3.56255400058084e-17),
+    dd(-0.5689527681952095, 2.7593154288978013e-17),
+    dd(0.5689527681952095, -2.7593154288978013e-17),
+    dd(-0.6153831983311274, 5.267836654644153e-17),
+    dd(0.6153831983311274, -5.267836654644153e-17),
+    dd(-0.6597693876319831, -7.255216462222417e-18),
+    dd(0.6597693876319831, 7.255216462222417e-18),
+    dd(-0.7019638897191729, -2.393169067604968e-17),
+    dd(0.7019638897191729, 2.393169067604968e-17),
+    dd(-0.7418265388091844, 4.96666098146882e-17),
+    dd(0.7418265388091844, -4.96666098146882e-17),
+    dd(-0.779224915346254, 2.1877461202890476e-17),
+    dd(0.779224915346254, -2.1877461202890476e-17),
+    dd(-0.8140347859135678, -2.000668379072493e-17),
+    dd(0.8140347859135678, 2.000668379072493e-17),
+    dd(-0.846140515970773, 5.0061904765467927e-17),
+    dd(0.846140515970773, -5.0061904765467927e-17),
+    dd(-0.8754354540655689, -5.3977879746205103e-17),
+    dd(0.8754354540655689, 5.3977879746205103e-17),
+    dd(-0.9018222862847016, 4.336864720730694e-17),
+    dd(0.9018222862847016, -4.336864720730694e-17),
+    dd(-0.9252133598666515, 5.5018230992978575e-17),
+    dd(0.9252133598666515, -5.5018230992978575e-17),
+    dd(-0.9455309751649958, -7.692089913088374e-18),
+    dd(0.9455309751649958, 7.692089913088374e-18),
+    dd(-0.9627076457859236, 2.1462875290755353e-17),
+    dd(0.9627076457859236, -2.1462875290755353e-17),
+    dd(-0.9766863288579032, -5.177027597777438e-17),
+    dd(0.9766863288579032, 5.177027597777438e-17),
+    dd(-0.9874206373973435, -2.166255226877043e-17),
+    dd(0.9874206373973435, 2.166255226877043e-17),
+    dd(-0.9948751170183389, -2.345571190487739e-17),
+    dd(0.9948751170183389, 2.345571190487739e-17),
+    dd(-0.999026666867341, 5.948177004019685e-19),
+    dd(0.999026666867341, -5.948177004019685e-19),
+    dd(0.0, 0.0),
+    dd(-0.05657275381833678, 4.159209428859062e-19),
+    dd(0.05657275381833678, -4.159209428859062e-19),
+    dd(-0.11296428805932926, -6.827139265584272e-18),
+    dd(0.11296428805932926, 6.827139265584272e-18),
+    dd(-0.16899396364687322, 1.0415176565535807e-17),
+    dd(0.16899396364687322, -1.0415176565535807e-17),
+    dd(-0.2244823006478455, 1.1800438334622602e-17),
+    dd(0.2244823006478455, -1.1800438334622602e-17),
+    dd(-0.2792515532008065, -1.478084492030854e-17),
+    dd(0.2792515532008065, 1.478084492030854e-17),
+    dd(-0.3331262788900239, 6.252702984027669e-19),
+    dd(0.3331262788900239, -6.252702984027669e-19),
+    dd(-0.38593390074097944, 1.1112879035159994e-17),
+    dd(0.38593390074097944, -1.1112879035159994e-17),
+    dd(-0.4375052600371746, 1.1851567408787273e-17),
+    dd(0.4375052600371746, -1.1851567408787273e-17),
+    dd(-0.4876751581874741, -7.919692551669746e-18),
+    dd(0.4876751581874741, 7.919692551669746e-18),
+    dd(-0.5362828859083433, -2.02940642329922e-17),
+    dd(0.5362828859083433, 2.02940642329922e-17),
+    dd(-0.5831727380260321, -1.3765166239762778e-17),
+    dd(0.5831727380260321, 1.3765166239762778e-17),
+    dd(-0.6281945122499282, 3.18635024187039e-17),
+    dd(0.6281945122499282, -3.18635024187039e-17),
+    dd(-0.6712039903198264, -3.561831403610216e-17),
+    dd(0.6712039903198264, 3.561831403610216e-17),
+    dd(-0.7120633999866378, -2.6143017747240776e-17),
+    dd(0.7120633999866378, 2.6143017747240776e-17),
+    dd(-0.7506418563480219, 3.2128517775754273e-17),
+    dd(0.7506418563480219, -3.2128517775754273e-17),
+    dd(-0.7868157811276224, 3.861397607974174e-17),
+    dd(0.7868157811276224, -3.861397607974174e-17),
+    dd(-0.8204692985593209, 2.3993861371833565e-17),
+    dd(0.8204692985593209, -2.3993861371833565e-17),
+    dd(-0.8514946066171545, 9.938056547499654e-18),
+    dd(0.8514946066171545, -9.938056547499654e-18),
+    dd(-0.8797923224198955, -3.8811678371203934e-17),
+    dd(0.8797923224198955, 3.8811678371203934e-17),
+    dd(-0.905271800744, 1.7832878349808044e-17),
+    dd(0.905271800744, -1.7832878349808044e-17),
+    dd(-0.9278514247207917, 4.2992318412837717e-17),
+    dd(0.9278514247207917, -4.2992318412837717e-17),
+    dd(-0.9474588680412107, -3.074047570785754e-17),
+    dd(0.9474588680412107, 3.074047570785754e-17),
+    dd(-0.9640313285931352, 2.951016527868253e-17),
+    dd(0.9640313285931352, -2.951016527868253e-17),
+    dd(-0.9775157355039892, -2.8415420902872815e-17),
+    dd(0.9775157355039892, 2.8415420902872815e-17),
+    dd(-0.9878689411988892, 4.029706151603904e-17),
+    dd(0.9878689411988892, -4.029706151603904e-17),
+    dd(-0.9950579778474119, 2.5774841855498432e-17),
+    dd(0.9950579778474119, -2.5774841855498432e-17),
+    dd(-0.9990614195648185, -1.0088050455209305e-17),
+    dd(0.9990614195648185, 1.0088050455209305e-17),
+    dd(-0.027797035287275437, 1.712251740277211e-19),
+    dd(0.027797035287275437, -1.712251740277211e-19),
+    dd(-0.08330518682243537, -1.3451125733752206e-18),
+    dd(0.08330518682243537, 1.3451125733752206e-18),
+    dd(-0.13855584681037625, 6.599442128548261e-18),
+    dd(0.13855584681037625, -6.599442128548261e-18),
+    dd(-0.19337823863527526, -1.3221067656328196e-18),
+    dd(0.19337823863527526, 1.3221067656328196e-18),
+    dd(-0.24760290943433721, 1.1027032777605881e-17),
+    dd(0.24760290943433721, -1.1027032777605881e-17),
+    dd(-0.3010622538672207, 1.6990188051901856e-17),
+    dd(0.3010622538672207, -1.6990188051901856e-17),
+    dd(-0.3535910321749545, 1.6791391507171072e-18),
+    dd(0.3535910321749545, -1.6791391507171072e-18),
+    dd(-0.40502688092709127, -7.462263279438347e-18),
+    dd(0.40502688092709127, 7.462263279438347e-18),
+    dd(-0.4552108148784596, 2.3057612547749616e-17),
+    dd(0.4552108148784596, -2.3057612547749616e-17),
+    dd(-0.5039877183843817, 7.90794694036822e-18),
+    dd(0.5039877183843817, -7.90794694036822e-18),
+    dd(-0.5512068248555346, 8.506658736755863e-18),
+    dd(0.5512068248555346, -8.506658736755863e-18),
+    dd(-0.5967221827706634, 3.623959695830794e-17),
+    dd(0.5967221827706634, -3.623959695830794e-17),
+    dd(-0.6403931068070069, 1.218783845938524e-18),
+    dd(0.6403931068070069, -1.218783845938524e-18),
+    dd(-0.6820846126944704, -5.085358865030898e-17),
+    dd(0.6820846126944704, 5.085358865030898e-17),
+    dd(-0.7216678344501881, 3.35232608096064e-17),
+    dd(0.7216678344501881, -3.35232608096064e-17),
+    dd(-0.7590204227051289, 2.542937695324171e-17),
+    dd(0.7590204227051289, -2.542937695324171e-17),
+    dd(-0.7940269228938664, -4.883654094788494e-17),
+    dd(0.7940269228938664, 4.883654094788494e-17),
+    dd(-0.8265791321428817, 4.900121551878946e-17),
+    dd(0.8265791321428817, -4.900121551878946e-17),
+    dd(-0.8565764337627486, -2.293699326528781e-17),
+    dd(0.8565764337627486, 2.293699326528781e-17),
+    dd(-0.8839261083278276, 4.5774772903545974e-17),
+    dd(0.8839261083278276, -4.5774772903545974e-17),
+    dd(-0.9085436204206555, 1.5795941913989712e-17),
+    dd(0.9085436204206555, -1.5795941913989712e-17),
+    dd(-0.9303528802474963, -6.117052136345367e-18),
+    dd(0.9303528802474963, 6.117052136345367e-18),
+    dd(-0.9492864795619627, 2.379910196018227e-17),
+    dd(0.9492864795619627, -2.379910196018227e-17),
+    dd(-0.9652859019054901, -5.4566011640813934e-17),
+    dd(0.9652859019054901, 5.4566011640813934e-17),
+    dd(-0.9783017091402564, -1.9741124462470228e-18),
+    dd(0.9783017091402564, 1.9741124462470228e-18),
+    dd(-0.9882937155401615, 3.257458502734177e-17),
+    dd(0.9882937155401615, -3.257458502734177e-17),
+    dd(-0.9952312260810697, -1.635635725469055e-17),
+    dd(0.9952312260810697, 1.635635725469055e-17),
+    dd(-0.9990943438014656, -2.792150253141246e-17),
+    dd(0.9990943438014656, 2.792150253141246e-17),
+    dd(0.0, 0.0),
+    dd(-0.05460715100164682, -3.0441581736756266e-18),
+    dd(0.05460715100164682, 3.0441581736756266e-18),
+    dd(-0.1090513328087878, 4.642137959593844e-19),
+    dd(0.1090513328087878, -4.642137959593844e-19),
+    dd(-0.16317006259126426, 1.3330055967348883e-17),
+    dd(0.16317006259126426, -1.3330055967348883e-17),
+    dd(-0.21680182879612403, -6.61687040513806e-18),
+    dd(0.21680182879612403, 6.61687040513806e-18),
+    dd(-0.26978657316183874, -2.7164073717928176e-17),
+    dd(0.26978657316183874, 2.7164073717928176e-17),
+    dd(-0.3219661683953786, -2.4827171792706824e-17),
+    dd(0.3219661683953786, 2.4827171792706824e-17),
+    dd(-0.37318489008659445, -1.3383334957383691e-17),
+    dd(0.37318489008659445, 1.3383334957383691e-17),
+    dd(-0.42328988145156393, -1.9102162209301312e-17),
+    dd(0.42328988145156393, 1.9102162209301312e-17),
+    dd(-0.4721316095179757, 1.592152640002674e-17),
+    dd(0.4721316095179757, -1.592152640002674e-17),
+    dd(-0.5195643113911876, 3.241825940342919e-17),
+    dd(0.5195643113911876, -3.241825940342919e-17),
+    dd(-0.5654464292692367, -4.235525871449965e-17),
+    dd(0.5654464292692367, 4.235525871449965e-17),
+    dd(-0.6096410329087154, 4.2432599207744405e-17),
+    dd(0.6096410329087154, -4.2432599207744405e-17),
+    dd(-0.6520162282809769, 4.815271862035433e-17),
+    dd(0.6520162282809769, -4.815271862035433e-17),
+    dd(-0.6924455511995178, 4.461768239285134e-17),
+    dd(0.6924455511995178, -4.461768239285134e-17),
+    dd(-0.7308083447445233, -4.7844505459148053e-17),
+    dd(0.7308083447445233, 4.7844505459148053e-17),
+    dd(-0.7669901193594502, -3.344075433618229e-17),
+    dd(0.7669901193594502, 3.344075433618229e-17),
+    dd(-0.8008828945472183, 4.0456688057093375e-17),
+    dd(0.8008828945472183, -4.0456688057093375e-17),
+    dd(-0.8323855211504391, -5.3622860483916873e-17),
+    dd(0.8323855211504391, 5.3622860483916873e-17),
+    dd(-0.8614039832620469, -2.0803410883359588e-17),
+    dd(0.8614039832620469, 2.0803410883359588e-17),
+    dd(-0.8878516788822214, 5.5034790273851256e-17),
+    dd(0.8878516788822214, -5.5034790273851256e-17),
+    dd(-0.9116496785213912, 1.410909191959924e-17),
+    dd(0.9116496785213912, -1.410909191959924e-17),
+    dd(-0.9327269610671017, 3.8036698085950153e-17),
+    dd(0.9327269610671017, -3.8036698085950153e-17),
+    dd(-0.9510206264478768, 2.934700057172164e-17),
+    dd(0.9510206264478768, -2.934700057172164e-17),
+    dd(-0.9664760851718867, -2.2781729644064835e-17),
+    dd(0.9664760851718867, 2.2781729644064835e-17),
+    dd(-0.9790472267094688, 4.038114492390043e-17),
+    dd(0.9790472267094688, -4.038114492390043e-17),
+    dd(-0.988696577650222, -4.0611968092954484e-17),
+    dd(0.988696577650222, 4.0611968092954484e-17),
+    dd(-0.9953955236784303, 5.081622514695e-18),
+    dd(0.9953955236784303, -5.081622514695e-18),
+    dd(-0.9991255656252629, 3.97071358726068e-17),
+    dd(0.9991255656252629, -3.97071358726068e-17),
+    dd(-0.026847012365942356, 4.345524858793459e-19),
+    dd(0.026847012365942356, -4.345524858793459e-19),
+    dd(-0.08046363021414273, -3.6602746411218386e-18),
+    dd(0.08046363021414273, 3.6602746411218386e-18),
+    dd(-0.13384825059546684, -1.2574265360365673e-17),
+    dd(0.13384825059546684, 1.2574265360365673e-17),
+    dd(-0.18684695183576133, 1.0997859378205609e-17),
+    dd(0.18684695183576133, -1.0997859378205609e-17),
+    dd(-0.23930692496615347, 1.2553683871592052e-17),
+    dd(0.23930692496615347, -1.2553683871592052e-17),
+    dd(-0.2910769143111092, 2.434012688893381e-17),
+    dd(0.2910769143111092, -2.434012688893381e-17),
+    dd(-0.3420076535979953, 2.572756191729674e-17),
+    dd(0.3420076535979953, -2.572756191729674e-17),
+    dd(-0.3919522963307531, -2.5451251383575385e-17),
+    dd(0.3919522963307531, 2.5451251383575385e-17),
+    dd(-0.44076683918683957, 7.422816742795798e-21),
+    dd(0.44076683918683957, -7.422816742795798e-21),
+    dd(-0.48831053721671847, 1.8430455024649495e-18),
+    dd(0.48831053721671847, -1.8430455024649495e-18),
+    dd(-0.5344463096488475, -4.685799745448818e-17),
+    dd(0.5344463096488475, 4.685799745448818e-17),
+    dd(-0.579041135130225, 7.07391202839491e-18),
+    dd(0.579041135130225, -7.07391202839491e-18),
+    dd(-0.6219664352630792, 4.2693987911765647e-17),
+    dd(0.6219664352630792, -4.2693987911765647e-17),
+    dd(-0.6630984453321253, -1.390593838096864e-17),
+    dd(0.6630984453321253, 1.390593838096864e-17),
+    dd(-0.7023185711539082, 5.3113525356682905e-17),
+    dd(0.7023185711539082, -5.3113525356682905e-17),
+    dd(-0.7395137310200423, -1.4718506647283244e-17),
+    dd(0.7395137310200423, 1.4718506647283244e-17),
+    dd(-0.7745766817496528, 2.814601137210038e-17),
+    dd(0.7745766817496528, -2.814601137210038e-17),
+    dd(-0.8074063279130882, 5.4331456119147386e-17),
+    dd(0.8074063279130882, -5.4331456119147386e-17),
+    dd(-0.8379080133393734, 5.345785022377165e-17),
+    dd(0.8379080133393734, -5.345785022377165e-17),
+    dd(-0.8659937940748075, -2.329150406983641e-18),
+    dd(0.8659937940748075, 2.329150406983641e-18),
+    dd(-0.8915826920220302, 4.4568648645683843e-17),
+    dd(0.8915826920220302, -4.4568648645683843e-17),
+    dd(-0.9146009285643525, -5.1816690389056247e-17),
+    dd(0.9146009285643525, 5.1816690389056247e-17),
+    dd(-0.9349821375882593, -2.7410404689190648e-17),
+    dd(0.9349821375882593, 2.7410404689190648e-17),
+    dd(-0.9526675575188691, 2.1077730926486214e-17),
+    dd(0.9526675575188691, -2.1077730926486214e-17),
+    dd(-0.9676062025029241, -2.807226521242974e-17),
+    dd(0.9676062025029241, 2.807226521242974e-17),
+    dd(-0.9797550146943503, 2.5528580503202787e-17),
+    dd(0.9797550146943503, -2.5528580503202787e-17),
+    dd(-0.9890790082484426, 5.968764562059577e-19),
+    dd(0.9890790082484426, -5.968764562059577e-19),
+    dd(-0.9955514765972909, 2.7079288796922688e-17),
+    dd(0.9955514765972909, -2.7079288796922688e-17),
+    dd(-0.9991552004073866, -1.6420296557393296e-17),
+    dd(0.9991552004073866, 1.6420296557393296e-17),
+    dd(0.0, 0.0),
+    dd(-0.05277348408831, -3.3013455429709715e-18),
+    dd(0.05277348408831, 3.3013455429709715e-18),
+    dd(-0.10539987901634415, 6.29194442647967e-18),
+    dd(0.10539987901634415, -6.29194442647967e-18),
+    dd(-0.15773250558785798, 1.1474609694354514e-17),
+    dd(0.15773250558785798, -1.1474609694354514e-17),
+    dd(-0.20962550339203653, -1.1988627733576806e-17),
+    dd(0.20962550339203653, 1.1988627733576806e-17),
+    dd(-0.26093423734281174, 2.514891287240318e-17),
+    dd(0.26093423734281174, -2.514891287240318e-17),
+    dd(-0.3115157008030137, 1.7249675041091657e-17),
+    dd(0.3115157008030137, -1.7249675041091657e-17),
+    dd(-0.3612289141697948, -8.600268615293503e-18),
+    dd(0.3612289141697948, 8.600268615293503e-18),
+    dd(-0.409935317810419, 8.714335212305206e-18),
+    dd(0.409935317810419, -8.714335212305206e-18),
+    dd(-0.4574991582532667, 1.2041365707165263e-17),
+    dd(0.4574991582532667, -1.2041365707165263e-17),
+    dd(-0.503787866557718, 3.273840752359533e-17),
+    dd(0.503787866557718, -3.273840752359533e-17),
+    dd(-0.5486724278083964, 3.9093097263710306e-17),
+    dd(0.5486724278083964, -3.9093097263710306e-17),
+    dd(-0.5920277407040302, 4.755629218858965e-17),
+    dd(0.5920277407040302, -4.755629218858965e-17),
+    dd(-0.6337329662388501, 1.7513501291272714e-17),
+    dd(0.6337329662388501, -1.7513501291272714e-17),
+    dd(-0.6736718645049372, -1.4742750242439295e-17),
+    dd(0.6736718645049372, 1.4742750242439295e-17),
+    dd(-0.7117331186771977, -4.684209447235121e-17),
+    dd(0.7117331186771977, 4.684209447235121e-17),
+    dd(-0.7478106452786403, 4.1691803558506796e-17),
+    dd(0.7478106452786403, -4.1691803558506796e-17),
+    dd(-0.7818038898623609, 2.8882416510382095e-17),
+    dd(0.7818038898623609, -2.8882416510382095e-17),
+    dd(-0.8136181072882116, 2.911226650982022e-17),
+    dd(0.8136181072882116, -2.911226650982022e-17),
+    dd(-0.8431646258168722, 3.5958971513533465e-17),
+    dd(0.8431646258168722, -3.5958971513533465e-17),
+    dd(-0.8703610942928822, -4.068322095878324e-17),
+    dd(0.8703610942928822, 4.068322095878324e-17),
+    dd(-0.8951317117434721, -2.7090700525983178e-17),
+    dd(0.8951317117434721, 2.7090700525983178e-17),
+    dd(-0.9174074387881552, -5.408088249835017e-17),
+    dd(0.9174074387881552, 5.408088249835017e-17),
+    dd(-0.9371261903534539, 4.3431950191592945e-17),
+    dd(0.9371261903534539, -4.3431950191592945e-17),
+    dd(-0.9542330093769511, 4.6490581236858463e-17),
+    dd(0.9542330093769511, -4.6490581236858463e-17),
+    dd(-0.9686802216817816, 5.2244447602792896e-17),
+    dd(0.9686802216817816, -5.2244447602792896e-17),
+    dd(-0.9804275739567156, -4.065356274930472e-17),
+    dd(0.9804275739567156, 4.065356274930472e-17),
+    dd(-0.989442365133731, 2.9308734819030133e-17),
+    dd(0.989442365133731, -2.9308734819030133e-17),
+    dd(-0.995699640383246, 2.2742259542103106e-17),
+    dd(0.995699640383246, -2.2742259542103106e-17),
+    dd(-0.9991833539092947, 7.663094111210327e-18),
+    dd(0.9991833539092947, -7.663094111210327e-18),
+    dd(-0.0259597723012478, 1.4356778036818046e-18),
+    dd(0.0259597723012478, -1.4356778036818046e-18),
+    dd(-0.07780933394953657, -6.3294287969153e-19),
+    dd(0.07780933394953657, 6.3294287969153e-19),
+    dd(-0.129449135396945, -5.546200454937345e-18),
+    dd(0.129449135396945, 5.546200454937345e-18),
+    dd(-0.18073996487342542, 1.038263389661985e-18),
+    dd(0.18073996487342542, -1.038263389661985e-18),
+    dd(-0.23154355137602933, -5.110369542979982e-18),
+    dd(0.23154355137602933, 5.110369542979982e-18),
+    dd(-0.2817229374232617, 1.8375263280718775e-17),
+    dd(0.2817229374232617, -1.8375263280718775e-17),
+    dd(-0.3311428482684482, 3.504799263964118e-18),
+    dd(0.3311428482684482, -3.504799263964118e-18),
+    dd(-0.379670056576798, -8.994753190070323e-19),
+    dd(0.379670056576798, 8.994753190070323e-19),
+    dd(-0.4271737415830784, 2.5277919830556594e-17),
+    dd(0.4271737415830784, -2.5277919830556594e-17),
+    dd(-0.4735258417617071, -2.192453243918222e-17),
+    dd(0.4735258417617071, 2.192453243918222e-17),
+    dd(-0.5186014000585697, -5.261142422610163e-17),
+    dd(0.5186014000585697, 5.261142422610163e-17),
+    dd(-0.5622789007539445, -5.085613902166967e-17),
+    dd(0.5622789007539445, 5.085613902166967e-17),
+    dd(-0.6044405970485104, 2.283572043106477e-17),
+    dd(0.6044405970485104, -2.283572043106477e-17),
+    dd(-0.644972828489477, -5.530991996574467e-17),
+    dd(0.644972828489477, 5.530991996574467e-17),
+    dd(-0.6837663273813555, 1.7083869162595875e-17),
+    dd(0.6837663273813555, -1.7083869162595875e-17),
+    dd(-0.7207165133557304, -6.782323625123895e-18),
+    dd(0.7207165133557304, 6.782323625123895e-18),
+    dd(-0.7557237753065856, -5.4596781473506995e-17),
+    dd(0.7557237753065856, 5.4596781473506995e-17),
+    dd(-0.7886937399322641, 4.945007622295678e-17),
+    dd(0.7886937399322641, -4.945007622295678e-17),
+    dd(-0.8195375261621458, 5.3578328381856906e-17),
+    dd(0.8195375261621458, -5.3578328381856906e-17),
+    dd(-0.8481719847859296, -4.33892434091673e-17),
+    dd(0.8481719847859296, 4.33892434091673e-17),
+    dd(-0.8745199226468983, -5.28431222454486e-17),
+    dd(0.8745199226468983, 5.28431222454486e-17),
+    dd(-0.898510310810046, 1.1529050944331754e-17),
+    dd(0.898510310810046, -1.1529050944331754e-17),
+    dd(-0.9200784761776275, -5.06039807675795e-17),
+    dd(0.9200784761776275, 5.06039807675795e-17),
+    dd(-0.9391662761164232, -2.3012198224123644e-17),
+    dd(0.9391662761164232, 2.3012198224123644e-17),
+    dd(-0.9557222558399961, 4.133242182302213e-17),
+    dd(0.9557222558399961, -4.133242182302213e-17),
+    dd(-0.9697017887650528, 1.8617557872387923e-17),
+    dd(0.9697017887650528, -1.8617557872387923e-17),
+    dd(-0.9810672017525982, 2.0203121429729433e-17),
+    dd(0.9810672017525982, -2.0203121429729433e-17),
+    dd(-0.9897878952222218, 5.229709347235804e-17),
+    dd(0.9897878952222218, -5.229709347235804e-17),
+    dd(-0.9958405251188381, -3.591329605044019e-17),
+    dd(0.9958405251188381, 3.591329605044019e-17),
+    dd(-0.9992101232274361, 5.355382975297246e-17),
+    dd(0.9992101232274361, -5.355382975297246e-17),
+    dd(0.0, 0.0),
+    dd(-0.05105890670797435, -2.427826805924411e-18),
+    dd(0.05105890670797435, 2.427826805924411e-18),
+    dd(-0.10198460656227407, -2.891098992232706e-18),
+    dd(0.10198460656227407, 2.891098992232706e-18),
+    dd(-0.1526442402308153, -2.6199344250525925e-18),
+    dd(0.1526442402308153, 2.6199344250525925e-18),
+    dd(-0.2029056425180585, 1.0699656757250582e-17),
+    dd(0.2029056425180585, -1.0699656757250582e-17),
+    dd(-0.2526376871690535, -2.73670905273938e-18),
+    dd(0.2526376871690535, 2.73670905273938e-18),
+    dd(-0.3017106289630307, -2.1152723293276593e-17),
+    dd(0.3017106289630307, 2.1152723293276593e-17),
+    dd(-0.34999644220406684, 3.1666482378837988e-18),
+    dd(0.34999644220406684, -3.1666482378837988e-18),
+    dd(-0.3973691547257566, 1.4491205155624422e-17),
+    dd(0.3973691547257566, -1.4491205155624422e-17),
+    dd(-0.4437051765385316, -1.4868521577579717e-17),
+    dd(0.4437051765385316, 1.4868521577579717e-17),
+    dd(-0.4888836222622521, 7.768334229895887e-18),
+    dd(0.4888836222622521, -7.768334229895887e-18),
+    dd(-0.5327866265029253, 3.22606008631605e-17),
+    dd(0.5327866265029253, -3.22606008631605e-17),
+    dd(-0.5752996513508306, -8.814212216461023e-18),
+    dd(0.5752996513508306, 8.814212216461023e-18),
+    dd(-0.6163117851979217, 1.0714514524878112e-17),
+    dd(0.6163117851979217, -1.0714514524878112e-17),
+    dd(-0.6557160320950709, 2.8121924055446813e-17),
+    dd(0.6557160320950709, -2.8121924055446813e-17),
+    dd(-0.6934095908944912, 1.2423624108252405e-17),
+    dd(0.6934095908944912, -1.2423624108252405e-17),
+    dd(-0.7292941234494651, -1.7076614003073778e-17),
+    dd(0.7292941234494651, 1.7076614003073778e-17),
+    dd(-0.7632760111723123, 5.4882945134027416e-17),
+    dd(0.7632760111723123, -5.4882945134027416e-17),
+    dd(-0.7952665992823597, 4.980741380459363e-18),
+    dd(0.7952665992823597, -4.980741380459363e-18),
+    dd(-0.8251824281086599, -1.2915456351102651e-17),
+    dd(0.8251824281086599, 1.2915456351102651e-17),
+    dd(-0.8529454508476635, 4.3293227838219055e-17),
+    dd(0.8529454508476635, -4.3293227838219055e-17),
+    dd(-0.8784832372148811, 5.280737459047291e-17),
+    dd(0.8784832372148811, -5.280737459047291e-17),
+    dd(-0.9017291624740011, -2.5997209368459444e-17),
+    dd(0.9017291624740011, 2.5997209368459444e-17),
+    dd(-0.9226225813829553, 1.4506339267292507e-17),
+    dd(0.9226225813829553, -1.4506339267292507e-17),
+    dd(-0.9411089866813611, -3.2587633947505426e-17),
+    dd(0.9411089866813611, 3.2587633947505426e-17),
+    dd(-0.9571401519129841, -1.9558441012215427e-17),
+    dd(0.9571401519129841, 1.9558441012215427e-17),
+    dd(-0.9706742588331829, 1.5319642694435966e-17),
+    dd(0.9706742588331829, -1.5319642694435966e-17),
+    dd(-0.981676011284037, -4.853917333265863e-17),
+    dd(0.981676011284037, 4.853917333265863e-17),
+    dd(-0.990116745232517, -2.501136522934141e-17),
+    dd(0.990116745232517, 2.501136522934141e-17),
+    dd(-0.9959745998151203, 3.0788110355952534e-17),
+    dd(0.9959745998151203, -3.0788110355952534e-17),
+    dd(-0.9992355976313635, 2.070053693858183e-17),
+    dd(0.9992355976313635, -2.070053693858183e-17),
+    dd(-0.025129291421820615, -5.00586943141276e-20),
+    dd(0.025129291421820615, 5.00586943141276e-20),
+    dd(-0.07532439549623433, 9.60667608250651e-19),
+    dd(0.07532439549623433, -9.60667608250651e-19),
+    dd(-0.1253292236158968, -1.3234466172523493e-17),
+    dd(0.1253292236158968, 1.3234466172523493e-17),
+    dd(-0.17501745924901563, -7.688267190311295e-19),
+    dd(0.17501745924901563, 7.688267190311295e-19),
+    dd(-0.22426358560416554, 6.776195773526737e-18),
+    dd(0.22426358560416554, -6.776195773526737e-18),
+    dd(-0.27294320269672634, -4.026102984513093e-18),
+    dd(0.27294320269672634, 4.026102984513093e-18),
+    dd(-0.320933341594194, 6.341362334711341e-18),
+    dd(0.320933341594194, -6.341362334711341e-18),
+    dd(-0.36811277504656453, 3.524854291658252e-18),
+    dd(0.36811277504656453, -3.524854291658252e-18),
+    dd(-0.41436232371712606, 9.468803457434653e-18),
+    dd(0.41436232371712606, -9.468803457434653e-18),
+    dd(-0.4595651572401134, -1.9670805042585985e-17),
+    dd(0.4595651572401134, 1.9670805042585985e-17),
+    dd(-0.503607089344756, -2.1422415057844145e-18),
+    dd(0.503607089344756, 2.1422415057844145e-18),
+    dd(-0.5463768663002511, -3.299150037213276e-17),
+    dd(0.5463768663002511, 3.299150037213276e-17),
+    dd(-0.5877664479530873, -3.7042753461393306e-17),
+    dd(0.5877664479530873, 3.7042753461393306e-17),
+    dd(-0.6276712806468852, -2.607431813573611e-17),
+    dd(0.6276712806468852, 2.607431813573611e-17),
+    dd(-0.6659905613354794, -4.404851247801291e-17),
+    dd(0.6659905613354794, 4.404851247801291e-17),
+    dd(-0.702627492222297, -2.0846525295844613e-17),
+    dd(0.702627492222297, 2.0846525295844613e-17),
+    dd(-0.7374895252831567, -5.0451835778625615e-17),
+    dd(0.7374895252831567, 5.0451835778625615e-17),
+    dd(-0.7704885960554193, -1.3149561701900891e-17),
+    dd(0.7704885960554193, 1.3149561701900891e-17),
+    dd(-0.8015413461039764, 5.4924117325112846e-17),
+    dd(0.8015413461039764, -5.4924117325112846e-17),
+    dd(-0.8305693336040049, 1.5531870195758852e-17),
+    dd(0.8305693336040049, -1.5531870195758852e-17),
+    dd(-0.857499231512071, 4.6789060800964436e-17),
+    dd(0.857499231512071, -4.6789060800964436e-17),
+    dd(-0.8822630128318973, -2.1147217688258953e-17),
+    dd(0.8822630128318973, 2.1147217688258953e-17),
+    dd(-0.9047981225210935, 1.8380046801020686e-17),
+    dd(0.9047981225210935, -1.8380046801020686e-17),
+    dd(-0.9250476356362037, -1.9662125064898993e-17),
+    dd(0.9250476356362037, 1.9662125064898993e-17),
+    dd(-0.9429604013923285, 2.8048370782082213e-18),
+    dd(0.9429604013923285, -2.8048370782082213e-18),
+    dd(-0.958491172973927, -3.9526531230476e-17),
+    dd(0.958491172973927, 3.9526531230476e-17),
+    dd(-0.9716007233716518, 1.3434654006949203e-17),
+    dd(0.9716007233716518, -1.3434654006949203e-17),
+    dd(-0.9822559490972367, 3.075415664870167e-17),
+    dd(0.9822559490972367, -3.075415664870167e-17),
+    dd(-0.9904299711892903, -2.6688763798285652e-17),
+    dd(0.9904299711892903, 2.6688763798285652e-17),
+    dd(-0.9961022963162671, -1.1117752410471646e-17),
+    dd(0.9961022963162671, 1.1117752410471646e-17),
+    dd(-0.999259859308777, 2.3069579713155247e-18),
+    dd(0.999259859308777, -2.3069579713155247e-18),
+    dd(0.0, 0.0),
+    dd(-0.049452187116159625, -2.0322284170727748e-18),
+    dd(0.049452187116159625, 2.0322284170727748e-18),
+    dd(-0.09878335644694528, -4.354195142099527e-18),
+    dd(0.09878335644694528, 4.354195142099527e-18),
+    dd(-0.14787278635787196, -8.219942329369009e-18),
+    dd(0.14787278635787196, 8.219942329369009e-18),
+    dd(-0.19660034679150667, -9.642643069851073e-18),
+    dd(0.19660034679150667, 9.642643069851073e-18),
+    dd(-0.24484679324595338, 1.3046549760280572e-17),
+    dd(0.24484679324595338, -1.3046549760280572e-17),
+    dd(-0.29249405858625144, -2.5324236143162706e-18),
+    dd(0.29249405858625144, 2.5324236143162706e-18),
+    dd(-0.3394255419745844, 7.985151151850277e-18),
+    dd(0.3394255419745844, -7.985151151850277e-18),
+    dd(-0.3855263942122479, -1.2066744074020966e-17),
+    dd(0.3855263942122479, 1.2066744074020966e-17),
+    dd(-0.4306837987951116, -6.413436524840426e-18),
+    dd(0.4306837987951116, 6.413436524840426e-18),
+    dd(-0.4747872479948044, -2.657001668429428e-17),
+    dd(0.4747872479948044, 2.657001668429428e-17),
+    dd(-0.5177288132900333, 3.697493425713023e-17),
+    dd(0.5177288132900333, -3.697493425713023e-17),
+    dd(-0.559403409486285, -3.840686091734377e-17),
+    dd(0.559403409486285, 3.840686091734377e-17),
+    dd(-0.5997090518776252, 7.504121699200815e-18),
+    dd(0.5997090518776252, -7.504121699200815e-18),
+    dd(-0.6385471058213654, 3.884970425759732e-17),
+    dd(0.6385471058213654, -3.884970425759732e-17),
+    dd(-0.6758225281149861, 1.9539677617841785e-17),
+    dd(0.6758225281149861, -1.9539677617841785e-17),
+    dd(-0.7114440995848458, -2.846878461349912e-17),
+    dd(0.7114440995848458, 2.846878461349912e-17),
+    dd(-0.7453246483178474, -2.426552633921286e-17),
+    dd(0.7453246483178474, 2.426552633921286e-17),
+    dd(-0.7773812629903724, 2.1439069704643357e-17),
+    dd(0.7773812629903724, -2.1439069704643357e-17),
+    dd(-0.8075354957734567, -2.4893530204464102e-17),
+    dd(0.8075354957734567, 2.4893530204464102e-17),
+    dd(-0.8357135543195029, 5.024731044983534e-17),
+    dd(0.8357135543195029, -5.024731044983534e-17),
+    dd(-0.8618464823641238, 3.645296800561114e-17),
+    dd(0.8618464823641238, -3.645296800561114e-17),
+    dd(-0.8858703285078534, -1.564137836787039e-17),
+    dd(0.8858703285078534, 1.564137836787039e-17),
+    dd(-0.9077263027785316, 5.4921137457920994e-17),
+    dd(0.9077263027785316, -5.4921137457920994e-17),
+    dd(-0.9273609206218432, -3.991261661560906e-17),
+    dd(0.9273609206218432, 3.991261661560906e-17),
+    dd(-0.9447261340410098, -1.1325094975092593e-18),
+    dd(0.9447261340410098, 1.1325094975092593e-18),
+    dd(-0.9597794497589419, -1.1032640839599702e-17),
+    dd(0.9597794497589419, 1.1032640839599702e-17),
+    dd(-0.97248403469757, 3.1659874164519046e-17),
+    dd(0.97248403469757, -3.1659874164519046e-17),
+    dd(-0.9828088105937273, 3.1850903841376433e-17),
+    dd(0.9828088105937273, -3.1850903841376433e-17),
+    dd(-0.9907285468921895, 9.84718024200527e-18),
+    dd(0.9907285468921895, -9.84718024200527e-18),
+    dd(-0.9962240127779701, 1.6263396334981844e-17),
+    dd(0.9962240127779701, -1.6263396334981844e-17),
+    dd(-0.9992829840291237, -3.617156817782956e-17),
+    dd(0.9992829840291237, 3.617156817782956e-17),
+    dd(-0.024350292663424433, 1.206850029193905e-20),
+    dd(0.024350292663424433, -1.206850029193905e-20),
+    dd(-0.07299312178779904, 2.9163755251720135e-18),
+    dd(0.07299312178779904, -2.9163755251720135e-18),
+    dd(-0.12146281929612056, 3.806342773308752e-18),
+    dd(0.12146281929612056, -3.806342773308752e-18),
+    dd(-0.16964442042399283, 1.3022265004519112e-17),
+    dd(0.16964442042399283, -1.3022265004519112e-17),
+    dd(-0.21742364374000708, -9.913877236425309e-19),
+    dd(0.21742364374000708, 9.913877236425309e-19),
+    dd(-0.2646871622087674, 7.24726096190134e-18),
+    dd(0.2646871622087674, -7.24726096190134e-18),
+    dd(-0.31132287199021097, 1.3637275144496682e-17),
+    dd(0.31132287199021097, -1.3637275144496682e-17),
+    dd(-0.3572201583376681, 9.586181005018145e-18),
+    dd(0.3572201583376681, -9.586181005018145e-18),
+    dd(-0.4022701579639916, 2.2142196057621995e-17),
+    dd(0.4022701579639916, -2.2142196057621995e-17),
+    dd(-0.4463660172534641, -1.1180490179329592e-18),
+    dd(0.4463660172534641, 1.1180490179329592e-18),
+    dd(-0.48940314570705296, -1.87437137574244e-18),
+    dd(0.48940314570705296, 1.87437137574244e-18),
+    dd(-0.5312794640198946, 1.9383099406599826e-17),
+    dd(0.5312794640198946, -1.9383099406599826e-17),
+    dd(-0.571895646202634, -3.3873763748962965e-17),
+    dd(0.571895646202634, 3.3873763748962965e-17),
+    dd(-0.6111553551723933, 2.731415029553182e-17),
+    dd(0.6111553551723933, -2.731415029553182e-17),
+    dd(-0.6489654712546573, -2.865134601642805e-17),
+    dd(0.6489654712546573, 2.865134601642805e-17),
+    dd(-0.6852363130542333, 2.7750783181844326e-17),
+    dd(0.6852363130542333, -2.7750783181844326e-17),
+    dd(-0.7198818501716109, 5.512318826388863e-17),
+    dd(0.7198818501716109, -5.512318826388863e-17),
+    dd(-0.7528199072605319, 4.312923567486201e-17),
+    dd(0.7528199072605319, -4.312923567486201e-17),
+    dd(-0.7839723589433414, -2.232572267877196e-17),
+    dd(0.7839723589433414, 2.232572267877196e-17),
+    dd(-0.8132653151227975, -2.1192375002945733e-17),
+    dd(0.8132653151227975, 2.1192375002945733e-17),
+    dd(-0.8406292962525803, -4.690035887397791e-17),
+    dd(0.8406292962525803, 4.690035887397791e-17),
+    dd(-0.8659993981540928, -4.98748911183133e-17),
+    dd(0.8659993981540928, 4.98748911183133e-17),
+    dd(-0.8893154459951141, 3.4100892167996796e-17),
+    dd(0.8893154459951141, -3.4100892167996796e-17),
+    dd(-0.9105221370785028, 1.878010493675889e-17),
+    dd(0.9105221370785028, -1.878010493675889e-17),
+    dd(-0.9295691721319396, -6.318145198967042e-18),
+    dd(0.9295691721319396, 6.318145198967042e-18),
+    dd(-0.9464113748584028, -5.0842503554552515e-17),
+    dd(0.9464113748584028, 5.0842503554552515e-17),
+    dd(-0.9610087996520538, 5.006179455482819e-17),
+    dd(0.9610087996520538, -5.006179455482819e-17),
+    dd(-0.973326827789911, 1.1755069621605588e-17),
+    dd(0.973326827789911, -1.1755069621605588e-17),
+    dd(-0.983336253884626, 2.0119560244423054e-17),
+    dd(0.983336253884626, -2.0119560244423054e-17),
+    dd(-0.9910133714767443, -3.3780733483844094e-17),
+    dd(0.9910133714767443, 3.3780733483844094e-17),
+    dd(-0.9963401167719553, 5.150745778159209e-17),
+    dd(0.9963401167719553, -5.150745778159209e-17),
+    dd(-0.9993050417357722, 3.0962332967075526e-17),
+    dd(0.9993050417357722, -3.0962332967075526e-17),
+];
+
+static WEIGHTS: [DoubleDouble; 2079] = [
+    dd(1.0, 0.0),
+    dd(1.0, 0.0),
+    dd(0.8888888888888888, 4.9343245538895844e-17),
+    dd(0.5555555555555556, -2.4671622769447922e-17),
+    dd(0.5555555555555556, -2.4671622769447922e-17),
+    dd(0.6521451548625461, 4.7850814842508387e-17),
+    dd(0.6521451548625461, 4.7850814842508387e-17),
+    dd(0.34785484513745385, 7.660336388749442e-18),
+    dd(0.34785484513745385, 7.660336388749442e-18),
+    dd(0.5688888888888889, 4.934324553889585e-19),
+    dd(0.47862867049936647, -2.8616217915821202e-18),
+    dd(0.47862867049936647, -2.8616217915821202e-18),
+    dd(0.23692688505618908, 2.6149055638876413e-18),
+    dd(0.23692688505618908, 2.6149055638876413e-18),
+    dd(0.3607615730481386, 1.3020350910601565e-18),
+    dd(0.3607615730481386, 1.3020350910601565e-18),
+    dd(0.46791393457269104, 1.0328338883494817e-17),
+    dd(0.46791393457269104, 1.0328338883494817e-17),
+    dd(0.17132449237917036, -1.1630373974554974e-17),
+    dd(0.17132449237917036, -1.1630373974554974e-17),
+    dd(0.4179591836734694, -1.5497807119257288e-17),
+    dd(0.3818300505051189, 2.1862747923824822e-17),
+    dd(0.3818300505051189, 2.1862747923824822e-17),
+    dd(0.27970539148927664, 2.3267180221717138e-17),
+    dd(0.27970539148927664, 2.3267180221717138e-17),
+    dd(0.1294849661688697, -9.625448970284404e-18),
+    dd(0.1294849661688697, -9.625448970284404e-18),
+    dd(0.362683783378362, -7.24767323521018e-18),
+    dd(0.362683783378362, -7.24767323521018e-18),
+    dd(0.31370664587788727, 1.826860043779111e-17),
+    dd(0.31370664587788727, 1.826860043779111e-17),
+    dd(0.22238103445337448, -1.1507301429227283e-17),
+    dd(0.22238103445337448, -1.1507301429227283e-17),
+    dd(0.10122853629037626, 4.863742266463526e-19),
+    dd(0.10122853629037626, 4.863742266463526e-19),
+    dd(0.3302393550012598, -1.909840948080302e-17),
+    dd(0.1806481606948574, 8.144757049881505e-18),
+    dd(0.1806481606948574, 8.144757049881505e-18),
+    dd(0.08127438836157441, -2.931504525596834e-19),
+    dd(0.08127438836157441, -2.931504525596834e-19),
+    dd(0.31234707704000286, -2.2811300083141223e-17),
+    dd(0.31234707704000286, -2.2811300083141223e-17),
+    dd(0.26061069640293544, 2.4508898226220913e-17),
+    dd(0.26061069640293544, 2.4508898226220913e-17),
+    dd(0.29552422471475287, 1.4926748620194873e-19),
+    dd(0.29552422471475287, 1.4926748620194873e-19),
+    dd(0.26926671930999635, 5.461783364364092e-18),
+    dd(0.26926671930999635, 5.461783364364092e-18),
+    dd(0.21908636251598204, 2.4077873034994635e-18),
+    dd(0.21908636251598204, 2.4077873034994635e-18),
+    dd(0.1494513491505806, 6.257139381592662e-18),
+    dd(0.1494513491505806, 6.257139381592662e-18),
+    dd(0.06667134430868814, -3.981897278437097e-19),
+    dd(0.06667134430868814, -3.981897278437097e-19),
+    dd(0.2729250867779006, 1.449500027423731e-17),
+    dd(0.26280454451024665, 1.0657655591027214e-17),
+    dd(0.26280454451024665, 1.0657655591027214e-17),
+    dd(0.23319376459199048, -2.3192806669913245e-18),
+    dd(0.23319376459199048, -2.3192806669913245e-18),
+    dd(0.18629021092773426, -1.0732397329686105e-17),
+    dd(0.18629021092773426, -1.0732397329686105e-17),
+    dd(0.1255803694649046, 1.2581192434757997e-17),
+    dd(0.1255803694649046, 1.2581192434757997e-17),
+    dd(0.05566856711617366, 3.382011545495249e-18),
+    dd(0.05566856711617366, 3.382011545495249e-18),
+    dd(0.24914704581340277, 1.1771689547576084e-17),
+    dd(0.24914704581340277, 1.1771689547576084e-17),
+    dd(0.2334925365383548, 3.0522993555387965e-18),
+    dd(0.2334925365383548, 3.0522993555387965e-18),
+    dd(0.20316742672306592, -3.016101390301105e-18),
+    dd(0.20316742672306592, -3.016101390301105e-18),
+    dd(0.16007832854334622, 5.2545954948742064e-18),
+    dd(0.16007832854334622, 5.2545954948742064e-18),
+    dd(0.10693932599531843, 4.317166601559679e-18),
+    dd(0.10693932599531843, 4.317166601559679e-18),
+    dd(0.04717533638651183, -5.629678975259754e-19),
+    dd(0.04717533638651183, -5.629678975259754e-19),
+    dd(0.2325515532308739, 1.2679237814919706e-17),
+    dd(0.22628318026289723, 6.2187370385173466e-18),
+    dd(0.22628318026289723, 6.2187370385173466e-18),
+    dd(0.2078160475368885, -7.30449140052861e-18),
+    dd(0.2078160475368885, -7.30449140052861e-18),
+    dd(0.17814598076194574, 2.2218577019695094e-19),
+    dd(0.17814598076194574, 2.2218577019695094e-19),
+    dd(0.13887351021978725, -1.1056394176182095e-17),
+    dd(0.13887351021978725, -1.1056394176182095e-17),
+    dd(0.09212149983772845, -3.717346902044683e-18),
+    dd(0.09212149983772845, -3.717346902044683e-18),
+    dd(0.04048400476531588, 2.3587968586740077e-18),
+    dd(0.04048400476531588, 2.3587968586740077e-18),
+    dd(0.2152638534631578, -4.702687236136958e-18),
+    dd(0.2152638534631578, -4.702687236136958e-18),
+    dd(0.2051984637212956, -2.2369651044648186e-19),
+    dd(0.2051984637212956, -2.2369651044648186e-19),
+    dd(0.18553839747793782, -8.258199378137783e-18),
+    dd(0.18553839747793782, -8.258199378137783e-18),
+    dd(0.15720316715819355, -1.1790365801876155e-17),
+    dd(0.15720316715819355, -1.1790365801876155e-17),
+    dd(0.12151857068790319, -4.785181088480758e-19),
+    dd(0.12151857068790319, -4.785181088480758e-19),
+    dd(0.08015808715976021, 1.8763733428730957e-18),
+    dd(0.08015808715976021, 1.8763733428730957e-18),
+    dd(0.03511946033175186, 2.7604119808506722e-18),
+    dd(0.03511946033175186, 2.7604119808506722e-18),
+    dd(0.2025782419255613, -1.36265978284512e-17),
+    dd(0.19843148532711158, -2.153186516313571e-18),
+    dd(0.19843148532711158, -2.153186516313571e-18),
+    dd(0.1861610000155622, -3.0256685282523727e-19),
+    dd(0.1861610000155622, -3.0256685282523727e-19),
+    dd(0.16626920581699392, 1.3342622820894374e-17),
+    dd(0.16626920581699392, 1.3342622820894374e-17),
+    dd(0.13957067792615432, -9.552247248209171e-18),
+    dd(0.13957067792615432, -9.552247248209171e-18),
+    dd(0.10715922046717194, -4.482963053162582e-18),
+    dd(0.10715922046717194, -4.482963053162582e-18),
+    dd(0.07036604748810812, 3.345058292453826e-19),
+    dd(0.07036604748810812, 3.345058292453826e-19),
+    dd(0.03075324199611727, -7.812069212644382e-19),
+    dd(0.03075324199611727, -7.812069212644382e-19),
+    dd(0.1894506104550685, -5.883843495582664e-18),
+    dd(0.1894506104550685, -5.883843495582664e-18),
+    dd(0.18260341504492358, 5.090226510905207e-18),
+    dd(0.18260341504492358, 5.090226510905207e-18),
+    dd(0.16915651939500254, 2.323299329564479e-18),
+    dd(0.16915651939500254, 2.323299329564479e-18),
+    dd(0.14959598881657674, -3.887619883741701e-18),
+    dd(0.14959598881657674, -3.887619883741701e-18),
+    dd(0.12462897125553388, -4.841529802320495e-18),
+    dd(0.12462897125553388, -4.841529802320495e-18),
+    dd(0.09515851168249279, -8.783003597087393e-19),
+    dd(0.09515851168249279, -8.783003597087393e-19),
+    dd(0.062253523938647894, -7.690264522605704e-19),
+    dd(0.062253523938647894, -7.690264522605704e-19),
+    dd(0.027152459411754096, -1.56154670271636e-18),
+    dd(0.027152459411754096, -1.56154670271636e-18),
+    dd(0.17944647035620653, -7.844857152234724e-18),
+    dd(0.17656270536699264, 1.374430049603391e-18),
+    dd(0.17656270536699264, 1.374430049603391e-18),
+    dd(0.16800410215645004, 8.644594683395468e-18),
+    dd(0.16800410215645004, 8.644594683395468e-18),
+    dd(0.15404576107681028, 4.4518193088525525e-18),
+    dd(0.15404576107681028, 4.4518193088525525e-18),
+    dd(0.13513636846852548, -1.8419967889764432e-18),
+    dd(0.13513636846852548, -1.8419967889764432e-18),
+    dd(0.11188384719340397, 3.093653145397451e-18),
+    dd(0.11188384719340397, 3.093653145397451e-18),
+    dd(0.08503614831717918, 3.2254592422363616e-18),
+    dd(0.08503614831717918, 3.2254592422363616e-18),
+    dd(0.0554595293739872, -1.6532851822024548e-18),
+    dd(0.0554595293739872, -1.6532851822024548e-18),
+    dd(0.02414830286854793, 5.055419256254928e-19),
+    dd(0.02414830286854793, 5.055419256254928e-19),
+    dd(0.1691423829631436, -8.597715079763888e-18),
+    dd(0.1691423829631436, -8.597715079763888e-18),
+    dd(0.16427648374583273, -6.846460697389626e-18),
+    dd(0.16427648374583273, -6.846460697389626e-18),
+    dd(0.15468467512626524, 2.9631312810264254e-18),
+    dd(0.15468467512626524, 2.9631312810264254e-18),
+    dd(0.14064291467065065, -2.680799497083166e-18),
+    dd(0.14064291467065065, -2.680799497083166e-18),
+    dd(0.12255520671147846, 8.373991753832304e-19),
+    dd(0.12255520671147846, 8.373991753832304e-19),
+    dd(0.10094204410628717, -2.6075188059259725e-18),
+    dd(0.10094204410628717, -2.6075188059259725e-18),
+    dd(0.07642573025488905, 4.944375066403935e-18),
+    dd(0.07642573025488905, 4.944375066403935e-18),
+    dd(0.0497145488949698, -5.016218737427984e-19),
+    dd(0.0497145488949698, -5.016218737427984e-19),
+    dd(0.02161601352648331, -1.3885773767225958e-18),
+    dd(0.02161601352648331, -1.3885773767225958e-18),
+    dd(0.1610544498487837, -2.427698560626913e-18),
+    dd(0.15896884339395434, 7.712377105246293e-18),
+    dd(0.15896884339395434, 7.712377105246293e-18),
+    dd(0.15276604206585967, -2.8286639213009782e-18),
+    dd(0.15276604206585967, -2.8286639213009782e-18),
+    dd(0.1426067021736066, 8.607891408855144e-18),
+    dd(0.1426067021736066, 8.607891408855144e-18),
+    dd(0.12875396253933621, 1.3520743165234642e-17),
+    dd(0.12875396253933621, 1.3520743165234642e-17),
+    dd(0.11156664554733399, 5.075098179539638e-18),
+    dd(0.11156664554733399, 5.075098179539638e-18),
+    dd(0.09149002162245, 4.363915721790842e-19),
+    dd(0.09149002162245, 4.363915721790842e-19),
+    dd(0.06904454273764123, 2.8750897663408684e-19),
+    dd(0.06904454273764123, 2.8750897663408684e-19),
+    dd(0.0448142267656996, 7.133857150902542e-19),
+    dd(0.0448142267656996, 7.133857150902542e-19),
+    dd(0.019461788229726478, -1.0858603535821801e-18),
+    dd(0.019461788229726478, -1.0858603535821801e-18),
+    dd(0.15275338713072584, 1.340295334535119e-17),
+    dd(0.15275338713072584, 1.340295334535119e-17),
+    dd(0.14917298647260374, 5.450889017016148e-18),
+    dd(0.14917298647260374, 5.450889017016148e-18),
+    dd(0.14209610931838204, 1.0153688127497397e-17),
+    dd(0.14209610931838204, 1.0153688127497397e-17),
+    dd(0.13168863844917664, -1.0181179424087636e-17),
+    dd(0.13168863844917664, -1.0181179424087636e-17),
+    dd(0.11819453196151841, 5.301374412410806e-18),
+    dd(0.11819453196151841, 5.301374412410806e-18),
+    dd(0.10193011981724044, -6.5341878677436505e-18),
+    dd(0.10193011981724044, -6.5341878677436505e-18),
+    dd(0.08327674157670475, -6.018929287851294e-18),
+    dd(0.08327674157670475, -6.018929287851294e-18),
+    dd(0.06267204833410907, -4.2658003813625565e-18),
+    dd(0.06267204833410907, -4.2658003813625565e-18),
+    dd(0.04060142980038694, 2.6688577065676327e-18),
+    dd(0.04060142980038694, 2.6688577065676327e-18),
+    dd(0.017614007139152118, 4.3067520806280603e-19),
+    dd(0.017614007139152118, 4.3067520806280603e-19),
+    dd(0.14608113364969041, 1.2714267566107228e-17),
+    dd(0.14452440398997005, 1.29500161579725e-17),
+    dd(0.14452440398997005, 1.29500161579725e-17),
+    dd(0.13988739479107315, 5.053030619685253e-18),
+    dd(0.13988739479107315, 5.053030619685253e-18),
+    dd(0.13226893863333747, -6.588025208384729e-18),
+    dd(0.13226893863333747, -6.588025208384729e-18),
+    dd(0.12183141605372853, 7.513444468891225e-19),
+    dd(0.12183141605372853, 7.513444468891225e-19),
+    dd(0.10879729916714838, -8.99033948031632e-19),
+    dd(0.10879729916714838, -8.99033948031632e-19),
+    dd(0.09344442345603386, -5.765317023179331e-19),
+    dd(0.09344442345603386, -5.765317023179331e-19),
+    dd(0.0761001136283793, -1.9146075411041633e-18),
+    dd(0.0761001136283793, -1.9146075411041633e-18),
+    dd(0.057134425426857205, 3.3509622868844528e-18),
+    dd(0.057134425426857205, 3.3509622868844528e-18),
+    dd(0.036953789770852494, 7.64764713588147e-20),
+    dd(0.036953789770852494, 7.64764713588147e-20),
+    dd(0.016017228257774335, -1.2135306062372298e-18),
+    dd(0.016017228257774335, -1.2135306062372298e-18),
+    dd(0.13925187285563198, 1.2678810067705941e-17),
+    dd(0.13925187285563198, 1.2678810067705941e-17),
+    dd(0.13654149834601517, -7.525096482053537e-19),
+    dd(0.13654149834601517, -7.525096482053537e-19),
+    dd(0.13117350478706238, -1.3081024193379724e-17),
+    dd(0.13117350478706238, -1.3081024193379724e-17),
+    dd(0.12325237681051242, 6.392687670304803e-18),
+    dd(0.12325237681051242, 6.392687670304803e-18),
+    dd(0.11293229608053922, 2.7498105954713476e-18),
+    dd(0.11293229608053922, 2.7498105954713476e-18),
+    dd(0.10041414444288096, 7.394528553287753e-20),
+    dd(0.10041414444288096, 7.394528553287753e-20),
+    dd(0.08594160621706773, -1.2091605577738133e-18),
+    dd(0.08594160621706773, -1.2091605577738133e-18),
+    dd(0.06979646842452049, -5.098727374462375e-19),
+    dd(0.06979646842452049, -5.098727374462375e-19),
+    dd(0.052293335152683286, -3.0941417187206677e-20),
+    dd(0.052293335152683286, -3.0941417187206677e-20),
+    dd(0.03377490158481415, 3.292700238283849e-18),
+    dd(0.03377490158481415, 3.292700238283849e-18),
+    dd(0.0146279952982722, 8.038955525543593e-19),
+    dd(0.0146279952982722, 8.038955525543593e-19),
+    dd(0.13365457218610619, -9.931637194759141e-18),
+    dd(0.1324620394046966, 4.1731891725327195e-18),
+    dd(0.1324620394046966, 4.1731891725327195e-18),
+    dd(0.12890572218808216, -1.1338317903954272e-17),
+    dd(0.12890572218808216, -1.1338317903954272e-17),
+    dd(0.12304908430672953, -3.210103897148856e-18),
+    dd(0.12304908430672953, -3.210103897148856e-18),
+    dd(0.11499664022241136, 6.456144780301936e-19),
+    dd(0.11499664022241136, 6.456144780301936e-19),
+    dd(0.10489209146454141, -2.0084033726186363e-18),
+    dd(0.10489209146454141, -2.0084033726186363e-18),
+    dd(0.09291576606003515, -6.784224333809391e-18),
+    dd(0.09291576606003515, -6.784224333809391e-18),
+    dd(0.07928141177671895, 5.798072212151443e-18),
+    dd(0.07928141177671895, 5.798072212151443e-18),
+    dd(0.06423242140852585, 2.2119975694468135e-18),
+    dd(0.06423242140852585, 2.2119975694468135e-18),
+    dd(0.04803767173108467, -4.639974408366882e-19),
+    dd(0.04803767173108467, -4.639974408366882e-19),
+    dd(0.030988005856979445, -4.524609096524341e-19),
+    dd(0.030988005856979445, -4.524609096524341e-19),
+    dd(0.013411859487141771, 7.819417394474148e-19),
+    dd(0.013411859487141771, 7.819417394474148e-19),
+    dd(0.12793819534675216, -2.3463464323618126e-18),
+    dd(0.12793819534675216, -2.3463464323618126e-18),
+    dd(0.1258374563468283, -6.378909352776821e-18),
+    dd(0.1258374563468283, -6.378909352776821e-18),
+    dd(0.12167047292780339, -2.0081385799594554e-19),
+    dd(0.12167047292780339, -2.0081385799594554e-19),
+    dd(0.1155056680537256, 2.1552772973718842e-18),
+    dd(0.1155056680537256, 2.1552772973718842e-18),
+    dd(0.10744427011596563, 4.113417050012542e-19),
+    dd(0.10744427011596563, 4.113417050012542e-19),
+    dd(0.09761865210411388, 3.8874947738607746e-18),
+    dd(0.09761865210411388, 3.8874947738607746e-18),
+    dd(0.08619016153195327, 1.5740755196972858e-18),
+    dd(0.08619016153195327, 1.5740755196972858e-18),
+    dd(0.0733464814110803, 5.894777856910201e-18),
+    dd(0.0733464814110803, 5.894777856910201e-18),
+    dd(0.05929858491543678, -2.5916486103160615e-18),
+    dd(0.05929858491543678, -2.5916486103160615e-18),
+    dd(0.04427743881741981, -1.5797517950528749e-18),
+    dd(0.04427743881741981, -1.5797517950528749e-18),
+    dd(0.028531388628933663, -1.892826074174435e-19),
+    dd(0.028531388628933663, -1.892826074174435e-19),
+    dd(0.0123412297999872, -6.362144969204398e-19),
+    dd(0.0123412297999872, -6.362144969204398e-19),
+    dd(0.12317605372671545, 5.812809533257086e-18),
+    dd(0.12224244299031004, 6.555398545937281e-18),
+    dd(0.12224244299031004, 6.555398545937281e-18),
+    dd(0.11945576353578477, 1.9819828188493176e-18),
+    dd(0.11945576353578477, 1.9819828188493176e-18),
+    dd(0.11485825914571164, 6.925791336137095e-18),
+    dd(0.11485825914571164, 6.925791336137095e-18),
+    dd(0.10851962447426365, 1.901260242374638e-18),
+    dd(0.10851962447426365, 1.901260242374638e-18),
+    dd(0.10053594906705064, 1.9328349275812663e-18),
+    dd(0.10053594906705064, 1.9328349275812663e-18),
+    dd(0.09102826198296365, -4.308274269810921e-18),
+    dd(0.09102826198296365, -4.308274269810921e-18),
+    dd(0.08014070033500102, -4.178803573706924e-18),
+    dd(0.08014070033500102, -4.178803573706924e-18),
+    dd(0.06803833381235691, 6.849920870397669e-18),
+    dd(0.06803833381235691, 6.849920870397669e-18),
+    dd(0.054904695975835194, -1.862646518883125e-18),
+    dd(0.054904695975835194, -1.862646518883125e-18),
+    dd(0.040939156701306316, -3.2996231297656684e-18),
+    dd(0.040939156701306316, -3.2996231297656684e-18),
+    dd(0.026354986615032137, 5.465931625984228e-19),
+    dd(0.026354986615032137, 5.465931625984228e-19),
+    dd(0.011393798501026288, -3.3832789454632977e-19),
+    dd(0.011393798501026288, -3.3832789454632977e-19),
+    dd(0.11832141527926228, -2.6801660363608038e-18),
+    dd(0.11832141527926228, -2.6801660363608038e-18),
+    dd(0.11666044348529658, -1.210088605150066e-18),
+    dd(0.11666044348529658, -1.210088605150066e-18),
+    dd(0.11336181654631966, 6.2345619439995386e-18),
+    dd(0.11336181654631966, 6.2345619439995386e-18),
+    dd(0.10847184052857659, 5.451279051236209e-18),
+    dd(0.10847184052857659, 5.451279051236209e-18),
+    dd(0.10205916109442542, 1.9594723578093647e-18),
+    dd(0.10205916109442542, 1.9594723578093647e-18),
+    dd(0.09421380035591415, 2.7626009388513003e-18),
+    dd(0.09421380035591415, 2.7626009388513003e-18),
+    dd(0.08504589431348523, 4.560295997824971e-18),
+    dd(0.08504589431348523, 4.560295997824971e-18),
+    dd(0.07468414976565975, -3.27792743778106e-18),
+    dd(0.07468414976565975, -3.27792743778106e-18),
+    dd(0.06327404632957484, -4.5579113505851046e-18),
+    dd(0.06327404632957484, -4.5579113505851046e-18),
+    dd(0.05097582529714781, 3.2526851668283605e-18),
+    dd(0.05097582529714781, 3.2526851668283605e-18),
+    dd(0.037962383294362766, -1.7007945052199196e-18),
+    dd(0.037962383294362766, -1.7007945052199196e-18),
+    dd(0.02441785109263191, -1.1309558591920215e-18),
+    dd(0.02441785109263191, -1.1309558591920215e-18),
+    dd(0.010551372617343006, 7.452891936000734e-19),
+    dd(0.010551372617343006, 7.452891936000734e-19),
+    dd(0.114220867378957, -5.4417037286209e-18),
+    dd(0.11347634610896515, 4.464617152717043e-19),
+    dd(0.11347634610896515, 4.464617152717043e-19),
+    dd(0.11125248835684519, 3.783940842688961e-18),
+    dd(0.11125248835684519, 3.783940842688961e-18),
+    dd(0.10757828578853319, -5.578354050182708e-18),
+    dd(0.10757828578853319, -5.578354050182708e-18),
+    dd(0.1025016378177458, 4.370186842785364e-18),
+    dd(0.1025016378177458, 4.370186842785364e-18),
+    dd(0.0960887273700285, 9.520664299133435e-19),
+    dd(0.0960887273700285, 9.520664299133435e-19),
+    dd(0.08842315854375694, 6.244257894631774e-18),
+    dd(0.08842315854375694, 6.244257894631774e-18),
+    dd(0.07960486777305777, 4.86283957907655e-18),
+    dd(0.07960486777305777, 4.86283957907655e-18),
+    dd(0.0697488237662456, -3.2274873209405876e-18),
+    dd(0.0697488237662456, -3.2274873209405876e-18),
+    dd(0.058983536859833596, 2.8216627425099543e-18),
+    dd(0.058983536859833596, 2.8216627425099543e-18),
+    dd(0.04744941252061506, 1.3868882383225115e-18),
+    dd(0.04744941252061506, 1.3868882383225115e-18),
+    dd(0.03529705375741971, -1.1051958580430246e-18),
+    dd(0.03529705375741971, -1.1051958580430246e-18),
+    dd(0.022686231596180623, -1.805787217080659e-19),
+    dd(0.022686231596180623, -1.805787217080659e-19),
+    dd(0.00979899605129436, 8.7227861822323e-20),
+    dd(0.00979899605129436, 8.7227861822323e-20),
+    dd(0.1100470130164752, 9.917874367903665e-19),
+    dd(0.1100470130164752, 9.917874367903665e-19),
+    dd(0.10871119225829413, 1.015010561975228e-18),
+    dd(0.10871119225829413, 1.015010561975228e-18),
+    dd(0.10605576592284642, -2.6576544317427856e-18),
+    dd(0.10605576592284642, -2.6576544317427856e-18),
+    dd(0.10211296757806076, 4.963682823754169e-18),
+    dd(0.10211296757806076, 4.963682823754169e-18),
+    dd(0.09693065799792992, -6.803001558148398e-18),
+    dd(0.09693065799792992, -6.803001558148398e-18),
+    dd(0.09057174439303284, 2.859184097199356e-18),
+    dd(0.09057174439303284, 2.859184097199356e-18),
+    dd(0.08311341722890121, 5.971328808237264e-18),
+    dd(0.08311341722890121, 5.971328808237264e-18),
+    dd(0.07464621423456878, -4.43932094074138e-18),
+    dd(0.07464621423456878, -4.43932094074138e-18),
+    dd(0.0652729239669996, -6.1811947709076295e-18),
+    dd(0.0652729239669996, -6.1811947709076295e-18),
+    dd(0.05510734567571675, -3.036160039848939e-18),
+    dd(0.05510734567571675, -3.036160039848939e-18),
+    dd(0.04427293475900423, 4.1661407924173107e-19),
+    dd(0.04427293475900423, 4.1661407924173107e-19),
+    dd(0.03290142778230438, 1.9995909526225443e-18),
+    dd(0.03290142778230438, 1.9995909526225443e-18),
+    dd(0.02113211259277126, -9.158565885271041e-19),
+    dd(0.02113211259277126, -9.158565885271041e-19),
+    dd(0.009124282593094517, 6.118191421651576e-19),
+    dd(0.009124282593094517, 6.118191421651576e-19),
+    dd(0.10647938171831424, 4.877539030764961e-18),
+    dd(0.10587615509732094, -1.1375454847053657e-18),
+    dd(0.10587615509732094, -1.1375454847053657e-18),
+    dd(0.10407331007772938, -6.782670412935778e-18),
+    dd(0.10407331007772938, -6.782670412935778e-18),
+    dd(0.10109127375991496, 6.448375634993522e-18),
+    dd(0.10109127375991496, 6.448375634993522e-18),
+    dd(0.0969638340944086, 1.621695897043502e-18),
+    dd(0.0969638340944086, 1.621695897043502e-18),
+    dd(0.09173775713925876, 2.939748359169941e-18),
+    dd(0.09173775713925876, 2.939748359169941e-18),
+    dd(0.08547225736617253, -5.121236824468696e-18),
+    dd(0.08547225736617253, -5.121236824468696e-18),
+    dd(0.07823832713576379, -1.6744308403098518e-18),
+    dd(0.07823832713576379, -1.6744308403098518e-18),
+    dd(0.07011793325505128, -4.815917094727078e-19),
+    dd(0.07011793325505128, -4.815917094727078e-19),
+    dd(0.061203090657079136, 2.7253338314696213e-18),
+    dd(0.061203090657079136, 2.7253338314696213e-18),
+    dd(0.05159482690249793, -3.0555330917668923e-18),
+    dd(0.05159482690249793, -3.0555330917668923e-18),
+    dd(0.041402062518682836, -5.1528452062208765e-20),
+    dd(0.041402062518682836, -5.1528452062208765e-20),
+    dd(0.030740492202093624, -1.6466064362390382e-18),
+    dd(0.030740492202093624, -1.6466064362390382e-18),
+    dd(0.019732085056122707, -1.094658828301359e-18),
+    dd(0.019732085056122707, -1.094658828301359e-18),
+    dd(0.00851690387874641, -3.322915857275897e-19),
+    dd(0.00851690387874641, -3.322915857275897e-19),
+    dd(0.10285265289355884, -4.329828668318992e-19),
+    dd(0.10285265289355884, -4.329828668318992e-19),
+    dd(0.1017623897484055, 5.5949577105416945e-18),
+    dd(0.1017623897484055, 5.5949577105416945e-18),
+    dd(0.09959342058679527, -3.929568596510566e-20),
+    dd(0.09959342058679527, -3.929568596510566e-20),
+    dd(0.09636873717464425, 6.265712184591176e-18),
+    dd(0.09636873717464425, 6.265712184591176e-18),
+    dd(0.09212252223778612, 6.238901719137361e-18),
+    dd(0.09212252223778612, 6.238901719137361e-18),
+    dd(0.08689978720108298, 3.973029340347924e-18),
+    dd(0.08689978720108298, 3.973029340347924e-18),
+    dd(0.08075589522942021, 2.2108055541053955e-18),
+    dd(0.08075589522942021, 2.2108055541053955e-18),
+    dd(0.0737559747377052, 1.8656280726961996e-18),
+    dd(0.0737559747377052, 1.8656280726961996e-18),
+    dd(0.06597422988218049, 4.458687473142776e-18),
+    dd(0.06597422988218049, 4.458687473142776e-18),
+    dd(0.057493156217619065, 1.2303875687989234e-18),
+    dd(0.057493156217619065, 1.2303875687989234e-18),
+    dd(0.04840267283059405, 2.809162348597197e-19),
+    dd(0.04840267283059405, 2.809162348597197e-19),
+    dd(0.03879919256962705, -5.010338267075052e-19),
+    dd(0.03879919256962705, -5.010338267075052e-19),
+    dd(0.02878470788332337, 3.8429666224865196e-19),
+    dd(0.02878470788332337, 3.8429666224865196e-19),
+    dd(0.01846646831109096, 8.215051033208603e-19),
+    dd(0.01846646831109096, 8.215051033208603e-19),
+    dd(0.007968192496166605, 6.082307992731624e-19),
+    dd(0.007968192496166605, 6.082307992731624e-19),
+    dd(0.09972054479342646, -6.2916871007159275e-18),
+    dd(0.09922501122667231, 1.0124250611877129e-19),
+    dd(0.09922501122667231, 1.0124250611877129e-19),
+    dd(0.09774333538632872, 5.444011540081436e-18),
+    dd(0.09774333538632872, 5.444011540081436e-18),
+    dd(0.09529024291231951, 3.979347801704723e-18),
+    dd(0.09529024291231951, 3.979347801704723e-18),
+    dd(0.09189011389364148, 2.464600845042961e-18),
+    dd(0.09189011389364148, 2.464600845042961e-18),
+    dd(0.08757674060847788, -3.2089494504280948e-18),
+    dd(0.08757674060847788, -3.2089494504280948e-18),
+    dd(0.08239299176158926, 1.2701318346763419e-18),
+    dd(0.08239299176158926, 1.2701318346763419e-18),
+    dd(0.07639038659877662, 2.370030480611173e-19),
+    dd(0.07639038659877662, 2.370030480611173e-19),
+    dd(0.06962858323541037, 5.194570348650536e-19),
+    dd(0.06962858323541037, 5.194570348650536e-19),
+    dd(0.06217478656102843, -9.12262546871414e-19),
+    dd(0.06217478656102843, -9.12262546871414e-19),
+    dd(0.054103082424916855, -1.7190747215503633e-18),
+    dd(0.054103082424916855, -1.7190747215503633e-18),
+    dd(0.045493707527201104, -5.997744046145979e-19),
+    dd(0.045493707527201104, -5.997744046145979e-19),
+    dd(0.03643227391238547, -2.8941478231461734e-18),
+    dd(0.03643227391238547, -2.8941478231461734e-18),
+    dd(0.027009019184979423, -1.033427747377754e-18),
+    dd(0.027009019184979423, -1.033427747377754e-18),
+    dd(0.017318620790310584, -1.7211658760498805e-18),
+    dd(0.017318620790310584, -1.7211658760498805e-18),
+    dd(0.0074708315792487755, 3.514897718574342e-19),
+    dd(0.0074708315792487755, 3.514897718574342e-19),
+    dd(0.0965400885147278, 2.3921675903415145e-18),
+    dd(0.0965400885147278, 2.3921675903415145e-18),
+    dd(0.09563872007927486, -1.5443765910285133e-18),
+    dd(0.09563872007927486, -1.5443765910285133e-18),
+    dd(0.09384439908080457, -7.428833318947708e-19),
+    dd(0.09384439908080457, -7.428833318947708e-19),
+    dd(0.09117387869576389, -6.098630120418987e-18),
+    dd(0.09117387869576389, -6.098630120418987e-18),
+    dd(0.08765209300440381, 1.2650405119183659e-19),
+    dd(0.08765209300440381, 1.2650405119183659e-19),
+    dd(0.08331192422694675, 6.627130604182229e-18),
+    dd(0.08331192422694675, 6.627130604182229e-18),
+    dd(0.07819389578707031, -4.604664541142977e-18),
+    dd(0.07819389578707031, -4.604664541142977e-18),
+    dd(0.0723457941088485, 1.6365503743121054e-18),
+    dd(0.0723457941088485, 1.6365503743121054e-18),
+    dd(0.06582222277636185, -2.64570078838998e-18),
+    dd(0.06582222277636185, -2.64570078838998e-18),
+    dd(0.058684093478535544, 2.948122592678491e-18),
+    dd(0.058684093478535544, 2.948122592678491e-18),
+    dd(0.050998059262376175, 1.4910740178987887e-18),
+    dd(0.050998059262376175, 1.4910740178987887e-18),
+    dd(0.04283589802222668, -2.367126564958019e-18),
+    dd(0.04283589802222668, -2.367126564958019e-18),
+    dd(0.03427386291302143, 1.6493679117042415e-18),
+    dd(0.03427386291302143, 1.6493679117042415e-18),
+    dd(0.02539206530926206, 6.636912225042373e-19),
+    dd(0.02539206530926206, 6.636912225042373e-19),
+    dd(0.01627439473090567, 2.2978344461868e-19),
+    dd(0.01627439473090567, 2.2978344461868e-19),
+    dd(0.007018610009470096, 2.3899012840112396e-19),
+    dd(0.007018610009470096, 2.3899012840112396e-19),
+    dd(0.09376844616021, -6.769971877187033e-18),
+    dd(0.09335642606559612, -1.9524552117979124e-18),
+    dd(0.09335642606559612, -1.9524552117979124e-18),
+    dd(0.09212398664331685, -2.952267153419085e-18),
+    dd(0.09212398664331685, -2.952267153419085e-18),
+    dd(0.09008195866063858, 1.975009576322555e-18),
+    dd(0.09008195866063858, 1.975009576322555e-18),
+    dd(0.08724828761884433, 6.903110784652887e-18),
+    dd(0.08724828761884433, 6.903110784652887e-18),
+    dd(0.0836478760670387, 3.685048230828762e-18),
+    dd(0.0836478760670387, 3.685048230828762e-18),
+    dd(0.07931236479488674, 2.2892778227987135e-18),
+    dd(0.07931236479488674, 2.2892778227987135e-18),
+    dd(0.07427985484395415, 3.961554691487507e-19),
+    dd(0.07427985484395415, 3.961554691487507e-19),
+    dd(0.06859457281865672, -5.487839865275416e-18),
+    dd(0.06859457281865672, -5.487839865275416e-18),
+    dd(0.06230648253031748, -7.0752935087037935e-19),
+    dd(0.06230648253031748, -7.0752935087037935e-19),
+    dd(0.05547084663166356, 2.2839535803015958e-18),
+    dd(0.05547084663166356, 2.2839535803015958e-18),
+    dd(0.048147742818711696, -3.0775994800963246e-19),
+    dd(0.048147742818711696, -3.0775994800963246e-19),
+    dd(0.040401541331669594, -2.1517381351225456e-18),
+    dd(0.040401541331669594, -2.1517381351225456e-18),
+    dd(0.032300358632328954, -1.0409486811002457e-18),
+    dd(0.032300358632328954, -1.0409486811002457e-18),
+    dd(0.02391554810174948, 1.7016635175018923e-18),
+    dd(0.02391554810174948, 1.7016635175018923e-18),
+    dd(0.015321701512934676, 4.210895217429405e-19),
+    dd(0.015321701512934676, 4.210895217429405e-19),
+    dd(0.006606227847587378, 6.493925686744349e-20),
+    dd(0.006606227847587378, 6.493925686744349e-20),
+    dd(0.09095674033025987, 4.3504092632222495e-18),
+    dd(0.09095674033025987, 4.3504092632222495e-18),
+    dd(0.09020304437064074, -6.863985145197092e-18),
+    dd(0.09020304437064074, -6.863985145197092e-18),
+    dd(0.08870189783569386, 6.183497608415043e-18),
+    dd(0.08870189783569386, 6.183497608415043e-18),
+    dd(0.08646573974703575, -2.7095398747847362e-18),
+    dd(0.08646573974703575, -2.7095398747847362e-18),
+    dd(0.08351309969984566, -5.763338932732895e-18),
+    dd(0.08351309969984566, -5.763338932732895e-18),
+    dd(0.07986844433977185, -1.5798574632439603e-18),
+    dd(0.07986844433977185, -1.5798574632439603e-18),
+    dd(0.07556197466003194, -4.58676192300395e-18),
+    dd(0.07556197466003194, -4.58676192300395e-18),
+    dd(0.07062937581425573, -1.5298952796460874e-18),
+    dd(0.07062937581425573, -1.5298952796460874e-18),
+    dd(0.06511152155407642, -3.907183389017346e-18),
+    dd(0.06511152155407642, -3.907183389017346e-18),
+    dd(0.059054135827524494, -8.350562045487615e-19),
+    dd(0.059054135827524494, -8.350562045487615e-19),
+    dd(0.05250741457267811, -2.4092066299346684e-18),
+    dd(0.05250741457267811, -2.4092066299346684e-18),
+    dd(0.04552561152335327, 1.460880519946123e-18),
+    dd(0.04552561152335327, 1.460880519946123e-18),
+    dd(0.03816659379638752, -7.684191505660467e-19),
+    dd(0.03816659379638752, -7.684191505660467e-19),
+    dd(0.03049138063844613, 5.579135620804459e-19),
+    dd(0.03049138063844613, 5.579135620804459e-19),
+    dd(0.02256372198549497, 1.342668252358925e-18),
+    dd(0.02256372198549497, 1.342668252358925e-18),
+    dd(0.014450162748595036, -2.736344637971763e-19),
+    dd(0.014450162748595036, -2.736344637971763e-19),
+    dd(0.006229140555908685, -1.5725509318138087e-20),
+    dd(0.006229140555908685, -1.5725509318138087e-20),
+    dd(0.08848679490710429, 2.5611141862409885e-18),
+    dd(0.08814053043027546, 2.230586701389429e-19),
+    dd(0.08814053043027546, 2.230586701389429e-19),
+    dd(0.08710444699718353, 2.0124869192189832e-18),
+    dd(0.08710444699718353, 2.0124869192189832e-18),
+    dd(0.08538665339209912, 1.3655180963383897e-18),
+    dd(0.08538665339209912, 1.3655180963383897e-18),
+    dd(0.08300059372885658, 4.671484554148495e-18),
+    dd(0.08300059372885658, 4.671484554148495e-18),
+    dd(0.07996494224232427, -6.017941912759107e-18),
+    dd(0.07996494224232427, -6.017941912759107e-18),
+    dd(0.07630345715544205, 3.3286246655964147e-19),
+    dd(0.07630345715544205, 3.3286246655964147e-19),
+    dd(0.07204479477256007, -2.143502142357705e-18),
+    dd(0.07204479477256007, -2.143502142357705e-18),
+    dd(0.0672222852690869, 5.6904007577259475e-18),
+    dd(0.0672222852690869, 5.6904007577259475e-18),
+    dd(0.061873671966080186, 2.6739480110680942e-18),
+    dd(0.061873671966080186, 2.6739480110680942e-18),
+    dd(0.05604081621237013, -8.99908668529614e-20),
+    dd(0.05604081621237013, -8.99908668529614e-20),
+    dd(0.04976937040135353, 2.727163055661477e-18),
+    dd(0.04976937040135353, 2.727163055661477e-18),
+    dd(0.043108422326170216, 2.539004678951628e-18),
+    dd(0.043108422326170216, 2.539004678951628e-18),
+    dd(0.03611011586346338, -1.7802974795621818e-18),
+    dd(0.03611011586346338, -1.7802974795621818e-18),
+    dd(0.028829260108894254, 4.095714171892483e-19),
+    dd(0.028829260108894254, 4.095714171892483e-19),
+    dd(0.021322979911483582, -1.0217247812580991e-18),
+    dd(0.021322979911483582, -1.0217247812580991e-18),
+    dd(0.013650828348361493, -4.3145598391294766e-19),
+    dd(0.013650828348361493, -4.3145598391294766e-19),
+    dd(0.005883433420443085, -2.980782215806899e-19),
+    dd(0.005883433420443085, -2.980782215806899e-19),
+    dd(0.08598327567039475, -4.009706932761385e-18),
+    dd(0.08598327567039475, -4.009706932761385e-18),
+    dd(0.08534668573933862, 3.925453752156082e-18),
+    dd(0.08534668573933862, 3.925453752156082e-18),
+    dd(0.08407821897966193, 3.790635995054591e-18),
+    dd(0.08407821897966193, 3.790635995054591e-18),
+    dd(0.0821872667043397, 3.0260121143377148e-18),
+    dd(0.0821872667043397, 3.0260121143377148e-18),
+    dd(0.0796878289120716, 8.912940989304438e-19),
+    dd(0.0796878289120716, 8.912940989304438e-19),
+    dd(0.07659841064587067, 6.361268008301774e-18),
+    dd(0.07659841064587067, 6.361268008301774e-18),
+    dd(0.07294188500565306, 2.0276595273965454e-18),
+    dd(0.07294188500565306, 2.0276595273965454e-18),
+    dd(0.06874532383573645, -6.722363871342211e-18),
+    dd(0.06874532383573645, -6.722363871342211e-18),
+    dd(0.06403979735501548, 4.79754031909664e-18),
+    dd(0.06403979735501548, 4.79754031909664e-18),
+    dd(0.05886014424532482, -1.8358618763804002e-18),
+    dd(0.05886014424532482, -1.8358618763804002e-18),
+    dd(0.05324471397775992, -1.6168619915805807e-18),
+    dd(0.05324471397775992, -1.6168619915805807e-18),
+    dd(0.04723508349026598, 6.707246288157936e-19),
+    dd(0.04723508349026598, 6.707246288157936e-19),
+    dd(0.04087575092364489, 3.3754961032652747e-18),
+    dd(0.04087575092364489, 3.3754961032652747e-18),
+    dd(0.03421381077030723, -2.0241856532962433e-18),
+    dd(0.03421381077030723, -2.0241856532962433e-18),
+    dd(0.02729862149856878, 4.738867857942545e-19),
+    dd(0.02729862149856878, 4.738867857942545e-19),
+    dd(0.020181515297735472, -7.264456466939738e-19),
+    dd(0.020181515297735472, -7.264456466939738e-19),
+    dd(0.012915947284065574, 7.186738915523912e-19),
+    dd(0.012915947284065574, 7.186738915523912e-19),
+    dd(0.0055657196642450455, -1.1279318282065788e-19),
+    dd(0.0055657196642450455, -1.1279318282065788e-19),
+    dd(0.0837683609931389, 3.0587752632220585e-19),
+    dd(0.08347457362586279, -1.2539936494415833e-18),
+    dd(0.08347457362586279, -1.2539936494415833e-18),
+    dd(0.08259527223643726, -5.1280843443747905e-18),
+    dd(0.08259527223643726, -5.1280843443747905e-18),
+    dd(0.08113662450846502, 6.0131270290197906e-18),
+    dd(0.08113662450846502, 6.0131270290197906e-18),
+    dd(0.07910886183752938, -9.54766251294067e-19),
+    dd(0.07910886183752938, -9.54766251294067e-19),
+    dd(0.07652620757052923, 4.049101104693186e-18),
+    dd(0.07652620757052923, 4.049101104693186e-18),
+    dd(0.07340677724848817, 5.737631690975986e-18),
+    dd(0.07340677724848817, 5.737631690975986e-18),
+    dd(0.06977245155570035, -1.1857615975116612e-18),
+    dd(0.06977245155570035, -1.1857615975116612e-18),
+    dd(0.06564872287275125, -3.359646733416819e-18),
+    dd(0.06564872287275125, -3.359646733416819e-18),
+    dd(0.06106451652322599, -3.4217041787682013e-18),
+    dd(0.06106451652322599, -3.4217041787682013e-18),
+    dd(0.05605198799827492, -8.145924288182761e-19),
+    dd(0.05605198799827492, -8.145924288182761e-19),
+    dd(0.050646297654824604, -2.502540211868916e-18),
+    dd(0.050646297654824604, -2.502540211868916e-18),
+    dd(0.044885364662437165, 1.9951205582901066e-18),
+    dd(0.044885364662437165, 1.9951205582901066e-18),
+    dd(0.03880960250193454, 3.3478692213203194e-18),
+    dd(0.03880960250193454, 3.3478692213203194e-18),
+    dd(0.032461639847521484, -2.9474575969600728e-18),
+    dd(0.032461639847521484, -2.9474575969600728e-18),
+    dd(0.025886036990558935, -1.122330779666112e-18),
+    dd(0.025886036990558935, -1.122330779666112e-18),
+    dd(0.019129044489083966, 4.468034146221298e-19),
+    dd(0.019129044489083966, 4.468034146221298e-19),
+    dd(0.012238780100307557, -3.2380953527791014e-19),
+    dd(0.012238780100307557, -3.2380953527791014e-19),
+    dd(0.005273057279497939, 4.0473378732738415e-19),
+    dd(0.005273057279497939, 4.0473378732738415e-19),
+    dd(0.08152502928038578, 3.806503784548751e-18),
+    dd(0.08152502928038578, 3.806503784548751e-18),
+    dd(0.0809824937705971, -2.010332123930517e-18),
+    dd(0.0809824937705971, -2.010332123930517e-18),
+    dd(0.07990103324352782, 2.369560305642499e-18),
+    dd(0.07990103324352782, 2.369560305642499e-18),
+    dd(0.07828784465821095, -5.210484142422549e-18),
+    dd(0.07828784465821095, -5.210484142422549e-18),
+    dd(0.0761536635484464, 2.181250680209641e-19),
+    dd(0.0761536635484464, 2.181250680209641e-19),
+    dd(0.07351269258474345, 4.561177955720959e-18),
+    dd(0.07351269258474345, 4.561177955720959e-18),
+    dd(0.07038250706689896, -7.885286978398875e-19),
+    dd(0.07038250706689896, -7.885286978398875e-19),
+    dd(0.06678393797914041, 2.7437651711346518e-18),
+    dd(0.06678393797914041, 2.7437651711346518e-18),
+    dd(0.06274093339213306, -6.811134594349493e-18),
+    dd(0.06274093339213306, -6.811134594349493e-18),
+    dd(0.0582803991469972, 3.3249991068928145e-18),
+    dd(0.0582803991469972, 3.3249991068928145e-18),
+    dd(0.05343201991033232, -7.042022263056211e-19),
+    dd(0.05343201991033232, -7.042022263056211e-19),
+    dd(0.04822806186075868, 1.141066625181247e-18),
+    dd(0.04822806186075868, 1.141066625181247e-18),
+    dd(0.04270315850467443, 2.041586012731484e-18),
+    dd(0.04270315850467443, 2.041586012731484e-18),
+    dd(0.03689408159402474, -3.1346798559165206e-18),
+    dd(0.03689408159402474, -3.1346798559165206e-18),
+    dd(0.030839500545175053, 1.3943848541730394e-18),
+    dd(0.030839500545175053, 1.3943848541730394e-18),
+    dd(0.024579739738232374, 1.6042740652098886e-18),
+    dd(0.024579739738232374, 1.6042740652098886e-18),
+    dd(0.018156577709613236, 5.389337914799626e-19),
+    dd(0.018156577709613236, 5.389337914799626e-19),
+    dd(0.011613444716468675, -6.861390853431753e-19),
+    dd(0.011613444716468675, -6.861390853431753e-19),
+    dd(0.005002880749639346, -6.20673246864796e-20),
+    dd(0.005002880749639346, -6.20673246864796e-20),
+    dd(0.07952762213944285, 1.1703430615436594e-19),
+    dd(0.07927622256836847, 3.101048511135746e-19),
+    dd(0.07927622256836847, 3.101048511135746e-19),
+    dd(0.07852361328737117, 2.206693502451556e-18),
+    dd(0.07852361328737117, 2.206693502451556e-18),
+    dd(0.07727455254468202, -1.3341045432987923e-18),
+    dd(0.07727455254468202, -1.3341045432987923e-18),
+    dd(0.07553693732283606, -4.176263961404336e-18),
+    dd(0.07553693732283606, -4.176263961404336e-18),
+    dd(0.07332175341426862, -5.424873120819327e-18),
+    dd(0.07332175341426862, -5.424873120819327e-18),
+    dd(0.07064300597060875, 5.908009304402274e-18),
+    dd(0.07064300597060875, 5.908009304402274e-18),
+    dd(0.06751763096623127, -5.3319336298429735e-18),
+    dd(0.06751763096623127, -5.3319336298429735e-18),
+    dd(0.06396538813868238, 5.940987511110427e-18),
+    dd(0.06396538813868238, 5.940987511110427e-18),
+    dd(0.06000873608859615, -1.6952607098254858e-18),
+    dd(0.06000873608859615, -1.6952607098254858e-18),
+    dd(0.0556726903409163, 8.730116623279834e-19),
+    dd(0.0556726903409163, 8.730116623279834e-19),
+    dd(0.0509846652921294, 2.7555002187873836e-18),
+    dd(0.0509846652921294, 2.7555002187873836e-18),
+    dd(0.045974301108916635, -2.9107109174370965e-18),
+    dd(0.045974301108916635, -2.9107109174370965e-18),
+    dd(0.04067327684793384, 1.4796305101478828e-18),
+    dd(0.04067327684793384, 1.4796305101478828e-18),
+    dd(0.03511511149813133, -1.4961013627636898e-18),
+    dd(0.03511511149813133, -1.4961013627636898e-18),
+    dd(0.02933495598390338, 3.183865951849519e-19),
+    dd(0.02933495598390338, 3.183865951849519e-19),
+    dd(0.023369384832178163, 1.419769591198857e-18),
+    dd(0.023369384832178163, 1.419769591198857e-18),
+    dd(0.017256229093724918, 9.241686139484749e-19),
+    dd(0.017256229093724918, 9.241686139484749e-19),
+    dd(0.011034788939164594, 6.907208880467834e-19),
+    dd(0.011034788939164594, 6.907208880467834e-19),
+    dd(0.004752944691635101, 3.5110958158277107e-19),
+    dd(0.004752944691635101, 3.5110958158277107e-19),
+    dd(0.0775059479784248, 6.163307001890504e-18),
+    dd(0.0775059479784248, 6.163307001890504e-18),
+    dd(0.07703981816424797, -6.765789737079761e-18),
+    dd(0.07703981816424797, -6.765789737079761e-18),
+    dd(0.07611036190062624, 7.446698738593667e-19),
+    dd(0.07611036190062624, 7.446698738593667e-19),
+    dd(0.07472316905796826, 3.0582450256952924e-18),
+    dd(0.07472316905796826, 3.0582450256952924e-18),
+    dd(0.07288658239580406, -2.446638956977294e-18),
+    dd(0.07288658239580406, -2.446638956977294e-18),
+    dd(0.07061164739128678, -6.664683382430129e-19),
+    dd(0.07061164739128678, -6.664683382430129e-19),
+    dd(0.0679120458152339, 5.332066509407581e-18),
+    dd(0.0679120458152339, 5.332066509407581e-18),
+    dd(0.06480401345660104, -4.21218761298404e-18),
+    dd(0.06480401345660104, -4.21218761298404e-18),
+    dd(0.06130624249292894, 1.5402315893257328e-18),
+    dd(0.06130624249292894, 1.5402315893257328e-18),
+    dd(0.05743976909939155, -8.095970282184989e-19),
+    dd(0.05743976909939155, -8.095970282184989e-19),
+    dd(0.05322784698393682, 1.1774775198347453e-18),
+    dd(0.05322784698393682, 1.1774775198347453e-18),
+    dd(0.04869580763507223, 4.7195493019661935e-19),
+    dd(0.04869580763507223, 4.7195493019661935e-19),
+    dd(0.04387090818567327, 3.4383018360767714e-18),
+    dd(0.04387090818567327, 3.4383018360767714e-18),
+    dd(0.038782167974472016, 1.336971743328169e-18),
+    dd(0.038782167974472016, 1.336971743328169e-18),
+    dd(0.033460195282547844, 3.028404468340761e-18),
+    dd(0.033460195282547844, 3.028404468340761e-18),
+    dd(0.0279370069800234, 1.521338146577001e-18),
+    dd(0.0279370069800234, 1.521338146577001e-18),
+    dd(0.02224584919416696, -1.1231731329769518e-18),
+    dd(0.02224584919416696, -1.1231731329769518e-18),
+    dd(0.01642105838190789, -8.100469526806774e-19),
+    dd(0.01642105838190789, -8.100469526806774e-19),
+    dd(0.010498284531152813, 8.217887162215783e-19),
+    dd(0.010498284531152813, 8.217887162215783e-19),
+    dd(0.004521277098533191, 3.4220873024376216e-19),
+    dd(0.004521277098533191, 3.4220873024376216e-19),
+    dd(0.07569553564729838, -3.595402638823144e-18),
+    dd(0.07547874709271582, 4.0831716566185444e-18),
+    dd(0.07547874709271582, 4.0831716566185444e-18),
+    dd(0.07482962317622155, 2.761200168454044e-18),
+    dd(0.07482962317622155, 2.761200168454044e-18),
+    dd(0.07375188202722346, 5.6283309108395114e-18),
+    dd(0.07375188202722346, 5.6283309108395114e-18),
+    dd(0.07225169686102308, -3.0272671331116453e-18),
+    dd(0.07225169686102308, -3.0272671331116453e-18),
+    dd(0.07033766062081749, 6.550947177189963e-18),
+    dd(0.07033766062081749, 6.550947177189963e-18),
+    dd(0.06802073676087676, 4.0523298726937014e-18),
+    dd(0.06802073676087676, 4.0523298726937014e-18),
+    dd(0.06531419645352741, -4.551064215468797e-18),
+    dd(0.06531419645352741, -4.551064215468797e-18),
+    dd(0.06223354258096632, -3.0524428110318686e-18),
+    dd(0.06223354258096632, -3.0524428110318686e-18),
+    dd(0.05879642094987195, -2.012542824901907e-18),
+    dd(0.05879642094987195, -2.012542824901907e-18),
+    dd(0.05502251924257874, 1.1632599120725305e-18),
+    dd(0.05502251924257874, 1.1632599120725305e-18),
+    dd(0.05093345429461749, 1.6512328993636675e-18),
+    dd(0.05093345429461749, 1.6512328993636675e-18),
+    dd(0.04655264836901434, 1.1604053576195187e-19),
+    dd(0.04655264836901434, 1.1604053576195187e-19),
+    dd(0.04190519519590969, -1.0682640419949752e-18),
+    dd(0.04190519519590969, -1.0682640419949752e-18),
+    dd(0.03701771670350799, 1.3381175682941115e-18),
+    dd(0.03701771670350799, 1.3381175682941115e-18),
+    dd(0.03191821173169928, 2.343936212322284e-18),
+    dd(0.03191821173169928, 2.343936212322284e-18),
+    dd(0.026635899207110444, 1.0143204489095819e-18),
+    dd(0.026635899207110444, 1.0143204489095819e-18),
+    dd(0.021201063368779555, -1.5874379117648304e-18),
+    dd(0.021201063368779555, -1.5874379117648304e-18),
+    dd(0.01564493840781859, 9.201841638868858e-21),
+    dd(0.01564493840781859, 9.201841638868858e-21),
+    dd(0.009999938773905946, -8.159003767346186e-19),
+    dd(0.009999938773905946, -8.159003767346186e-19),
+    dd(0.0043061403581648875, 2.1095750008750626e-19),
+    dd(0.0043061403581648875, 2.1095750008750626e-19),
+    dd(0.07386423423217288, 1.0163694103869695e-18),
+    dd(0.07386423423217288, 1.0163694103869695e-18),
+    dd(0.07346081345346753, 1.0408886022747883e-18),
+    dd(0.07346081345346753, 1.0408886022747883e-18),
+    dd(0.0726561752438041, -4.1358700688365953e-19),
+    dd(0.0726561752438041, -4.1358700688365953e-19),
+    dd(0.07145471426517098, -1.9774675952753493e-18),
+    dd(0.07145471426517098, -1.9774675952753493e-18),
+    dd(0.06986299249259416, 1.0995136510124912e-18),
+    dd(0.06986299249259416, 1.0995136510124912e-18),
+    dd(0.06788970337652195, -3.341745311216004e-18),
+    dd(0.06788970337652195, -3.341745311216004e-18),
+    dd(0.06554562436490897, 4.114333847172741e-18),
+    dd(0.06554562436490897, 4.114333847172741e-18),
+    dd(0.06284355804500258, -2.3953092952365888e-18),
+    dd(0.06284355804500258, -2.3953092952365888e-18),
+    dd(0.059798262227586656, -1.4020522441158835e-18),
+    dd(0.059798262227586656, -1.4020522441158835e-18),
+    dd(0.05642636935801838, -1.1804646129451e-18),
+    dd(0.05642636935801838, -1.1804646129451e-18),
+    dd(0.05274629569917407, -1.0590611550144003e-18),
+    dd(0.05274629569917407, -1.0590611550144003e-18),
+    dd(0.048778140792803244, 7.529934426245059e-19),
+    dd(0.048778140792803244, 7.529934426245059e-19),
+    dd(0.04454357777196588, -3.099364984966431e-18),
+    dd(0.04454357777196588, -3.099364984966431e-18),
+    dd(0.040065735180692265, -3.10011731146188e-18),
+    dd(0.040065735180692265, -3.10011731146188e-18),
+    dd(0.03536907109759211, 9.01388640402657e-19),
+    dd(0.03536907109759211, 9.01388640402657e-19),
+    dd(0.030479240699603467, 1.7329081159249841e-18),
+    dd(0.030479240699603467, 1.7329081159249841e-18),
+    dd(0.025422959526113047, 4.796503603014708e-19),
+    dd(0.025422959526113047, 4.796503603014708e-19),
+    dd(0.020227869569052644, 4.574330627029403e-19),
+    dd(0.020227869569052644, 4.574330627029403e-19),
+    dd(0.014922443697357493, 7.001557638782585e-19),
+    dd(0.014922443697357493, 7.001557638782585e-19),
+    dd(0.009536220301748503, -2.749804345960911e-19),
+    dd(0.009536220301748503, -2.749804345960911e-19),
+    dd(0.004105998604649085, -1.2301711088924448e-19),
+    dd(0.004105998604649085, -1.2301711088924448e-19),
+    dd(0.07221575169379899, 2.7994989862639115e-18),
+    dd(0.07202750197142198, -3.954273660797326e-18),
+    dd(0.07202750197142198, -3.954273660797326e-18),
+    dd(0.07146373425251414, 5.716678485475248e-18),
+    dd(0.07146373425251414, 5.716678485475248e-18),
+    dd(0.07052738776508503, -5.806412976648563e-18),
+    dd(0.07052738776508503, -5.806412976648563e-18),
+    dd(0.06922334419365668, 4.1589384655657754e-18),
+    dd(0.06922334419365668, 4.1589384655657754e-18),
+    dd(0.06755840222936517, -6.348454899168141e-20),
+    dd(0.06755840222936517, -6.348454899168141e-20),
+    dd(0.0655412421263228, -1.5085819243539133e-18),
+    dd(0.0655412421263228, -1.5085819243539133e-18),
+    dd(0.06318238044939611, -1.538339231133757e-18),
+    dd(0.06318238044939611, -1.538339231133757e-18),
+    dd(0.06049411524999129, 1.881990807916288e-18),
+    dd(0.06049411524999129, 1.881990807916288e-18),
+    dd(0.05749046195691052, -3.261892247150957e-18),
+    dd(0.05749046195691052, -3.261892247150957e-18),
+    dd(0.05418708031888179, -7.472233115862302e-19),
+    dd(0.05418708031888179, -7.472233115862302e-19),
+    dd(0.05060119278439015, 3.300721848900081e-18),
+    dd(0.05060119278439015, 3.300721848900081e-18),
+    dd(0.04675149475434658, -2.7943912021184052e-18),
+    dd(0.04675149475434658, -2.7943912021184052e-18),
+    dd(0.04265805719798208, 2.4205056750422837e-18),
+    dd(0.04265805719798208, 2.4205056750422837e-18),
+    dd(0.03834222219413266, 9.84633190982975e-19),
+    dd(0.03834222219413266, 9.84633190982975e-19),
+    dd(0.03382649208686029, 2.879502053155619e-18),
+    dd(0.03382649208686029, 2.879502053155619e-18),
+    dd(0.029134413261498494, 5.529405466768691e-19),
+    dd(0.029134413261498494, 5.529405466768691e-19),
+    dd(0.024290456613838815, 8.544392348308696e-19),
+    dd(0.024290456613838815, 8.544392348308696e-19),
+    dd(0.0193199014236839, -5.023179338658556e-19),
+    dd(0.0193199014236839, -5.023179338658556e-19),
+    dd(0.014248756431576486, 1.800275486077909e-19),
+    dd(0.014248756431576486, 1.800275486077909e-19),
+    dd(0.009103996637401404, -5.247349451159706e-19),
+    dd(0.009103996637401404, -5.247349451159706e-19),
+    dd(0.0039194902538441274, -1.5902841656948112e-19),
+    dd(0.0039194902538441274, -1.5902841656948112e-19),
+    dd(0.07054915778935407, -6.363798443885964e-19),
+    dd(0.07054915778935407, -6.363798443885964e-19),
+    dd(0.07019768547355822, -3.60879081537313e-18),
+    dd(0.07019768547355822, -3.60879081537313e-18),
+    dd(0.06949649186157258, -6.588066648232095e-18),
+    dd(0.06949649186157258, -6.588066648232095e-18),
+    dd(0.06844907026936665, 6.256965245299395e-18),
+    dd(0.06844907026936665, 6.256965245299395e-18),
+    dd(0.06706063890629366, -4.584335021766146e-18),
+    dd(0.06706063890629366, -4.584335021766146e-18),
+    dd(0.06533811487918144, -3.692462058370969e-18),
+    dd(0.06533811487918144, -3.692462058370969e-18),
+    dd(0.06329007973320386, -2.875646250329612e-18),
+    dd(0.06329007973320386, -2.875646250329612e-18),
+    dd(0.06092673670156197, -2.372143171075309e-18),
+    dd(0.06092673670156197, -2.372143171075309e-18),
+    dd(0.05825985987759549, 1.8849901914389386e-18),
+    dd(0.05825985987759549, 1.8849901914389386e-18),
+    dd(0.055302735563728056, -3.440938484667575e-18),
+    dd(0.055302735563728056, -3.440938484667575e-18),
+    dd(0.05207009609170446, 2.141430011663971e-18),
+    dd(0.05207009609170446, 2.141430011663971e-18),
+    dd(0.048578046448352036, 1.74773431411018e-18),
+    dd(0.048578046448352036, 1.74773431411018e-18),
+    dd(0.04484398408197003, 6.059878234915933e-20),
+    dd(0.04484398408197003, 6.059878234915933e-20),
+    dd(0.04088651231034622, -1.8914032448129308e-18),
+    dd(0.04088651231034622, -1.8914032448129308e-18),
+    dd(0.03672534781380887, 7.218939563159778e-19),
+    dd(0.03672534781380887, 7.218939563159778e-19),
+    dd(0.03238122281206982, -1.4002311069041243e-18),
+    dd(0.03238122281206982, -1.4002311069041243e-18),
+    dd(0.02787578282128101, -1.2309200474169184e-19),
+    dd(0.02787578282128101, -1.2309200474169184e-19),
+    dd(0.02323148190201921, -4.376096423384933e-19),
+    dd(0.02323148190201921, -4.376096423384933e-19),
+    dd(0.01847148173681475, -3.304310525224461e-19),
+    dd(0.01847148173681475, -3.304310525224461e-19),
+    dd(0.013619586755579985, 2.907799113212227e-19),
+    dd(0.013619586755579985, 2.907799113212227e-19),
+    dd(0.008700481367524843, 7.379346397462057e-19),
+    dd(0.008700481367524843, 7.379346397462057e-19),
+    dd(0.0037454048031127776, -7.539420447840426e-20),
+    dd(0.0037454048031127776, -7.539420447840426e-20),
+    dd(0.06904182482923202, 2.087081622683896e-18),
+    dd(0.06887731697766132, 1.9972179914541482e-18),
+    dd(0.06887731697766132, 1.9972179914541482e-18),
+    dd(0.06838457737866968, -6.359884277445678e-18),
+    dd(0.06838457737866968, -6.359884277445678e-18),
+    dd(0.06756595416360754, -5.4228043848811686e-18),
+    dd(0.06756595416360754, -5.4228043848811686e-18),
+    dd(0.06642534844984253, -5.310812990331274e-18),
+    dd(0.06642534844984253, -5.310812990331274e-18),
+    dd(0.06496819575072343, -3.3692351881710177e-18),
+    dd(0.06496819575072343, -3.3692351881710177e-18),
+    dd(0.06320144007381993, 3.8541369799321295e-18),
+    dd(0.06320144007381993, 3.8541369799321295e-18),
+    dd(0.06113350083106652, 1.0048827075925e-18),
+    dd(0.06113350083106652, 1.0048827075925e-18),
+    dd(0.05877423271884174, -2.018549702176927e-19),
+    dd(0.05877423271884174, -2.018549702176927e-19),
+    dd(0.056134878759786476, 6.892721771432743e-19),
+    dd(0.056134878759786476, 6.892721771432743e-19),
+    dd(0.05322801673126895, 7.787376517949935e-19),
+    dd(0.05322801673126895, 7.787376517949935e-19),
+    dd(0.05006749923795203, 2.2415244098321693e-18),
+    dd(0.05006749923795203, 2.2415244098321693e-18),
+    dd(0.046668387718373366, -1.1451365809354644e-18),
+    dd(0.046668387718373366, -1.1451365809354644e-18),
+    dd(0.04304688070916497, -1.2933110932326351e-18),
+    dd(0.04304688070916497, -1.2933110932326351e-18),
+    dd(0.03922023672930245, -2.79831266911982e-18),
+    dd(0.03922023672930245, -2.79831266911982e-18),
+    dd(0.03520669220160902, -2.663002022197933e-18),
+    dd(0.03520669220160902, -2.663002022197933e-18),
+    dd(0.031025374934515466, 7.550715041012242e-19),
+    dd(0.031025374934515466, 7.550715041012242e-19),
+    dd(0.026696213967577664, 1.094124705448738e-18),
+    dd(0.026696213967577664, 1.094124705448738e-18),
+    dd(0.022239847550578732, 3.944908114192779e-19),
+    dd(0.022239847550578732, 3.944908114192779e-19),
+    dd(0.01767753525793759, 1.4977666970181453e-18),
+    dd(0.01767753525793759, 1.4977666970181453e-18),
+    dd(0.013031104991582785, -2.623507973809625e-19),
+    dd(0.013031104991582785, -2.623507973809625e-19),
+    dd(0.00832318929621824, 8.027630844328555e-19),
+    dd(0.00832318929621824, 8.027630844328555e-19),
+    dd(0.003582663155283559, 9.64302415703906e-20),
+    dd(0.003582663155283559, 9.64302415703906e-20),
+    dd(0.06751868584903646, -2.268309519276033e-18),
+    dd(0.06751868584903646, -2.268309519276033e-18),
+    dd(0.06721061360067818, -3.869292645268667e-19),
+    dd(0.06721061360067818, -3.869292645268667e-19),
+    dd(0.06659587476845488, 4.9671427110931936e-18),
+    dd(0.06659587476845488, 4.9671427110931936e-18),
+    dd(0.06567727426778121, -3.1625855562069713e-19),
+    dd(0.06567727426778121, -3.1625855562069713e-19),
+    dd(0.06445900346713906, 5.218817263289629e-18),
+    dd(0.06445900346713906, 5.218817263289629e-18),
+    dd(0.06294662106439451, -3.589695125795594e-18),
+    dd(0.06294662106439451, -3.589695125795594e-18),
+    dd(0.06114702772465048, 2.873257788414644e-18),
+    dd(0.06114702772465048, 2.873257788414644e-18),
+    dd(0.05906843459554632, -1.8379916391748415e-18),
+    dd(0.05906843459554632, -1.8379916391748415e-18),
+    dd(0.05672032584399123, 2.755582424746898e-18),
+    dd(0.05672032584399123, 2.755582424746898e-18),
+    dd(0.054113415385856754, 1.7780626946651387e-19),
+    dd(0.054113415385856754, 1.7780626946651387e-19),
+    dd(0.05125959800714302, 2.5523713509369247e-18),
+    dd(0.05125959800714302, 2.5523713509369247e-18),
+    dd(0.0481718951017122, 7.060413098501882e-20),
+    dd(0.0481718951017122, 7.060413098501882e-20),
+    dd(0.04486439527731813, -1.3852716142604475e-18),
+    dd(0.04486439527731813, -1.3852716142604475e-18),
+    dd(0.04135219010967873, 1.3239710072755582e-18),
+    dd(0.04135219010967873, 1.3239710072755582e-18),
+    dd(0.03765130535738607, 3.176556502297961e-18),
+    dd(0.03765130535738607, 3.176556502297961e-18),
+    dd(0.0337786279991069, -3.4278188281097918e-18),
+    dd(0.0337786279991069, -3.4278188281097918e-18),
+    dd(0.029751829552202756, -4.1372364046838145e-19),
+    dd(0.029751829552202756, -4.1372364046838145e-19),
+    dd(0.025589286397130012, -1.5503310109415603e-18),
+    dd(0.025589286397130012, -1.5503310109415603e-18),
+    dd(0.0213099987541365, 1.144518016869586e-18),
+    dd(0.0213099987541365, 1.144518016869586e-18),
+    dd(0.01693351400783624, -7.609612616256677e-19),
+    dd(0.01693351400783624, -7.609612616256677e-19),
+    dd(0.012479883770988685, -7.894159033639898e-19),
+    dd(0.012479883770988685, -7.894159033639898e-19),
+    dd(0.007969898229724623, -1.1927784063186698e-19),
+    dd(0.007969898229724623, -1.1927784063186698e-19),
+    dd(0.0034303008681070483, -4.2068488678759457e-20),
+    dd(0.0034303008681070483, -4.2068488678759457e-20),
+    dd(0.06613512962365548, 4.336261556408376e-18),
+    dd(0.06599053358881048, -2.0615505462956725e-18),
+    dd(0.06599053358881048, -2.0615505462956725e-18),
+    dd(0.06555737776654974, -1.0991427231411264e-19),
+    dd(0.06555737776654974, -1.0991427231411264e-19),
+    dd(0.06483755623894573, -2.134426973816924e-18),
+    dd(0.06483755623894573, -2.134426973816924e-18),
+    dd(0.06383421660571703, -6.363367658431076e-19),
+    dd(0.06383421660571703, -6.363367658431076e-19),
+    dd(0.06255174622092166, 1.3838113267914786e-18),
+    dd(0.06255174622092166, 1.3838113267914786e-18),
+    dd(0.06099575300873965, -2.340743942016854e-18),
+    dd(0.06099575300873965, -2.340743942016854e-18),
+    dd(0.05917304094233888, -7.592189754153464e-19),
+    dd(0.05917304094233888, -7.592189754153464e-19),
+    dd(0.05709158029323154, 1.0659985324636378e-18),
+    dd(0.05709158029323154, 1.0659985324636378e-18),
+    dd(0.05476047278153023, -1.0028865544190267e-18),
+    dd(0.05476047278153023, -1.0028865544190267e-18),
+    dd(0.05218991178005714, 2.440279692826945e-18),
+    dd(0.05218991178005714, 2.440279692826945e-18),
+    dd(0.04939113774736117, 1.9731491333090708e-18),
+    dd(0.04939113774736117, 1.9731491333090708e-18),
+    dd(0.04637638908650591, 6.293706397335005e-19),
+    dd(0.04637638908650591, 6.293706397335005e-19),
+    dd(0.04315884864847954, -2.2868100173713313e-18),
+    dd(0.04315884864847954, -2.2868100173713313e-18),
+    dd(0.039752586122531006, -1.8714682487912684e-18),
+    dd(0.039752586122531006, -1.8714682487912684e-18),
+    dd(0.03617249658417495, 1.7171978662906508e-18),
+    dd(0.03617249658417495, 1.7171978662906508e-18),
+    dd(0.032434235515184755, 2.0397376286836476e-18),
+    dd(0.032434235515184755, 2.0397376286836476e-18),
+    dd(0.028554150700643387, -9.179881010005728e-19),
+    dd(0.028554150700643387, -9.179881010005728e-19),
+    dd(0.02454921165965882, 4.71417458786933e-20),
+    dd(0.02454921165965882, 4.71417458786933e-20),
+    dd(0.020436938147668428, -1.7001893237382385e-19),
+    dd(0.020436938147668428, -1.7001893237382385e-19),
+    dd(0.01623533314643306, -1.5481016887296981e-18),
+    dd(0.01623533314643306, -1.5481016887296981e-18),
+    dd(0.011962848464312321, -1.5057868699060414e-19),
+    dd(0.011962848464312321, -1.5057868699060414e-19),
+    dd(0.007638616295848833, 3.529981312926888e-19),
+    dd(0.007638616295848833, 3.529981312926888e-19),
+    dd(0.003287453842528015, 3.823884932832199e-21),
+    dd(0.003287453842528015, 3.823884932832199e-21),
+    dd(0.06473769681268392, 4.989749094578831e-18),
+    dd(0.06473769681268392, 4.989749094578831e-18),
+    dd(0.06446616443595009, -5.734370004353738e-18),
+    dd(0.06446616443595009, -5.734370004353738e-18),
+    dd(0.06392423858464819, 1.3110197759349048e-18),
+    dd(0.06392423858464819, 1.3110197759349048e-18),
+    dd(0.06311419228625402, 5.653692882177054e-18),
+    dd(0.06311419228625402, 5.653692882177054e-18),
+    dd(0.062039423159892665, -9.67663927479786e-19),
+    dd(0.062039423159892665, -9.67663927479786e-19),
+    dd(0.06070443916589388, -8.390306244028093e-19),
+    dd(0.06070443916589388, -8.390306244028093e-19),
+    dd(0.059114839698395635, 3.9860307139872193e-19),
+    dd(0.059114839698395635, 3.9860307139872193e-19),
+    dd(0.057277292100403214, 1.7016046635533744e-18),
+    dd(0.057277292100403214, 1.7016046635533744e-18),
+    dd(0.055199503699984165, -1.9713169222532382e-18),
+    dd(0.055199503699984165, -1.9713169222532382e-18),
+    dd(0.05289018948519367, -4.494514590402326e-20),
+    dd(0.05289018948519367, -4.494514590402326e-20),
+    dd(0.05035903555385447, 2.3467503721654377e-18),
+    dd(0.05035903555385447, 2.3467503721654377e-18),
+    dd(0.04761665849249048, -3.3347014682055506e-18),
+    dd(0.04761665849249048, -3.3347014682055506e-18),
+    dd(0.04467456085669428, 3.550990312115456e-19),
+    dd(0.04467456085669428, 3.550990312115456e-19),
+    dd(0.04154508294346475, 1.3763003561853365e-18),
+    dd(0.04154508294346475, 1.3763003561853365e-18),
+    dd(0.03824135106583071, -2.438082581706997e-18),
+    dd(0.03824135106583071, -2.438082581706997e-18),
+    dd(0.03477722256477044, -3.3221280630093793e-18),
+    dd(0.03477722256477044, -3.3221280630093793e-18),
+    dd(0.03116722783279809, -7.407859786015937e-19),
+    dd(0.03116722783279809, -7.407859786015937e-19),
+    dd(0.027426509708356948, 4.912999487781932e-19),
+    dd(0.027426509708356948, 4.912999487781932e-19),
+    dd(0.02357076083932438, -1.318461866029279e-18),
+    dd(0.02357076083932438, -1.318461866029279e-18),
+    dd(0.01961616045735553, -1.1754149174281186e-18),
+    dd(0.01961616045735553, -1.1754149174281186e-18),
+    dd(0.015579315722943849, 1.5487603835954287e-20),
+    dd(0.015579315722943849, 1.5487603835954287e-20),
+    dd(0.01147723457923454, -4.03895941543336e-19),
+    dd(0.01147723457923454, -4.03895941543336e-19),
+    dd(0.0073275539012762625, -3.9114090268036732e-19),
+    dd(0.0073275539012762625, -3.9114090268036732e-19),
+    dd(0.0031533460523058385, 1.3920372283104607e-19),
+    dd(0.0031533460523058385, 1.3920372283104607e-19),
+    dd(0.0634632814047906, -2.485926427747492e-18),
+    dd(0.06333550929649175, 2.601160169200597e-18),
+    dd(0.06333550929649175, 2.601160169200597e-18),
+    dd(0.0629527074651957, 1.4299528408801579e-18),
+    dd(0.0629527074651957, 1.4299528408801579e-18),
+    dd(0.062316417320057264, 3.0392445863966887e-18),
+    dd(0.062316417320057264, 3.0392445863966887e-18),
+    dd(0.06142920097919294, -2.0176282176171177e-18),
+    dd(0.06142920097919294, -2.0176282176171177e-18),
+    dd(0.06029463095315202, -1.798149931831274e-19),
+    dd(0.06029463095315202, -1.798149931831274e-19),
+    dd(0.05891727576002727, -2.380788996461525e-18),
+    dd(0.05891727576002727, -2.380788996461525e-18),
+    dd(0.05730268153018748, -2.912943832575433e-18),
+    dd(0.05730268153018748, -2.912943832575433e-18),
+    dd(0.05545734967480359, -2.3204538026111556e-18),
+    dd(0.05545734967480359, -2.3204538026111556e-18),
+    dd(0.05338871070825897, -2.721286385070468e-18),
+    dd(0.05338871070825897, -2.721286385070468e-18),
+    dd(0.05110509433014459, 1.7091306587682075e-18),
+    dd(0.05110509433014459, 1.7091306587682075e-18),
+    dd(0.04861569588782824, -1.6390992740307959e-18),
+    dd(0.04861569588782824, -1.6390992740307959e-18),
+    dd(0.04593053935559585, 2.7724131342905962e-18),
+    dd(0.04593053935559585, 2.7724131342905962e-18),
+    dd(0.043060436981259595, 3.030973567266338e-18),
+    dd(0.043060436981259595, 3.030973567266338e-18),
+    dd(0.04001694576637302, 2.5573256427850007e-18),
+    dd(0.04001694576637302, 2.5573256427850007e-18),
+    dd(0.03681232096300069, 1.1411323548562802e-18),
+    dd(0.03681232096300069, 1.1411323548562802e-18),
+    dd(0.03345946679162218, -3.3967977426134114e-18),
+    dd(0.03345946679162218, -3.3967977426134114e-18),
+    dd(0.029971884620583825, 7.185343356957487e-19),
+    dd(0.029971884620583825, 7.185343356957487e-19),
+    dd(0.026363618927066016, 8.71012858027798e-19),
+    dd(0.026363618927066016, 8.71012858027798e-19),
+    dd(0.022649201587446675, 1.1110217688643263e-18),
+    dd(0.022649201587446675, 1.1110217688643263e-18),
+    dd(0.018843595853089458, 5.522795986818558e-19),
+    dd(0.018843595853089458, 5.522795986818558e-19),
+    dd(0.01496214493562465, 2.1958219603993473e-19),
+    dd(0.01496214493562465, 2.1958219603993473e-19),
+    dd(0.01102055103159358, 3.567533149379107e-19),
+    dd(0.01102055103159358, 3.567533149379107e-19),
+    dd(0.0070350995900864514, 3.026795373694952e-20),
+    dd(0.0070350995900864514, 3.026795373694952e-20),
+    dd(0.003027278988922905, 1.4043842956200447e-19),
+    dd(0.003027278988922905, 1.4043842956200447e-19),
+    dd(0.06217661665534726, 2.6997993609311883e-18),
+    dd(0.06217661665534726, 2.6997993609311883e-18),
+    dd(0.06193606742068324, 7.715650753248624e-19),
+    dd(0.06193606742068324, 7.715650753248624e-19),
+    dd(0.061455899590316665, -1.1813101645057592e-18),
+    dd(0.061455899590316665, -1.1813101645057592e-18),
+    dd(0.06073797084177022, -2.234814269774082e-18),
+    dd(0.06073797084177022, -2.234814269774082e-18),
+    dd(0.05978505870426546, -2.9734418965167756e-18),
+    dd(0.05978505870426546, -2.9734418965167756e-18),
+    dd(0.058600849813222444, 1.5950540382730137e-18),
+    dd(0.058600849813222444, 1.5950540382730137e-18),
+    dd(0.05718992564772838, 3.2807375638148883e-18),
+    dd(0.05718992564772838, 3.2807375638148883e-18),
+    dd(0.05555774480621252, -1.9158115766412848e-18),
+    dd(0.05555774480621252, -1.9158115766412848e-18),
+    dd(0.053710621888996245, 1.790753021296262e-18),
+    dd(0.053710621888996245, 1.790753021296262e-18),
+    dd(0.05165570306958114, 1.7683277879980104e-18),
+    dd(0.05165570306958114, 1.7683277879980104e-18),
+    dd(0.04940093844946632, -1.816291429154991e-18),
+    dd(0.04940093844946632, -1.816291429154991e-18),
+    dd(0.046955051303948434, -5.508555783416799e-19),
+    dd(0.046955051303948434, -5.508555783416799e-19),
+    dd(0.044327504338803274, 1.6497379175643406e-18),
+    dd(0.044327504338803274, 1.6497379175643406e-18),
+    dd(0.041528463090147696, 9.875502218663885e-19),
+    dd(0.041528463090147696, 9.875502218663885e-19),
+    dd(0.03856875661258768, -2.4342569591977875e-18),
+    dd(0.03856875661258768, -2.4342569591977875e-18),
+    dd(0.03545983561514615, 2.816599860385054e-18),
+    dd(0.03545983561514615, 2.816599860385054e-18),
+    dd(0.032213728223578014, 2.4056317318215348e-18),
+    dd(0.032213728223578014, 2.4056317318215348e-18),
+    dd(0.028842993580535197, 9.286952197322594e-19),
+    dd(0.028842993580535197, 9.286952197322594e-19),
+    dd(0.02536067357001239, -1.257475161617334e-18),
+    dd(0.02536067357001239, -1.257475161617334e-18),
+    dd(0.021780243170124794, -9.5725453888087e-19),
+    dd(0.021780243170124794, -9.5725453888087e-19),
+    dd(0.018115560713489392, -1.5184527756629734e-18),
+    dd(0.018115560713489392, -1.5184527756629734e-18),
+    dd(0.014380822761485574, 4.675892906640049e-19),
+    dd(0.014380822761485574, 4.675892906640049e-19),
+    dd(0.010590548383650969, 6.55042693819389e-19),
+    dd(0.010590548383650969, 6.55042693819389e-19),
+    dd(0.006759799195745401, 3.4865601150647084e-19),
+    dd(0.006759799195745401, 3.4865601150647084e-19),
+    dd(0.002908622553155141, -1.2160501677370984e-19),
+    dd(0.002908622553155141, -1.2160501677370984e-19),
+    dd(0.06099892484120588, 1.3935161423958942e-18),
+    dd(0.060885464844856345, -9.758616787294593e-19),
+    dd(0.060885464844856345, -9.758616787294593e-19),
+    dd(0.06054550693473779, 2.0903625297690085e-18),
+    dd(0.06054550693473779, 2.0903625297690085e-18),
+    dd(0.059980315777503256, -3.457589549863725e-18),
+    dd(0.059980315777503256, -3.457589549863725e-18),
+    dd(0.059191993922961546, -1.987438657166181e-18),
+    dd(0.059191993922961546, -1.987438657166181e-18),
+    dd(0.05818347398259214, 1.5585112845480708e-18),
+    dd(0.05818347398259214, 1.5585112845480708e-18),
+    dd(0.056958507720258664, -1.608989821363387e-18),
+    dd(0.056958507720258664, -1.608989821363387e-18),
+    dd(0.05552165209573869, 5.383487715391437e-19),
+    dd(0.05552165209573869, 5.383487715391437e-19),
+    dd(0.05387825231304556, -1.736924087107343e-18),
+    dd(0.05387825231304556, -1.736924087107343e-18),
+    dd(0.052034421936697085, 2.2439138919308232e-18),
+    dd(0.052034421936697085, 2.2439138919308232e-18),
+    dd(0.04999702015005741, 2.6510522771095535e-18),
+    dd(0.04999702015005741, 2.6510522771095535e-18),
+    dd(0.047773626240623104, -2.366460821577485e-18),
+    dd(0.047773626240623104, -2.366460821577485e-18),
+    dd(0.04537251140765007, -1.98790825028125e-18),
+    dd(0.04537251140765007, -1.98790825028125e-18),
+    dd(0.042802607997880084, 3.0745863781909205e-18),
+    dd(0.042802607997880084, 3.0745863781909205e-18),
+    dd(0.04007347628549646, -3.3857788151561823e-18),
+    dd(0.04007347628549646, -3.3857788151561823e-18),
+    dd(0.03719526892326029, 1.626748674876373e-18),
+    dd(0.03719526892326029, 1.626748674876373e-18),
+    dd(0.034178693204188336, 2.8388889528307587e-19),
+    dd(0.034178693204188336, 2.8388889528307587e-19),
+    dd(0.03103497129016001, -1.5125974603808489e-19),
+    dd(0.03103497129016001, -1.5125974603808489e-19),
+    dd(0.027775798594162477, 1.8390287221637722e-19),
+    dd(0.027775798594162477, 1.8390287221637722e-19),
+    dd(0.024413300573781434, -1.7620813776937345e-19),
+    dd(0.024413300573781434, -1.7620813776937345e-19),
+    dd(0.02095998840170321, -6.228941978486927e-19),
+    dd(0.02095998840170321, -6.228941978486927e-19),
+    dd(0.017428714723401052, -7.223260758403792e-21),
+    dd(0.017428714723401052, -7.223260758403792e-21),
+    dd(0.013832634006477822, 6.434857996704565e-19),
+    dd(0.013832634006477822, 6.434857996704565e-19),
+    dd(0.01018519129782173, -5.685623281759016e-19),
+    dd(0.01018519129782173, -5.685623281759016e-19),
+    dd(0.0065003377832526, 2.3196285527608264e-19),
+    dd(0.0065003377832526, 2.3196285527608264e-19),
+    dd(0.0027968071710898954, 1.7381096726822403e-19),
+    dd(0.0027968071710898954, 1.7381096726822403e-19),
+    dd(0.05981036574529186, 1.8924864485689215e-20),
+    dd(0.05981036574529186, 1.8924864485689215e-20),
+    dd(0.05959626017124816, -1.3511475048081423e-18),
+    dd(0.05959626017124816, -1.3511475048081423e-18),
+    dd(0.05916881546604297, 2.659251200259818e-18),
+    dd(0.05916881546604297, 2.659251200259818e-18),
+    dd(0.05852956177181387, -1.990690784811595e-18),
+    dd(0.05852956177181387, -1.990690784811595e-18),
+    dd(0.057680787452526826, 1.889100611340564e-18),
+    dd(0.057680787452526826, 1.889100611340564e-18),
+    dd(0.0566255309023686, 3.713121355789983e-19),
+    dd(0.0566255309023686, 3.713121355789983e-19),
+    dd(0.05536756966930265, -8.410672107143325e-19),
+    dd(0.05536756966930265, -8.410672107143325e-19),
+    dd(0.05391140693275726, 2.4394636995085833e-18),
+    dd(0.05391140693275726, 2.4394636995085833e-18),
+    dd(0.05226225538390699, 2.9871894007940003e-18),
+    dd(0.05226225538390699, 2.9871894007940003e-18),
+    dd(0.05042601856634238, -1.5611989286047117e-18),
+    dd(0.05042601856634238, -1.5611989286047117e-18),
+    dd(0.0484092697440749, 1.0761605498782909e-19),
+    dd(0.0484092697440749, 1.0761605498782909e-19),
+    dd(0.04621922837278479, 3.2701638053137635e-18),
+    dd(0.04621922837278479, 3.2701638053137635e-18),
+    dd(0.043863734259000406, 2.0933780031379626e-18),
+    dd(0.043863734259000406, 2.0933780031379626e-18),
+    dd(0.04135121950056027, 3.2740086366606957e-18),
+    dd(0.04135121950056027, 3.2740086366606957e-18),
+    dd(0.03869067831042398, 1.6143656357828032e-18),
+    dd(0.03869067831042398, 1.6143656357828032e-18),
+    dd(0.03589163483509723, -1.2746957723327777e-19),
+    dd(0.03589163483509723, -1.2746957723327777e-19),
+    dd(0.0329641090897188, -2.3012187553031126e-18),
+    dd(0.0329641090897188, -2.3012187553031126e-18),
+    dd(0.029918581147143946, 6.60511217106292e-19),
+    dd(0.029918581147143946, 6.60511217106292e-19),
+    dd(0.026765953746504013, 1.6521177910661502e-20),
+    dd(0.026765953746504013, 1.6521177910661502e-20),
+    dd(0.023517513553984463, -1.1860136529411335e-18),
+    dd(0.023517513553984463, -1.1860136529411335e-18),
+    dd(0.020184891507980793, -5.878216193047214e-19),
+    dd(0.020184891507980793, -5.878216193047214e-19),
+    dd(0.016780023396300737, -1.1541548021851547e-18),
+    dd(0.016780023396300737, -1.1541548021851547e-18),
+    dd(0.013315114982340961, -8.125979038458651e-19),
+    dd(0.013315114982340961, -8.125979038458651e-19),
+    dd(0.009802634579462751, 6.684096372856456e-19),
+    dd(0.009802634579462751, 6.684096372856456e-19),
+    dd(0.006255523962973277, -4.204492528945041e-19),
+    dd(0.006255523962973277, -4.204492528945041e-19),
+    dd(0.0026913169500471113, -1.9540696963431623e-19),
+    dd(0.0026913169500471113, -1.9540696963431623e-19),
+    dd(0.058718794151164364, 1.0104150466055283e-18),
+    dd(0.058617586232720266, -2.6526584296391786e-18),
+    dd(0.058617586232720266, -2.6526584296391786e-18),
+    dd(0.05831431136225601, -2.972861272814783e-18),
+    dd(0.05831431136225601, -2.972861272814783e-18),
+    dd(0.0578100149917132, -1.1988915978525906e-18),
+    dd(0.0578100149917132, -1.1988915978525906e-18),
+    dd(0.057106435536267194, -2.601609699920261e-18),
+    dd(0.057106435536267194, -2.601609699920261e-18),
+    dd(0.05620599838173971, 4.51147611387084e-19),
+    dd(0.05620599838173971, 4.51147611387084e-19),
+    dd(0.0551118075239336, 2.3381601111267736e-18),
+    dd(0.0551118075239336, 2.3381601111267736e-18),
+    dd(0.05382763486873103, -1.7173885604318435e-18),
+    dd(0.05382763486873103, -1.7173885604318435e-18),
+    dd(0.05235790722987272, -2.023117050766491e-18),
+    dd(0.05235790722987272, -2.023117050766491e-18),
+    dd(0.05070769106929272, -1.2826455702329934e-18),
+    dd(0.05070769106929272, -1.2826455702329934e-18),
+    dd(0.04888267503269914, -2.016238959724384e-18),
+    dd(0.04888267503269914, -2.016238959724384e-18),
+    dd(0.046889150340750314, -3.2780775477841754e-19),
+    dd(0.046889150340750314, -3.2780775477841754e-19),
+    dd(0.04473398910367281, 1.9831264441915954e-18),
+    dd(0.04473398910367281, 1.9831264441915954e-18),
+    dd(0.042424620634520016, -2.4902654072205024e-18),
+    dd(0.042424620634520016, -2.4902654072205024e-18),
+    dd(0.039969005843540385, -3.1428139431308976e-18),
+    dd(0.039969005843540385, -3.1428139431308976e-18),
+    dd(0.037375609803482916, -5.527079779419856e-19),
+    dd(0.037375609803482916, -5.527079779419856e-19),
+    dd(0.03465337258353424, 1.4077257760925846e-18),
+    dd(0.03465337258353424, 1.4077257760925846e-18),
+    dd(0.031811678459019326, -2.4412173729673558e-18),
+    dd(0.031811678459019326, -2.4412173729673558e-18),
+    dd(0.028860323617823737, -5.786483150297904e-19),
+    dd(0.028860323617823737, -5.786483150297904e-19),
+    dd(0.025809482510757518, -7.29748995626167e-20),
+    dd(0.025809482510757518, -7.29748995626167e-20),
+    dd(0.022669673057070207, 9.040661399845289e-19),
+    dd(0.022669673057070207, 9.040661399845289e-19),
+    dd(0.019451721107636894, 1.2566705850138732e-18),
+    dd(0.019451721107636894, 1.2566705850138732e-18),
+    dd(0.016166725256687463, 1.1308048675815997e-18),
+    dd(0.016166725256687463, 1.1308048675815997e-18),
+    dd(0.012826026144240379, 6.980989424786224e-20),
+    dd(0.012826026144240379, 6.980989424786224e-20),
+    dd(0.009441202284940345, -4.710044376423407e-19),
+    dd(0.009441202284940345, -4.710044376423407e-19),
+    dd(0.006024276226948674, -3.9800949368046495e-19),
+    dd(0.006024276226948674, -3.9800949368046495e-19),
+    dd(0.002591683720567032, -1.9412100365638497e-20),
+    dd(0.002591683720567032, -1.9412100365638497e-20),
+    dd(0.057617536707147025, -7.37515809546431e-19),
+    dd(0.057617536707147025, -7.37515809546431e-19),
+    dd(0.05742613705411211, 2.1361757955963296e-18),
+    dd(0.05742613705411211, 2.1361757955963296e-18),
+    dd(0.0570439735587946, -1.2049782631733e-19),
+    dd(0.0570439735587946, -1.2049782631733e-19),
+    dd(0.056472315730625965, 4.283817725839547e-19),
+    dd(0.056472315730625965, 4.283817725839547e-19),
+    dd(0.055713062560589985, 2.8326690442050285e-18),
+    dd(0.055713062560589985, 2.8326690442050285e-18),
+    dd(0.054768736213057986, -3.329148130827526e-20),
+    dd(0.054768736213057986, -3.329148130827526e-20),
+    dd(0.05364247364755361, 3.0066158569303047e-19),
+    dd(0.05364247364755361, 3.0066158569303047e-19),
+    dd(0.05233801619829875, -1.9073812802245556e-18),
+    dd(0.05233801619829875, -1.9073812802245556e-18),
+    dd(0.05085969714618815, -2.9234528851054287e-18),
+    dd(0.05085969714618815, -2.9234528851054287e-18),
+    dd(0.049212427324528886, 1.052845884792525e-19),
+    dd(0.049212427324528886, 1.052845884792525e-19),
+    dd(0.04740167880644499, 1.236454427545299e-18),
+    dd(0.04740167880644499, 1.236454427545299e-18),
+    dd(0.045433466728276715, -1.4768431859582832e-18),
+    dd(0.045433466728276715, -1.4768431859582832e-18),
+    dd(0.04331432930959701, 2.1130782791088894e-18),
+    dd(0.04331432930959701, 2.1130782791088894e-18),
+    dd(0.041051306136644976, -1.5754603918834462e-18),
+    dd(0.041051306136644976, -1.5754603918834462e-18),
+    dd(0.03865191478210252, -3.5053273942087893e-19),
+    dd(0.03865191478210252, -3.5053273942087893e-19),
+    dd(0.036124125840383554, -1.814862957986956e-18),
+    dd(0.036124125840383554, -1.814862957986956e-18),
+    dd(0.03347633646437265, -1.1567486739525943e-18),
+    dd(0.03347633646437265, -1.1567486739525943e-18),
+    dd(0.030717342497870677, -8.333014085271139e-19),
+    dd(0.030717342497870677, -8.333014085271139e-19),
+    dd(0.02785630931059587, -8.889286475202457e-19),
+    dd(0.02785630931059587, -8.889286475202457e-19),
+    dd(0.024902741467208774, -1.161961593046649e-18),
+    dd(0.024902741467208774, -1.161961593046649e-18),
+    dd(0.021866451422853084, 1.5102055331207731e-18),
+    dd(0.021866451422853084, 1.5102055331207731e-18),
+    dd(0.01875752762146938, -1.052472527911152e-18),
+    dd(0.01875752762146938, -1.052472527911152e-18),
+    dd(0.015586303035924131, 7.088523998096066e-19),
+    dd(0.015586303035924131, 7.088523998096066e-19),
+    dd(0.012363328128847644, -1.0800525802871091e-20),
+    dd(0.012363328128847644, -1.0800525802871091e-20),
+    dd(0.009099369455509397, -1.2746534641741766e-19),
+    dd(0.009099369455509397, -1.2746534641741766e-19),
+    dd(0.005805611015239985, -1.9440709893282877e-19),
+    dd(0.005805611015239985, -1.9440709893282877e-19),
+    dd(0.002497481835761586, -2.1000947421012756e-19),
+    dd(0.002497481835761586, -2.1000947421012756e-19),
+    dd(0.05660297644456042, 2.9535985834623664e-18),
+    dd(0.056512318249772, 8.227454338854311e-19),
+    dd(0.056512318249772, 8.227454338854311e-19),
+    dd(0.056240634071084365, 2.7863242603380735e-18),
+    dd(0.056240634071084365, 2.7863242603380735e-18),
+    dd(0.05578879419528409, -3.0686577533777475e-18),
+    dd(0.05578879419528409, -3.0686577533777475e-18),
+    dd(0.05515824600250869, -1.1578553544179577e-18),
+    dd(0.05515824600250869, -1.1578553544179577e-18),
+    dd(0.054351009329911104, -2.0473053557435284e-18),
+    dd(0.054351009329911104, -2.0473053557435284e-18),
+    dd(0.053369670001605474, -9.734556117748384e-19),
+    dd(0.053369670001605474, -9.734556117748384e-19),
+    dd(0.05221737154563209, -2.0957445081842584e-18),
+    dd(0.05221737154563209, -2.0957445081842584e-18),
+    dd(0.05089780512449398, -2.696099300310118e-18),
+    dd(0.05089780512449398, -2.696099300310118e-18),
+    dd(0.04941519771155174, -2.640535700479871e-18),
+    dd(0.04941519771155174, -2.640535700479871e-18),
+    dd(0.047774298551200696, -4.11379472458053e-19),
+    dd(0.047774298551200696, -4.11379472458053e-19),
+    dd(0.04598036394628384, -1.1676178478416716e-18),
+    dd(0.04598036394628384, -1.1676178478416716e-18),
+    dd(0.04403914042160659, 2.5229295352380886e-18),
+    dd(0.04403914042160659, 2.5229295352380886e-18),
+    dd(0.04195684631771876, 2.3419681368951973e-18),
+    dd(0.04195684631771876, 2.3419681368951973e-18),
+    dd(0.03974015187433718, -7.726602143150507e-19),
+    dd(0.03974015187433718, -7.726602143150507e-19),
+    dd(0.037396157867965546, -6.904610079431047e-19),
+    dd(0.037396157867965546, -6.904610079431047e-19),
+    dd(0.034932372873589884, 3.2193178661193897e-18),
+    dd(0.034932372873589884, 3.2193178661193897e-18),
+    dd(0.032356689226185835, -3.2441893991047755e-18),
+    dd(0.032356689226185835, -3.2441893991047755e-18),
+    dd(0.02967735776516104, 1.0950626349810445e-18),
+    dd(0.02967735776516104, 1.0950626349810445e-18),
+    dd(0.02690296145639627, -3.4452845134447813e-19),
+    dd(0.02690296145639627, -3.4452845134447813e-19),
+    dd(0.02404238800972562, 1.0513111321467151e-18),
+    dd(0.02404238800972562, 1.0513111321467151e-18),
+    dd(0.021104801668016454, 4.5392726500845334e-20),
+    dd(0.021104801668016454, 4.5392726500845334e-20),
+    dd(0.018099614520729064, -1.6075902045201408e-18),
+    dd(0.018099614520729064, -1.6075902045201408e-18),
+    dd(0.015036458333511788, -2.273554304210059e-19),
+    dd(0.015036458333511788, -2.273554304210059e-19),
+    dd(0.011925160719848612, 2.7527533366647695e-19),
+    dd(0.011925160719848612, 2.7527533366647695e-19),
+    dd(0.008775746107058528, 2.6846115373550625e-19),
+    dd(0.008775746107058528, 2.6846115373550625e-19),
+    dd(0.0055986322665607675, -1.1625910207588583e-19),
+    dd(0.0055986322665607675, -1.1625910207588583e-19),
+    dd(0.002408323619979789, -1.6467563826896357e-20),
+    dd(0.002408323619979789, -1.6467563826896357e-20),
+    dd(0.055579746306514397, -6.68135388784229e-19),
+    dd(0.055579746306514397, -6.68135388784229e-19),
+    dd(0.05540795250324512, 6.625512726106114e-19),
+    dd(0.05540795250324512, 6.625512726106114e-19),
+    dd(0.055064895901762424, 1.5278604903918217e-18),
+    dd(0.055064895901762424, 1.5278604903918217e-18),
+    dd(0.054551636870889424, -2.585473337585029e-18),
+    dd(0.054551636870889424, -2.585473337585029e-18),
+    dd(0.05386976186571449, -2.6157867722165586e-18),
+    dd(0.05386976186571449, -2.6157867722165586e-18),
+    dd(0.053021378524010766, -2.318620025911359e-18),
+    dd(0.053021378524010766, -2.318620025911359e-18),
+    dd(0.0520091091517414, -2.1343123668205307e-18),
+    dd(0.0520091091517414, -2.1343123668205307e-18),
+    dd(0.050836082617798484, -3.1358681992200195e-18),
+    dd(0.050836082617798484, -3.1358681992200195e-18),
+    dd(0.04950592468304758, 2.3244922754091298e-18),
+    dd(0.04950592468304758, 2.3244922754091298e-18),
+    dd(0.04802274679360026, -1.8750135076749926e-18),
+    dd(0.04802274679360026, -1.8750135076749926e-18),
+    dd(0.046391133373001894, 2.4377513258972827e-18),
+    dd(0.046391133373001894, 2.4377513258972827e-18),
+    dd(0.04461612765269228, 2.5660644507988587e-18),
+    dd(0.04461612765269228, 2.5660644507988587e-18),
+    dd(0.04270321608466709, -1.5039536587750351e-18),
+    dd(0.04270321608466709, -1.5039536587750351e-18),
+    dd(0.04065831138474452, 1.321171658340941e-18),
+    dd(0.04065831138474452, 1.321171658340941e-18),
+    dd(0.03848773425924766, 1.8137500054770845e-18),
+    dd(0.03848773425924766, 1.8137500054770845e-18),
+    dd(0.03619819387231519, -2.832276149796426e-18),
+    dd(0.03619819387231519, -2.832276149796426e-18),
+    dd(0.03379676711561176, -3.161555221385503e-19),
+    dd(0.03379676711561176, -3.161555221385503e-19),
+    dd(0.031290876747310445, 2.9847587367857816e-18),
+    dd(0.031290876747310445, 2.9847587367857816e-18),
+    dd(0.02868826847382274, 8.627118651445819e-19),
+    dd(0.02868826847382274, 8.627118651445819e-19),
+    dd(0.025996987058391954, -1.453711415231477e-18),
+    dd(0.025996987058391954, -1.453711415231477e-18),
+    dd(0.023225351562565315, 1.443133794804989e-18),
+    dd(0.023225351562565315, 1.443133794804989e-18),
+    dd(0.02038192988240257, 1.4656416655457858e-18),
+    dd(0.02038192988240257, 1.4656416655457858e-18),
+    dd(0.017475512911400946, 1.3518172637484975e-19),
+    dd(0.017475512911400946, 1.3518172637484975e-19),
+    dd(0.014515089278021472, -4.721518362982551e-19),
+    dd(0.014515089278021472, -4.721518362982551e-19),
+    dd(0.011509824340383383, -8.607301250322008e-19),
+    dd(0.011509824340383383, -8.607301250322008e-19),
+    dd(0.008469063163307887, 7.182731486593e-19),
+    dd(0.008469063163307887, 7.182731486593e-19),
+    dd(0.005402522246015338, -4.2795098389490753e-19),
+    dd(0.005402522246015338, -4.2795098389490753e-19),
+    dd(0.0023238553757732156, -9.896920982085947e-20),
+    dd(0.0023238553757732156, -9.896920982085947e-20),
+    dd(0.05463432875658403, -3.368289901643542e-18),
+    dd(0.05455280360476188, 3.141957845525777e-18),
+    dd(0.05455280360476188, 3.141957845525777e-18),
+    dd(0.05430847145249864, 3.3632943126560256e-18),
+    dd(0.05430847145249864, 3.3632943126560256e-18),
+    dd(0.053902061483298576, -1.2407176111251278e-18),
+    dd(0.053902061483298576, -1.2407176111251278e-18),
+    dd(0.05333478658481916, -2.033281289140054e-18),
+    dd(0.05333478658481916, -2.033281289140054e-18),
+    dd(0.05260833972917743, 1.707732860048096e-18),
+    dd(0.05260833972917743, 1.707732860048096e-18),
+    dd(0.051724888920517825, -5.541901634799439e-19),
+    dd(0.051724888920517825, -5.541901634799439e-19),
+    dd(0.05068707072492741, -1.846650588701778e-18),
+    dd(0.05068707072492741, -1.846650588701778e-18),
+    dd(0.04949798240201968, 1.6323224960888873e-18),
+    dd(0.04949798240201968, 1.6323224960888873e-18),
+    dd(0.04816117266168775, 2.915741212584569e-18),
+    dd(0.04816117266168775, 2.915741212584569e-18),
+    dd(0.0466806310736415, 9.576528050407872e-19),
+    dd(0.0466806310736415, 9.576528050407872e-19),
+    dd(0.045060776161381155, 2.5279880559228322e-18),
+    dd(0.045060776161381155, 2.5279880559228322e-18),
+    dd(0.0433064422162152, -2.4738517505447278e-18),
+    dd(0.0433064422162152, -2.4738517505447278e-18),
+    dd(0.04142286487080111, 1.8465344243219016e-18),
+    dd(0.04142286487080111, 1.8465344243219016e-18),
+    dd(0.039415665475480116, -2.030057899960549e-18),
+    dd(0.039415665475480116, -2.030057899960549e-18),
+    dd(0.037290834324417314, 3.0275750084797072e-18),
+    dd(0.037290834324417314, 3.0275750084797072e-18),
+    dd(0.03505471278231262, -1.7178948403653479e-18),
+    dd(0.03505471278231262, -1.7178948403653479e-18),
+    dd(0.03271397436637157, -3.2577925705318578e-18),
+    dd(0.03271397436637157, -3.2577925705318578e-18),
+    dd(0.030275604842694, 4.447986909708704e-19),
+    dd(0.030275604842694, 4.447986909708704e-19),
+    dd(0.027746881402180193, -6.224381631230587e-19),
+    dd(0.027746881402180193, -6.224381631230587e-19),
+    dd(0.025135350990918123, 3.71999510321982e-20),
+    dd(0.025135350990918123, 3.71999510321982e-20),
+    dd(0.022448807890776436, 1.6496533382740348e-18),
+    dd(0.022448807890776436, 1.6496533382740348e-18),
+    dd(0.01969527069948852, 3.604088921223065e-19),
+    dd(0.01969527069948852, 3.604088921223065e-19),
+    dd(0.01688295902344155, -1.2153209749445103e-18),
+    dd(0.01688295902344155, -1.2153209749445103e-18),
+    dd(0.014020270790753556, 1.779997591125144e-19),
+    dd(0.014020270790753556, 1.779997591125144e-19),
+    dd(0.01111576373233599, 6.930045829945919e-19),
+    dd(0.01111576373233599, 6.930045829945919e-19),
+    dd(0.008178160067821233, -6.687824567460965e-19),
+    dd(0.008178160067821233, -6.687824567460965e-19),
+    dd(0.00521653347471878, -2.807797651007649e-19),
+    dd(0.00521653347471878, -2.807797651007649e-19),
+    dd(0.002243753872250663, -8.747165165329281e-20),
+    dd(0.002243753872250663, -8.747165165329281e-20),
+    dd(0.05368111986333485, 1.4442834355682659e-18),
+    dd(0.05368111986333485, 1.4442834355682659e-18),
+    dd(0.053526343304058255, -2.7010919736313997e-18),
+    dd(0.053526343304058255, -2.7010919736313997e-18),
+    dd(0.05321723644657901, 3.125716671948468e-18),
+    dd(0.05321723644657901, 3.125716671948468e-18),
+    dd(0.052754690526370836, -2.29401162591439e-18),
+    dd(0.052754690526370836, -2.29401162591439e-18),
+    dd(0.05214003918366982, -2.5718273072868827e-18),
+    dd(0.05214003918366982, -2.5718273072868827e-18),
+    dd(0.051375054618285725, 9.131153016495856e-19),
+    dd(0.051375054618285725, 9.131153016495856e-19),
+    dd(0.05046194247995313, -3.239835566605197e-18),
+    dd(0.05046194247995313, -3.239835566605197e-18),
+    dd(0.04940333550896239, 1.9170761119072556e-19),
+    dd(0.04940333550896239, 1.9170761119072556e-19),
+    dd(0.04820228594541775, -1.1842886240137057e-19),
+    dd(0.04820228594541775, -1.1842886240137057e-19),
+    dd(0.046862256729026344, 2.7142815997270908e-18),
+    dd(0.046862256729026344, 2.7142815997270908e-18),
+    dd(0.045387111514819806, -3.153713937577331e-18),
+    dd(0.045387111514819806, -3.153713937577331e-18),
+    dd(0.04378110353364025, -7.735274534343221e-19),
+    dd(0.04378110353364025, -7.735274534343221e-19),
+    dd(0.042048863329582124, 1.6904631547777054e-18),
+    dd(0.042048863329582124, 1.6904631547777054e-18),
+    dd(0.0401953854098678, -2.67624398308027e-18),
+    dd(0.0401953854098678, -2.67624398308027e-18),
+    dd(0.038226013845858435, -1.806814413847052e-18),
+    dd(0.038226013845858435, -1.806814413847052e-18),
+    dd(0.03614642686708727, -1.831322400136175e-18),
+    dd(0.03614642686708727, -1.831322400136175e-18),
+    dd(0.03396262049341601, 2.4373397629758725e-18),
+    dd(0.03396262049341601, 2.4373397629758725e-18),
+    dd(0.03168089125380933, -3.08980408376517e-18),
+    dd(0.03168089125380933, -3.08980408376517e-18),
+    dd(0.02930781804416049, 1.1248459646018852e-19),
+    dd(0.02930781804416049, 1.1248459646018852e-19),
+    dd(0.02685024318198187, -1.4018094387936988e-18),
+    dd(0.02685024318198187, -1.4018094387936988e-18),
+    dd(0.024315252724963952, 8.267682125544166e-20),
+    dd(0.024315252724963952, 8.267682125544166e-20),
+    dd(0.021710156140146236, -4.1419307496039075e-19),
+    dd(0.021710156140146236, -4.1419307496039075e-19),
+    dd(0.019042465461893407, 1.71412518289852e-18),
+    dd(0.019042465461893407, 1.71412518289852e-18),
+    dd(0.016319874234970964, 6.526160079244248e-19),
+    dd(0.016319874234970964, 6.526160079244248e-19),
+    dd(0.013550237112988812, -3.038425142865541e-19),
+    dd(0.013550237112988812, -3.038425142865541e-19),
+    dd(0.010741553532878773, 8.530085571716625e-19),
+    dd(0.010741553532878773, 8.530085571716625e-19),
+    dd(0.007901973849998675, -4.937798522120701e-19),
+    dd(0.007901973849998675, -4.937798522120701e-19),
+    dd(0.005039981612650243, 2.46570455617718e-19),
+    dd(0.005039981612650243, 2.46570455617718e-19),
+    dd(0.00216772324962745, -1.5016439608843864e-19),
+    dd(0.00216772324962745, -1.5016439608843864e-19),
+    dd(0.05279801262199042, -1.3613840908983075e-18),
+    dd(0.05272443385912793, 2.336638139849776e-18),
+    dd(0.05272443385912793, 2.336638139849776e-18),
+    dd(0.05250390264782874, -1.1179702434192297e-18),
+    dd(0.05250390264782874, -1.1179702434192297e-18),
+    dd(0.052137033648375394, -2.950641443596677e-18),
+    dd(0.052137033648375394, -2.950641443596677e-18),
+    dd(0.05162484939089148, 2.8071127206509988e-18),
+    dd(0.05162484939089148, 2.8071127206509988e-18),
+    dd(0.050968777425393914, 2.4793155334623226e-18),
+    dd(0.050968777425393914, 2.4793155334623226e-18),
+    dd(0.0501706463429969, 1.7981196004700223e-18),
+    dd(0.0501706463429969, 1.7981196004700223e-18),
+    dd(0.049232680679361984, 1.5579511879598442e-18),
+    dd(0.049232680679361984, 1.5579511879598442e-18),
+    dd(0.04815749471460644, 1.786490649487198e-18),
+    dd(0.04815749471460644, 1.786490649487198e-18),
+    dd(0.046948085186962016, 3.3686186530077325e-18),
+    dd(0.046948085186962016, 3.3686186530077325e-18),
+    dd(0.04560782294050977, 3.2913282422113086e-18),
+    dd(0.04560782294050977, 3.2913282422113086e-18),
+    dd(0.044140443530297384, -3.1320820792285186e-18),
+    dd(0.044140443530297384, -3.1320820792285186e-18),
+    dd(0.042550036811067636, 2.5895315457830476e-18),
+    dd(0.042550036811067636, 2.5895315457830476e-18),
+    dd(0.04084103553868671, -3.3881152267817023e-18),
+    dd(0.04084103553868671, -3.3881152267817023e-18),
+    dd(0.03901820301616001, -2.6279331860614486e-18),
+    dd(0.03901820301616001, -2.6279331860614486e-18),
+    dd(0.037086619818870924, -9.897595009182486e-19),
+    dd(0.037086619818870924, -9.897595009182486e-19),
+    dd(0.03505166963640011, 1.9839066211600134e-18),
+    dd(0.03505166963640011, 1.9839066211600134e-18),
+    dd(0.032919024271045275, 3.1364165304513165e-18),
+    dd(0.032919024271045275, 3.1364165304513165e-18),
+    dd(0.030694627836111682, 1.2442601979444087e-18),
+    dd(0.030694627836111682, 1.2442601979444087e-18),
+    dd(0.0283846802005348, -1.604995206916037e-18),
+    dd(0.0283846802005348, -1.604995206916037e-18),
+    dd(0.0259956197312985, 1.675154642097151e-18),
+    dd(0.0259956197312985, 1.675154642097151e-18),
+    dd(0.023534105393713364, -1.767619871991159e-19),
+    dd(0.023534105393713364, -1.767619871991159e-19),
+    dd(0.021006998288437186, 1.0718848549262784e-18),
+    dd(0.021006998288437186, 1.0718848549262784e-18),
+    dd(0.01842134275361003, 5.410715534251149e-19),
+    dd(0.01842134275361003, 5.410715534251149e-19),
+    dd(0.015784347313081468, -1.509061081994949e-18),
+    dd(0.015784347313081468, -1.509061081994949e-18),
+    dd(0.01310336630634519, 5.12119974783654e-19),
+    dd(0.01310336630634519, 5.12119974783654e-19),
+    dd(0.010385885500995862, 1.2803280033281114e-19),
+    dd(0.010385885500995862, 1.2803280033281114e-19),
+    dd(0.0076395294534875756, -4.1134859956985647e-19),
+    dd(0.0076395294534875756, -4.1134859956985647e-19),
+    dd(0.004872239168265285, -1.804178369886842e-19),
+    dd(0.004872239168265285, -1.804178369886842e-19),
+    dd(0.0020954922845412235, -9.406807105912273e-20),
+    dd(0.0020954922845412235, -9.406807105912273e-20),
+    dd(0.051907877631220636, 3.3720778698649675e-18),
+    dd(0.051907877631220636, 3.3720778698649675e-18),
+    dd(0.05176794317491019, 2.232226976040154e-19),
+    dd(0.05176794317491019, 2.232226976040154e-19),
+    dd(0.051488451500980935, -8.767429007025096e-19),
+    dd(0.051488451500980935, -8.767429007025096e-19),
+    dd(0.05107015606985563, 6.808410787981573e-19),
+    dd(0.05107015606985563, 6.808410787981573e-19),
+    dd(0.050514184532509374, 1.0274420954901525e-18),
+    dd(0.050514184532509374, 1.0274420954901525e-18),
+    dd(0.04982203569055018, 8.46399678112597e-19),
+    dd(0.04982203569055018, 8.46399678112597e-19),
+    dd(0.048995575455756835, 7.964294085434622e-19),
+    dd(0.048995575455756835, 7.964294085434622e-19),
+    dd(0.04803703181997118, -1.1368926510338322e-18),
+    dd(0.04803703181997118, -1.1368926510338322e-18),
+    dd(0.0469489888489122, 3.3700426048555762e-18),
+    dd(0.0469489888489122, 3.3700426048555762e-18),
+    dd(0.045734379716114486, 8.772786917995882e-19),
+    dd(0.045734379716114486, 8.772786917995882e-19),
+    dd(0.04439647879578711, 4.0242019327461623e-19),
+    dd(0.04439647879578711, 4.0242019327461623e-19),
+    dd(0.04293889283593564, 2.4627464707596834e-18),
+    dd(0.04293889283593564, 2.4627464707596834e-18),
+    dd(0.041365551235584753, 2.115398971846541e-18),
+    dd(0.041365551235584753, 2.115398971846541e-18),
+    dd(0.0396806954523808, -1.95445165092452e-18),
+    dd(0.0396806954523808, -1.95445165092452e-18),
+    dd(0.037888867569243444, 2.9728991630665705e-19),
+    dd(0.037888867569243444, 2.9728991630665705e-19),
+    dd(0.0359948980510845, 9.890884892532175e-19),
+    dd(0.0359948980510845, 9.890884892532175e-19),
+    dd(0.03400389272494642, -3.412100532571936e-19),
+    dd(0.03400389272494642, -3.412100532571936e-19),
+    dd(0.03192121901929633, -1.5076259918344188e-19),
+    dd(0.03192121901929633, -1.5076259918344188e-19),
+    dd(0.029752491500788944, 1.2397707124466217e-18),
+    dd(0.029752491500788944, 1.2397707124466217e-18),
+    dd(0.02750355674992479, 7.822773647855841e-19),
+    dd(0.02750355674992479, 7.822773647855841e-19),
+    dd(0.025180477621521247, 9.962415988191239e-19),
+    dd(0.025180477621521247, 9.962415988191239e-19),
+    dd(0.02278951694399782, -2.6711260106125505e-19),
+    dd(0.02278951694399782, -2.6711260106125505e-19),
+    dd(0.020337120729457286, 3.3623055871549816e-19),
+    dd(0.020337120729457286, 3.3623055871549816e-19),
+    dd(0.01782990101420772, -2.3949712245488966e-19),
+    dd(0.01782990101420772, -2.3949712245488966e-19),
+    dd(0.015274618596784799, 4.2159067898439647e-19),
+    dd(0.015274618596784799, 4.2159067898439647e-19),
+    dd(0.01267816647681596, 7.578035681177845e-19),
+    dd(0.01267816647681596, 7.578035681177845e-19),
+    dd(0.010047557182287984, 6.759182396031758e-19),
+    dd(0.010047557182287984, 6.759182396031758e-19),
+    dd(0.007389931163345456, -3.1026743668108934e-19),
+    dd(0.007389931163345456, -3.1026743668108934e-19),
+    dd(0.004712729926953568, 3.473819910359947e-19),
+    dd(0.004712729926953568, 3.473819910359947e-19),
+    dd(0.0020268119688737585, 3.9959765043592807e-20),
+    dd(0.0020268119688737585, 3.9959765043592807e-20),
+    dd(0.05108111944078622, -3.2639580658191487e-18),
+    dd(0.051014487038697265, -1.3208600461360489e-18),
+    dd(0.051014487038697265, -1.3208600461360489e-18),
+    dd(0.05081476366881834, 3.3153925400205876e-18),
+    dd(0.05081476366881834, 3.3153925400205876e-18),
+    dd(0.05048247038679741, -3.464700000034073e-18),
+    dd(0.05048247038679741, -3.464700000034073e-18),
+    dd(0.05001847410817825, 2.0778395750607698e-18),
+    dd(0.05001847410817825, 2.0778395750607698e-18),
+    dd(0.04942398534673559, 1.6423068425304195e-18),
+    dd(0.04942398534673559, 1.6423068425304195e-18),
+    dd(0.04870055505641153, -1.6781062051374838e-18),
+    dd(0.04870055505641153, -1.6781062051374838e-18),
+    dd(0.047850070585095605, 1.7596739920478783e-18),
+    dd(0.047850070585095605, 1.7596739920478783e-18),
+    dd(0.04687475075080907, -1.4690831223401541e-18),
+    dd(0.04687475075080907, -1.4690831223401541e-18),
+    dd(0.04577714005314596, -1.3694704047293346e-18),
+    dd(0.04577714005314596, -1.3694704047293346e-18),
+    dd(0.04456010203508349, -6.645432859249139e-19),
+    dd(0.04456010203508349, -6.645432859249139e-19),
+    dd(0.043226811812496095, 2.79007443826933e-18),
+    dd(0.043226811812496095, 2.79007443826933e-18),
+    dd(0.041780747790888494, -1.5424094025269144e-18),
+    dd(0.041780747790888494, -1.5424094025269144e-18),
+    dd(0.04022568259099825, -1.6875852605619331e-18),
+    dd(0.04022568259099825, -1.6875852605619331e-18),
+    dd(0.038565673207008176, -3.44701475643917e-18),
+    dd(0.038565673207008176, -3.44701475643917e-18),
+    dd(0.036805050423154816, 1.7645570630248045e-18),
+    dd(0.036805050423154816, 1.7645570630248045e-18),
+    dd(0.03494840751653335, -5.00251776245657e-19),
+    dd(0.03494840751653335, -5.00251776245657e-19),
+    dd(0.03300058827590741, -1.7062038877860275e-18),
+    dd(0.03300058827590741, -1.7062038877860275e-18),
+    dd(0.030966674368397396, -6.867672732847286e-19),
+    dd(0.030966674368397396, -6.867672732847286e-19),
+    dd(0.0288519720881834, -8.363788400713932e-20),
+    dd(0.0288519720881834, -8.363788400713932e-20),
+    dd(0.02666199852415089, 1.0507941768123346e-18),
+    dd(0.02666199852415089, 1.0507941768123346e-18),
+    dd(0.024402467187544203, -7.690362019365864e-20),
+    dd(0.024402467187544203, -7.690362019365864e-20),
+    dd(0.022079273148319045, -4.9828895283568835e-19),
+    dd(0.022079273148319045, -4.9828895283568835e-19),
+    dd(0.01969847774610118, 1.5333065855085265e-18),
+    dd(0.01969847774610118, 1.5333065855085265e-18),
+    dd(0.017266292987613743, 2.7948798783430823e-19),
+    dd(0.017266292987613743, 2.7948798783430823e-19),
+    dd(0.014789065884937915, -6.11192564217905e-19),
+    dd(0.014789065884937915, -6.11192564217905e-19),
+    dd(0.012273263507812104, 2.289481556550557e-19),
+    dd(0.012273263507812104, 2.289481556550557e-19),
+    dd(0.009725461830356134, -2.9087246889678324e-19),
+    dd(0.009725461830356134, -2.9087246889678324e-19),
+    dd(0.00715235499174909, -3.994035625190681e-20),
+    dd(0.00715235499174909, -3.994035625190681e-20),
+    dd(0.004560924006012417, 3.3727472926316404e-21),
+    dd(0.004560924006012417, 3.3727472926316404e-21),
+    dd(0.001961453361670283, -1.8115683851057841e-19),
+    dd(0.001961453361670283, -1.8115683851057841e-19),
+    dd(0.05024800037525628, 3.250362068976247e-18),
+    dd(0.05024800037525628, 3.250362068976247e-18),
+    dd(0.05012106956904329, -8.411952122924475e-19),
+    dd(0.05012106956904329, -8.411952122924475e-19),
+    dd(0.049867528594952394, 9.498612286434146e-20),
+    dd(0.049867528594952394, 9.498612286434146e-20),
+    dd(0.04948801791969929, 1.3806372183691623e-18),
+    dd(0.04948801791969929, 1.3806372183691623e-18),
+    dd(0.048983496220517835, 2.575266949054589e-18),
+    dd(0.048983496220517835, 2.575266949054589e-18),
+    dd(0.048355237963477675, -2.1102754511394195e-18),
+    dd(0.048355237963477675, -2.1102754511394195e-18),
+    dd(0.047604830184101235, -2.7428441405249355e-18),
+    dd(0.047604830184101235, -2.7428441405249355e-18),
+    dd(0.04673416847841552, 2.7832864857990312e-18),
+    dd(0.04673416847841552, 2.7832864857990312e-18),
+    dd(0.04574545221457018, 7.610257369415915e-19),
+    dd(0.04574545221457018, 7.610257369415915e-19),
+    dd(0.04464117897712441, 1.5524826734170775e-18),
+    dd(0.04464117897712441, 1.5524826734170775e-18),
+    dd(0.04342413825804742, 1.7979319854213806e-18),
+    dd(0.04342413825804742, 1.7979319854213806e-18),
+    dd(0.0420974044103851, -2.2510203275943793e-18),
+    dd(0.0420974044103851, -2.2510203275943793e-18),
+    dd(0.040664328882417444, -3.2869966624267732e-18),
+    dd(0.040664328882417444, -3.2869966624267732e-18),
+    dd(0.03912853175196308, 9.271444642930332e-19),
+    dd(0.03912853175196308, 9.271444642930332e-19),
+    dd(0.03749389258228003, -6.486045034354984e-19),
+    dd(0.03749389258228003, -6.486045034354984e-19),
+    dd(0.03576454062276814, 1.2309770365125382e-18),
+    dd(0.03576454062276814, 1.2309770365125382e-18),
+    dd(0.033944844379410546, -1.351048730471441e-18),
+    dd(0.033944844379410546, -1.351048730471441e-18),
+    dd(0.03203940058162468, -3.467982773823529e-18),
+    dd(0.03203940058162468, -3.467982773823529e-18),
+    dd(0.03005302257398987, -1.5971288163573144e-18),
+    dd(0.03005302257398987, -1.5971288163573144e-18),
+    dd(0.02799072816331464, -1.4908171603176932e-18),
+    dd(0.02799072816331464, -1.4908171603176932e-18),
+    dd(0.025857726954024697, 9.634900797070834e-19),
+    dd(0.025857726954024697, 9.634900797070834e-19),
+    dd(0.023659407208682794, -1.0473621141409885e-18),
+    dd(0.023659407208682794, -1.0473621141409885e-18),
+    dd(0.021401322277669967, 1.5600461777025406e-18),
+    dd(0.021401322277669967, 1.5600461777025406e-18),
+    dd(0.0190891766585732, -8.122495384090238e-20),
+    dd(0.0190891766585732, -8.122495384090238e-20),
+    dd(0.016728811790177316, 6.670198757936853e-19),
+    dd(0.016728811790177316, 6.670198757936853e-19),
+    dd(0.014326191823806518, -2.9900581718719094e-19),
+    dd(0.014326191823806518, -2.9900581718719094e-19),
+    dd(0.011887390117010501, 5.473535560445063e-19),
+    dd(0.011887390117010501, 5.473535560445063e-19),
+    dd(0.009418579428420388, 9.033286597524154e-20),
+    dd(0.009418579428420388, 9.033286597524154e-20),
+    dd(0.006926041901830961, 2.9988527153687787e-19),
+    dd(0.006926041901830961, 2.9988527153687787e-19),
+    dd(0.004416333456930905, -3.428273391491481e-19),
+    dd(0.004416333456930905, -3.428273391491481e-19),
+    dd(0.0018992056795136905, -8.09673819277023e-21),
+    dd(0.0018992056795136905, -8.09673819277023e-21),
+    dd(0.04947236662393102, -8.009947177460443e-19),
+    dd(0.04941183303991818, -3.1307320022699923e-18),
+    dd(0.04941183303991818, -3.1307320022699923e-18),
+    dd(0.04923038042374756, -1.2280938965486897e-18),
+    dd(0.04923038042374756, -1.2280938965486897e-18),
+    dd(0.04892845282051199, 9.814836147199484e-19),
+    dd(0.04892845282051199, 9.814836147199484e-19),
+    dd(0.04850678909788385, -5.409782641173246e-19),
+    dd(0.04850678909788385, -5.409782641173246e-19),
+    dd(0.04796642113799513, 5.919172278656436e-20),
+    dd(0.04796642113799513, 5.919172278656436e-20),
+    dd(0.047308671312268916, 3.4376093513667984e-18),
+    dd(0.047308671312268916, 3.4376093513667984e-18),
+    dd(0.0465351492453837, -8.369048370951753e-19),
+    dd(0.0465351492453837, -8.369048370951753e-19),
+    dd(0.04564774787629261, -1.6391803419448696e-18),
+    dd(0.04564774787629261, -1.6391803419448696e-18),
+    dd(0.044648638825941396, -6.763155101461389e-19),
+    dd(0.044648638825941396, -6.763155101461389e-19),
+    dd(0.04354026708302759, -1.4996631237999426e-18),
+    dd(0.04354026708302759, -1.4996631237999426e-18),
+    dd(0.04232534502081582, 1.3652505594348728e-18),
+    dd(0.04232534502081582, 1.3652505594348728e-18),
+    dd(0.0410068457596664, -6.963122892041422e-19),
+    dd(0.0410068457596664, -6.963122892041422e-19),
+    dd(0.039587995891544096, -2.331497783830819e-18),
+    dd(0.039587995891544096, -2.331497783830819e-18),
+    dd(0.038072267584349555, 1.993798029052051e-18),
+    dd(0.038072267584349555, 1.993798029052051e-18),
+    dd(0.03646337008545729, 6.58876958445764e-19),
+    dd(0.03646337008545729, 6.58876958445764e-19),
+    dd(0.034765240645355876, 1.9822857823923963e-18),
+    dd(0.034765240645355876, 1.9822857823923963e-18),
+    dd(0.03298203488377934, 3.831091008430049e-20),
+    dd(0.03298203488377934, 3.831091008430049e-20),
+    dd(0.03111811662221982, -1.0484778492061164e-18),
+    dd(0.03111811662221982, -1.0484778492061164e-18),
+    dd(0.029178047208280527, 8.145224417641492e-20),
+    dd(0.029178047208280527, 8.145224417641492e-20),
+    dd(0.027166574359097934, -1.0795399650272477e-18),
+    dd(0.027166574359097934, -1.0795399650272477e-18),
+    dd(0.025088620553344987, -2.445788493469756e-19),
+    dd(0.025088620553344987, -2.445788493469756e-19),
+    dd(0.022949271004889932, 6.863086247077221e-19),
+    dd(0.022949271004889932, 6.863086247077221e-19),
+    dd(0.02075376125803909, 1.63720317596423e-18),
+    dd(0.02075376125803909, 1.63720317596423e-18),
+    dd(0.01850746446016127, -1.0374759181807136e-18),
+    dd(0.01850746446016127, -1.0374759181807136e-18),
+    dd(0.01621587841033834, -3.5603318965870655e-19),
+    dd(0.01621587841033834, -3.5603318965870655e-19),
+    dd(0.013884612616115611, -1.3088098644012885e-19),
+    dd(0.013884612616115611, -1.3088098644012885e-19),
+    dd(0.011519376076880042, -8.628551100965913e-20),
+    dd(0.011519376076880042, -8.628551100965913e-20),
+    dd(0.009125968676326656, 2.891020792384051e-19),
+    dd(0.009125968676326656, 2.891020792384051e-19),
+    dd(0.006710291765960137, -3.8350913602293966e-19),
+    dd(0.006710291765960137, -3.8350913602293966e-19),
+    dd(0.004278508346863762, -1.1638086218717376e-19),
+    dd(0.004278508346863762, -1.1638086218717376e-19),
+    dd(0.0018398745955770842, -8.43440674017079e-20),
+    dd(0.0018398745955770842, -8.43440674017079e-20),
+    dd(0.048690957009139724, -3.3045861823486603e-18),
+    dd(0.048690957009139724, -3.3045861823486603e-18),
+    dd(0.04857546744150343, -1.2772027692889083e-18),
+    dd(0.04857546744150343, -1.2772027692889083e-18),
+    dd(0.048344762234802954, 2.8548755747435952e-18),
+    dd(0.048344762234802954, 2.8548755747435952e-18),
+    dd(0.04799938859645831, -2.5684179897883368e-18),
+    dd(0.04799938859645831, -2.5684179897883368e-18),
+    dd(0.04754016571483031, 3.211721524733771e-19),
+    dd(0.04754016571483031, 3.211721524733771e-19),
+    dd(0.04696818281621002, -3.0662855488568862e-18),
+    dd(0.04696818281621002, -3.0662855488568862e-18),
+    dd(0.046284796581314416, 9.765450821768898e-19),
+    dd(0.046284796581314416, 9.765450821768898e-19),
+    dd(0.04549162792741814, 2.4386549410643004e-18),
+    dd(0.04549162792741814, 2.4386549410643004e-18),
+    dd(0.044590558163756566, -3.1632776819606426e-18),
+    dd(0.044590558163756566, -3.1632776819606426e-18),
+    dd(0.04358372452932345, 2.9517388682857768e-18),
+    dd(0.04358372452932345, 2.9517388682857768e-18),
+    dd(0.04247351512365359, -1.7119817032608608e-18),
+    dd(0.04247351512365359, -1.7119817032608608e-18),
+    dd(0.04126256324262353, 1.072755834512479e-18),
+    dd(0.04126256324262353, 1.072755834512479e-18),
+    dd(0.03995374113272034, -1.2236946676200828e-18),
+    dd(0.03995374113272034, -1.2236946676200828e-18),
+    dd(0.038550153178615626, 3.1693825813309044e-18),
+    dd(0.038550153178615626, 3.1693825813309044e-18),
+    dd(0.03705512854024005, -7.789944121910705e-19),
+    dd(0.03705512854024005, -7.789944121910705e-19),
+    dd(0.035472213256882386, -2.0460232641837795e-18),
+    dd(0.035472213256882386, -2.0460232641837795e-18),
+    dd(0.033805161837141606, 3.1179609160976343e-18),
+    dd(0.033805161837141606, 3.1179609160976343e-18),
+    dd(0.03205792835485155, 3.2399951713555406e-18),
+    dd(0.03205792835485155, 3.2399951713555406e-18),
+    dd(0.030234657072402478, 9.060264065351795e-19),
+    dd(0.030234657072402478, 9.060264065351795e-19),
+    dd(0.028339672614259483, -1.0779702647491349e-19),
+    dd(0.028339672614259483, -1.0779702647491349e-19),
+    dd(0.02637746971505466, 2.1604770495845585e-19),
+    dd(0.02637746971505466, 2.1604770495845585e-19),
+    dd(0.024352702568710874, -5.711969198495574e-19),
+    dd(0.024352702568710874, -5.711969198495574e-19),
+    dd(0.022270173808383253, 7.150220241298314e-19),
+    dd(0.022270173808383253, 7.150220241298314e-19),
+    dd(0.02013482315353021, 3.8338090102282212e-19),
+    dd(0.02013482315353021, 3.8338090102282212e-19),
+    dd(0.017951715775697343, -1.1196958600435003e-19),
+    dd(0.017951715775697343, -1.1196958600435003e-19),
+    dd(0.015726030476024718, 1.1975822564155514e-18),
+    dd(0.015726030476024718, 1.1975822564155514e-18),
+    dd(0.013463047896718643, -6.191613893099487e-21),
+    dd(0.013463047896718643, -6.191613893099487e-21),
+    dd(0.011168139460131128, 6.363607058478991e-19),
+    dd(0.011168139460131128, 6.363607058478991e-19),
+    dd(0.008846759826363947, 7.877738603105086e-19),
+    dd(0.008846759826363947, 7.877738603105086e-19),
+    dd(0.006504457968978363, 8.372311876899024e-20),
+    dd(0.006504457968978363, 8.372311876899024e-20),
+    dd(0.004147033260562468, -2.9007110714432806e-19),
+    dd(0.004147033260562468, -2.9007110714432806e-19),
+    dd(0.001783280721696433, -7.081806822804027e-20),
+    dd(0.001783280721696433, -7.081806822804027e-20),
+];
@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: MIT
+// Gauss-Legendre nodes and weights for arbitrary n, for when the tables in quadgl_data run out.
+// Rules are computed once per n by Newton iteration on P_n and then leaked, so that we can
+// hand out &'static slices just like quadgl_data does.
+
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+
+type Rule = (&'static [f64], &'static [f64]);
+
+static CACHE: LazyLock<Mutex<HashMap<usize, Rule>>> = LazyLock::new(|| Mutex::new(HashMap::new()));
+
+// returns (P_n(x), P_n'(x)) via the three-term recurrence
+fn legendre(n: usize, x: f64) -> (f64, f64) {
+    let mut p0: f64 = 1.0;
+    let mut p1: f64 = x;
+    if n == 0 {
+        return (1.0, 0.0);
+    }
+    for k in 1..n {
+        let k = k as f64;
+        let p2 = ((2.0 * k + 1.0) * x * p1 - k * p0) / (k + 1.0);
+        p0 = p1;
+        p1 = p2;
+    }
+    // P_n' = n (x P_n - P_(n-1)) / (x^2 - 1); never evaluated at x = ±1 since those aren't roots
+    return (p1, (n as f64) * (x * p1 - p0) / (x * x - 1.0));
+}
+
+fn generate(n: usize) -> (Vec<f64>, Vec<f64>) {
+    let mut xs: Vec<f64> = vec![0.0; n];
+    let mut ws: Vec<f64> = vec![0.0; n];
+    let nf = n as f64;
+    // the roots are symmetric about 0, so only find the positive half and mirror them
+    for i in 0..n.div_ceil(2) {
+        // Tricomi's initial guess for the i-th largest root
+        let theta = std::f64::consts::PI * ((i as f64) + 0.75) / (nf + 0.5);
+        let mut x = (1.0 - (nf - 1.0) / (8.0 * nf * nf * nf)) * theta.cos();
+        for _ in 0..100 {
+            let (p, dp) = legendre(n, x);
+            let dx = p / dp;
+            x -= dx;
+            if dx.abs() <= 4.0 * f64::EPSILON * x.abs().max(f64::EPSILON) {
+                break;
+            }
+        }
+        // one last derivative at the converged root, for the weight
+        let dp = legendre(n, x).1;
+        let w = 2.0 / ((1.0 - x * x) * dp * dp);
+        xs[i] = -x;
+        ws[i] = w;
+        xs[n - 1 - i] = x;
+        ws[n - 1 - i] = w;
+    }
+    return (xs, ws);
+}
+
+fn rule(n: usize) -> Rule {
+    assert!(n >= 1);
+    let mut cache = CACHE.lock().unwrap();
+    return *cache.entry(n).or_insert_with(|| {
+        let (xs, ws) = generate(n);
+        (xs.leak(), ws.leak())
+    });
+}
+
+#[inline]
+pub fn xs(n: usize) -> &'static [f64] {
+    return rule(n).0;
+}
+
+#[inline]
+pub fn ws(n: usize) -> &'static [f64] {
+    return rule(n).1;
+}
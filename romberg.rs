@@ -0,0 +1,252 @@
+// SPDX-License-Identifier: MIT
+// Romberg integration: Richardson extrapolation of the trapezoid rule, with the whole tableau kept
+// for showing how it works. Column m = 1 is the trapezoid rule on 2^(i-1) subintervals, and each
+// later column cancels the next even power of h, so R(i, m) is O(h^(2m)). Indices are 1-based to
+// match the usual R_(i,m) notation.
+
+use crate::integrator::{Budget, IntegrationError, IntegrationResult, Tolerance};
+use crate::summation::Summation;
+use plotters::prelude::*;
+use std::fmt;
+use std::time::Instant;
+
+// stops as soon as two successive estimates agree to within eps, however coarse, as it always has;
+// integrate_summed with a Tolerance can insist on a minimum level
+pub fn integrate(
+    eps: f64,
+    f: impl Fn(f64) -> f64,
+    x0: f64,
+    x1: f64,
+) -> Result<(usize, f64), IntegrationError> {
+    let tol = Tolerance {
+        min_level: 0,
+        ..Tolerance::new(eps, 0.0)
+    };
+    let (subs, ret, _, _) =
+        integrate_summed(&tol, f, x0, x1, Summation::Naive, &Budget::default())?;
+    return Ok((subs, ret));
+}
+
+// integrate to a general tolerance, with the trapezoid sums taken by `method`, giving up when the
+// next level would break `budget`; returns (subintervals, ret, last error estimate, rounding error
+// bound)
+pub fn integrate_summed(
+    tol: &Tolerance,
+    f: impl Fn(f64) -> f64,
+    x0: f64,
+    x1: f64,
+    method: Summation,
+    budget: &Budget,
+) -> Result<(usize, f64, f64, f64), IntegrationError> {
+    let (tableau, err, round) = tableau_summed(tol, f, x0, x1, method, budget)?;
+    return Ok((
+        tableau.subintervals(tableau.rows()),
+        tableau.best(),
+        err,
+        round,
+    ));
+}
+
+// integrate_summed, keeping every R_(i,m) instead of just the last; returns (tableau, last error
+// estimate, rounding error bound)
+pub fn tableau_summed(
+    tol: &Tolerance,
+    f: impl Fn(f64) -> f64,
+    x0: f64,
+    x1: f64,
+    method: Summation,
+    budget: &Budget,
+) -> Result<(Tableau, f64, f64), IntegrationError> {
+    // Rather than a 2D array for R_(i,m), we lay the rows end to end in one array `r` and keep a
+    // record `t` of the index of the last R_(i,1). `round` shadows `r` with the rounding error
+    // bound of each entry.
+    let start = Instant::now();
+    let mut r: Vec<f64> = Vec::new();
+    let mut round: Vec<f64> = Vec::new();
+    let mut t: usize = 0;
+    let mut i: usize = 1;
+    let mut subs: usize = 1;
+    let mut err: f64 = f64::INFINITY;
+    // R_(1,1)
+    r.push((x1 - x0) * (f(x0) + f(x1)) / 2.0);
+    round.push(f64::EPSILON * r[0].abs());
+    while !tol.met(subs.trailing_zeros(), *r.last().unwrap(), err) {
+        budget.check(
+            start,
+            2 * subs,
+            2 * subs + 1,
+            IntegrationResult {
+                value: *r.last().unwrap(),
+                abs_error: err.abs(),
+                evaluations: subs + 1,
+                subdivisions: subs,
+                converged: false,
+            },
+        )?;
+        i += 1;
+        subs *= 2;
+        let h: f64 = (x1 - x0) / (subs as f64);
+        // R_(i+1, 1)
+        let (sum, sum_err) = method.sum((1..subs).step_by(2).map(|k| f(x0 + (k as f64) * h)));
+        r.push(r[t] / 2.0 + h * sum);
+        round.push(round[t] / 2.0 + h.abs() * sum_err + f64::EPSILON * r.last().unwrap().abs());
+        for m in 1..i {
+            let pre = *(r.last().unwrap());
+            // 4^m - 1; a shift would overflow once a large budget lets m reach 32
+            let c = 4_f64.powi(m as i32) - 1.0;
+            err = (pre - r[t + m - 1]) / c;
+            // R_(i, m+1)
+            r.push(pre + err);
+            // R_(i, m+1) = (1 + 1/c) R_(i, m) - R_(i-1, m)/c
+            let pre_round = *(round.last().unwrap());
+            round.push(
+                (1.0 + 1.0 / c) * pre_round
+                    + round[t + m - 1] / c
+                    + 2.0 * f64::EPSILON * r.last().unwrap().abs(),
+            );
+        }
+        t = r.len() - i;
+    }
+    return Ok((Tableau::from_flat(r), err, round.pop().unwrap()));
+}
+
+#[derive(Clone, Debug)]
+pub struct Tableau {
+    // row after row: R(i, m) at i(i-1)/2 + m - 1
+    entries: Vec<f64>,
+    rows: usize,
+}
+
+impl Tableau {
+    // from the rows laid end to end, as tableau_summed builds them
+    pub fn from_flat(entries: Vec<f64>) -> Tableau {
+        let mut rows: usize = 0;
+        while rows * (rows + 1) / 2 < entries.len() {
+            rows += 1;
+        }
+        assert_eq!(
+            rows * (rows + 1) / 2,
+            entries.len(),
+            "not a triangular number of entries"
+        );
+        return Tableau { entries, rows };
+    }
+
+    pub fn rows(&self) -> usize {
+        return self.rows;
+    }
+
+    // R(i, m) for 1 <= m <= i <= rows
+    pub fn get(&self, i: usize, m: usize) -> f64 {
+        assert!(1 <= m && m <= i && i <= self.rows, "no R({i}, {m})");
+        return self.entries[i * (i - 1) / 2 + m - 1];
+    }
+
+    // R(i, 1..=i)
+    pub fn row(&self, i: usize) -> &[f64] {
+        assert!(1 <= i && i <= self.rows, "no row {i}");
+        let start = i * (i - 1) / 2;
+        return &self.entries[start..(start + i)];
+    }
+
+    // R(m..=rows, m)
+    pub fn column(&self, m: usize) -> Vec<f64> {
+        return (m..=self.rows).map(|i| self.get(i, m)).collect();
+    }
+
+    // subintervals behind row i
+    pub fn subintervals(&self, i: usize) -> usize {
+        return 1 << (i - 1);
+    }
+
+    // R(rows, rows), the answer
+    pub fn best(&self) -> f64 {
+        return *self.entries.last().unwrap();
+    }
+
+    // |R(i, m) - reference| against i for each column m, on a log scale; reference defaults to
+    // best(). Entries that hit the reference exactly have no place on the log axis and are left out.
+    pub fn plot(
+        &self,
+        path: &str,
+        caption: &str,
+        reference: Option<f64>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let reference = reference.unwrap_or(self.best());
+        let columns = (1..=self.rows)
+            .map(|m| {
+                (m..)
+                    .zip(self.column(m))
+                    .map(|(i, r)| (i as f64, (r - reference).abs()))
+                    .filter(|(_, e)| *e > 0.0)
+                    .collect::<Vec<(f64, f64)>>()
+            })
+            .collect::<Vec<_>>();
+        let (lo, hi) = columns
+            .iter()
+            .flatten()
+            .fold((f64::INFINITY, 0.0_f64), |(lo, hi), (_, e)| {
+                (lo.min(*e), hi.max(*e))
+            });
+        if hi == 0.0 {
+            // nothing to show: every entry is the reference
+            return Ok(());
+        }
+        let f = SVGBackend::new(path, (400, 300)).into_drawing_area();
+        let _ = f.fill(&WHITE);
+        let f = f.margin(10, 10, 10, 10);
+        let mut chart = ChartBuilder::on(&f)
+            .set_label_area_size(LabelAreaPosition::Left, 40)
+            .set_label_area_size(LabelAreaPosition::Bottom, 40)
+            .caption(caption, ("Libertinus Serif", 20))
+            .build_cartesian_2d(
+                1f64..(self.rows as f64),
+                ((lo / 2.0)..(hi * 2.0)).log_scale(),
+            )?;
+        chart
+            .configure_mesh()
+            .x_desc("i")
+            .y_desc("|R(i,m) - I|")
+            .draw()?;
+        for (k, points) in columns.into_iter().enumerate() {
+            if points.is_empty() {
+                continue;
+            }
+            let color = Palette99::pick(k).to_rgba();
+            // markers too, since the last columns are only a point or two
+            chart.draw_series(points.iter().map(|p| Circle::new(*p, 2, color.filled())))?;
+            chart
+                .draw_series(LineSeries::new(points, color))?
+                .label(format!("m={}", k + 1))
+                .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
+        }
+        chart
+            .configure_series_labels()
+            .position(SeriesLabelPosition::UpperRight)
+            .border_style(BLACK)
+            .background_style(WHITE.mix(0.8))
+            .draw()?;
+        f.present()?;
+        return Ok(());
+    }
+}
+
+// one row per line, "i  subintervals  R(i,1)  R(i,2) ..."; the precision ({:.15}, say) sets the
+// digits shown, 10 by default
+impl fmt::Display for Tableau {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let digits = f.precision().unwrap_or(10);
+        let width = digits + 6;
+        write!(f, "{:>3} {:>6}", "i", "subs")?;
+        for m in 1..=self.rows {
+            write!(f, " {:>width$}", format!("m={m}"))?;
+        }
+        for i in 1..=self.rows {
+            write!(f, "\n{i:>3} {:>6}", self.subintervals(i))?;
+            for r in self.row(i) {
+                write!(f, " {r:>width$.digits$}")?;
+            }
+        }
+        return Ok(());
+    }
+}
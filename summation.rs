@@ -0,0 +1,117 @@
+// SPDX-License-Identifier: MIT
+// summing many f64 terms, with a choice of how hard to fight rounding. Alongside the sum we keep
+// the standard a priori bound on its rounding error (Higham, "Accuracy and Stability of Numerical
+// Algorithms", ch. 4), k u Σ|x_i| with u = 2^-53 and
+//     naive:           k = n - 1
+//     pairwise:        k = ceil(log2 n)
+//     kahan, neumaier: k = 2 (plus an O(n u^2) term)
+// Neumaier's variant also stays exact when a term is bigger than the running sum, where Kahan's
+// loses the compensation.
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Summation {
+    #[default]
+    Naive,
+    Pairwise,
+    Kahan,
+    Neumaier,
+}
+
+pub const ALL: [Summation; 4] = [
+    Summation::Naive,
+    Summation::Pairwise,
+    Summation::Kahan,
+    Summation::Neumaier,
+];
+
+const U: f64 = f64::EPSILON / 2.0;
+
+#[derive(Clone, Debug)]
+pub struct Accumulator {
+    method: Summation,
+    sum: f64,
+    // running compensation for kahan and neumaier
+    c: f64,
+    // pairwise: partial sums of 2^k terms each, with strictly decreasing k, like a binary counter
+    stack: Vec<(f64, usize)>,
+    n: usize,
+    abs_sum: f64,
+}
+
+impl Accumulator {
+    pub fn new(method: Summation) -> Accumulator {
+        return Accumulator {
+            method,
+            sum: 0.0,
+            c: 0.0,
+            stack: Vec::new(),
+            n: 0,
+            abs_sum: 0.0,
+        };
+    }
+
+    pub fn add(&mut self, x: f64) {
+        self.n += 1;
+        self.abs_sum += x.abs();
+        match self.method {
+            Summation::Naive => self.sum += x,
+            Summation::Pairwise => {
+                let mut top = (x, 1);
+                while let Some(&(s, k)) = self.stack.last() {
+                    if k != top.1 {
+                        break;
+                    }
+                    self.stack.pop();
+                    top = (s + top.0, 2 * k);
+                }
+                self.stack.push(top);
+            }
+            Summation::Kahan => {
+                let y = x - self.c;
+                let t = self.sum + y;
+                self.c = (t - self.sum) - y;
+                self.sum = t;
+            }
+            Summation::Neumaier => {
+                let t = self.sum + x;
+                if self.sum.abs() >= x.abs() {
+                    self.c += (self.sum - t) + x;
+                } else {
+                    self.c += (x - t) + self.sum;
+                }
+                self.sum = t;
+            }
+        }
+    }
+
+    pub fn value(&self) -> f64 {
+        return match self.method {
+            Summation::Naive | Summation::Kahan => self.sum,
+            // smallest blocks first
+            Summation::Pairwise => self.stack.iter().rev().fold(0.0, |acc, (s, _)| acc + s),
+            Summation::Neumaier => self.sum + self.c,
+        };
+    }
+
+    // bound on |value() - exact sum of the terms|
+    pub fn error(&self) -> f64 {
+        let n = self.n as f64;
+        let k = match self.method {
+            Summation::Naive => (n - 1.0).max(0.0),
+            Summation::Pairwise => n.log2().ceil().max(0.0),
+            Summation::Kahan | Summation::Neumaier => 2.0 + n * U,
+        };
+        return k * U * self.abs_sum;
+    }
+}
+
+impl Summation {
+    // (sum, rounding error bound)
+    pub fn sum(self, xs: impl IntoIterator<Item = f64>) -> (f64, f64) {
+        let mut acc = Accumulator::new(self);
+        for x in xs {
+            acc.add(x);
+        }
+        return (acc.value(), acc.error());
+    }
+}
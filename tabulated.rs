@@ -0,0 +1,174 @@
+// SPDX-License-Identifier: MIT
+// integrating samples (x_i, y_i) instead of a function, e.g. measured data. The x_i must be
+// strictly increasing. Every method here is a sum over the intervals [x_i, x_(i+1)], which is what
+// makes the cumulative version cheap.
+
+#[derive(Clone, Copy, Debug)]
+pub enum Method {
+    Trapezoid,
+    // the quadratic through each pair of intervals, any spacing
+    Simpson,
+    // not-a-knot cubic spline
+    Spline,
+}
+
+fn check(data: &[(f64, f64)]) {
+    assert!(
+        data.windows(2).all(|p| p[0].0 < p[1].0),
+        "sample points must be strictly increasing"
+    );
+}
+
+pub fn trapezoid(data: &[(f64, f64)]) -> f64 {
+    return intervals(Method::Trapezoid, data).iter().sum();
+}
+
+// composite Simpson on evenly spaced samples: h/3 (y_0 + 4y_1 + 2y_2 + ... + 4y_(n-1) + y_n). With
+// an odd number of intervals the last one gets the quadratic through the last three points.
+pub fn simpson(data: &[(f64, f64)]) -> f64 {
+    check(data);
+    if data.len() < 3 {
+        return trapezoid(data);
+    }
+    let m = data.len() - 1;
+    let h = (data[m].0 - data[0].0) / (m as f64);
+    assert!(
+        data.windows(2)
+            .all(|p| ((p[1].0 - p[0].0) - h).abs() <= 1E-9 * h),
+        "simpson needs evenly spaced samples; use simpson_nonuniform"
+    );
+    let even = m - m % 2;
+    let mut ret: f64 = data[0].1 + data[even].1;
+    for (i, (_, y)) in data.iter().enumerate().take(even).skip(1) {
+        ret += if i % 2 == 1 { 4.0 * y } else { 2.0 * y };
+    }
+    ret *= h / 3.0;
+    if even < m {
+        let [a, b, c] = [data[m - 2], data[m - 1], data[m]];
+        ret += quadratic_first(c.0 - b.0, b.0 - a.0, c.1, b.1, a.1);
+    }
+    return ret;
+}
+
+// Simpson for uneven spacing: on each pair of intervals, the exact integral of the quadratic
+// through its three samples
+pub fn simpson_nonuniform(data: &[(f64, f64)]) -> f64 {
+    return intervals(Method::Simpson, data).iter().sum();
+}
+
+pub fn spline(data: &[(f64, f64)]) -> f64 {
+    return intervals(Method::Spline, data).iter().sum();
+}
+
+// the running integral from x_0 to each x_i (so the first entry is 0 and the last is the total)
+pub fn cumulative(method: Method, data: &[(f64, f64)]) -> Vec<f64> {
+    let mut ret: Vec<f64> = Vec::with_capacity(data.len());
+    let mut sum: f64 = 0.0;
+    if !data.is_empty() {
+        ret.push(0.0);
+    }
+    for piece in intervals(method, data) {
+        sum += piece;
+        ret.push(sum);
+    }
+    return ret;
+}
+
+// ∫ over [x0, x0 + h0] of the quadratic through (x0, y0), (x0 + h0, y1), (x0 + h0 + h1, y2); with
+// the arguments mirrored it gives the second interval instead
+fn quadratic_first(h0: f64, h1: f64, y0: f64, y1: f64, y2: f64) -> f64 {
+    // Newton form y0 + d01 t + d012 t (t - h0)
+    let d01 = (y1 - y0) / h0;
+    let d012 = ((y2 - y1) / h1 - d01) / (h0 + h1);
+    return h0 * (y0 + h0 * d01 / 2.0 - h0 * h0 * d012 / 6.0);
+}
+
+// second derivatives M_i of the not-a-knot cubic spline through the samples (the third
+// derivative is continuous at x_1 and x_(n-2)), which, unlike the natural spline, doesn't force
+// M = 0 at the ends and so keeps O(h^4) accuracy there. Fewer than four samples get the polynomial
+// through all of them.
+fn spline_moments(data: &[(f64, f64)]) -> Vec<f64> {
+    let n = data.len();
+    let mut m: Vec<f64> = vec![0.0; n];
+    if n < 3 {
+        return m;
+    }
+    let h = |i: usize| data[i + 1].0 - data[i].0;
+    let slope = |i: usize| (data[i + 1].1 - data[i].1) / h(i);
+    if n == 3 {
+        let c = 2.0 * (slope(1) - slope(0)) / (h(0) + h(1));
+        return vec![c; 3];
+    }
+    // interior rows i = 1..=n-2:
+    //     h_(i-1) M_(i-1) + 2(h_(i-1) + h_i) M_i + h_i M_(i+1) = 6 (s_i - s_(i-1))
+    // with M_0 and M_(n-1) eliminated from the first and last through the not-a-knot conditions
+    let mut sub: Vec<f64> = vec![0.0; n];
+    let mut diag: Vec<f64> = vec![0.0; n];
+    let mut sup: Vec<f64> = vec![0.0; n];
+    let mut rhs: Vec<f64> = vec![0.0; n];
+    for i in 1..(n - 1) {
+        sub[i] = h(i - 1);
+        diag[i] = 2.0 * (h(i - 1) + h(i));
+        sup[i] = h(i);
+        rhs[i] = 6.0 * (slope(i) - slope(i - 1));
+    }
+    // M_0 = M_1 + h_0 (M_1 - M_2)/h_1
+    diag[1] += h(0) * (h(0) + h(1)) / h(1);
+    sup[1] -= h(0) * h(0) / h(1);
+    // M_(n-1) = M_(n-2) + h_(n-2) (M_(n-2) - M_(n-3))/h_(n-3)
+    let (a, b) = (h(n - 3), h(n - 2));
+    diag[n - 2] += b * (a + b) / a;
+    sub[n - 2] -= b * b / a;
+    // Thomas algorithm
+    for i in 2..(n - 1) {
+        let w = sub[i] / diag[i - 1];
+        diag[i] -= w * sup[i - 1];
+        rhs[i] -= w * rhs[i - 1];
+    }
+    m[n - 2] = rhs[n - 2] / diag[n - 2];
+    for i in (1..(n - 2)).rev() {
+        m[i] = (rhs[i] - sup[i] * m[i + 1]) / diag[i];
+    }
+    m[0] = m[1] + h(0) * (m[1] - m[2]) / h(1);
+    m[n - 1] = m[n - 2] + b * (m[n - 2] - m[n - 3]) / a;
+    return m;
+}
+
+// ∫ over each [x_i, x_(i+1)]
+fn intervals(method: Method, data: &[(f64, f64)]) -> Vec<f64> {
+    check(data);
+    let n = data.len();
+    if n < 2 {
+        return Vec::new();
+    }
+    let h = |i: usize| data[i + 1].0 - data[i].0;
+    let y = |i: usize| data[i].1;
+    return match method {
+        Method::Trapezoid => (0..(n - 1))
+            .map(|i| h(i) * (y(i) + y(i + 1)) / 2.0)
+            .collect(),
+        Method::Simpson if n == 2 => intervals(Method::Trapezoid, data),
+        Method::Simpson => (0..(n - 1))
+            .map(|i| {
+                // pair intervals (0, 1), (2, 3), ...; an odd one out at the end pairs with the one
+                // before it
+                let first = if i.is_multiple_of(2) && i + 2 < n {
+                    i
+                } else {
+                    i - 1
+                };
+                if i == first {
+                    quadratic_first(h(i), h(i + 1), y(i), y(i + 1), y(i + 2))
+                } else {
+                    quadratic_first(h(i), h(i - 1), y(i + 1), y(i), y(i - 1))
+                }
+            })
+            .collect(),
+        Method::Spline => {
+            let m = spline_moments(data);
+            (0..(n - 1))
+                .map(|i| h(i) * (y(i) + y(i + 1)) / 2.0 - h(i).powi(3) * (m[i] + m[i + 1]) / 24.0)
+                .collect()
+        }
+    };
+}
@@ -0,0 +1,67 @@
+// SPDX-License-Identifier: MIT
+// the trapezoid rule, halving the subintervals until successive estimates agree; each level only
+// evaluates the new midpoints, I_i = I_(i-1)/2 + h_i Σ_(k odd) f(a + k h_i)
+
+use crate::integrator::{Budget, IntegrationError, IntegrationResult, Tolerance};
+use crate::summation::Summation;
+use std::time::Instant;
+
+// stops as soon as two successive estimates agree to within eps, however coarse, as it always has;
+// integrate_summed with a Tolerance can insist on a minimum level
+pub fn integrate(
+    eps: f64,
+    f: impl Fn(f64) -> f64,
+    x0: f64,
+    x1: f64,
+) -> Result<(u64, f64), IntegrationError> {
+    let tol = Tolerance {
+        min_level: 0,
+        ..Tolerance::new(eps, 0.0)
+    };
+    let (subs, ret, _, _) =
+        integrate_summed(&tol, f, x0, x1, Summation::Naive, &Budget::default())?;
+    return Ok((subs, ret));
+}
+
+// integrate to a general tolerance, with the new points at each level summed by `method`, giving up
+// when the next level would break `budget`; returns (subintervals, ret, last error estimate,
+// rounding error bound)
+pub fn integrate_summed(
+    tol: &Tolerance,
+    f: impl Fn(f64) -> f64,
+    x0: f64,
+    x1: f64,
+    method: Summation,
+    budget: &Budget,
+) -> Result<(u64, f64, f64, f64), IntegrationError> {
+    let start = Instant::now();
+    let mut subs: u64 = 1;
+    let mut ret: f64 = (x1 - x0) * (f(x0) + f(x1)) / 2.0;
+    let mut round: f64 = f64::EPSILON * ret.abs();
+    let mut error: f64 = f64::INFINITY;
+    while !tol.met(subs.trailing_zeros(), ret, error) {
+        // the next level doubles subs and evaluates subs new points
+        budget.check(
+            start,
+            2 * subs as usize,
+            2 * subs as usize + 1,
+            IntegrationResult {
+                value: ret,
+                abs_error: error.abs(),
+                evaluations: subs as usize + 1,
+                subdivisions: subs as usize,
+                converged: false,
+            },
+        )?;
+        error = -ret / 3.0;
+        subs *= 2;
+        ret /= 2.0;
+        let h: f64 = (x1 - x0) / (subs as f64);
+        let (sum, sum_err) = method.sum((1..subs).step_by(2).map(|k| f(x0 + (k as f64) * h)));
+        ret += h * sum;
+        // halving is exact; the new sum brings its own error, and the product and add one ulp each
+        round = round / 2.0 + h.abs() * sum_err + f64::EPSILON * ret.abs();
+        error += ret / 3.0;
+    }
+    return Ok((subs, ret, error, round));
+}
@@ -0,0 +1,143 @@
+// SPDX-License-Identifier: MIT
+// what an integrand may return: anything we can take weighted sums of. Quadrature weights are
+// always f64; f32 values get them rounded.
+
+use num_complex::Complex;
+
+pub trait Value: Clone {
+    // w * self
+    fn scale(&self, w: f64) -> Self;
+    // self += w * other
+    fn add_scaled(&mut self, w: f64, other: &Self);
+}
+
+impl Value for f64 {
+    #[inline]
+    fn scale(&self, w: f64) -> Self {
+        return w * self;
+    }
+
+    #[inline]
+    fn add_scaled(&mut self, w: f64, other: &Self) {
+        *self += w * other;
+    }
+}
+
+impl Value for f32 {
+    #[inline]
+    fn scale(&self, w: f64) -> Self {
+        return (w as f32) * self;
+    }
+
+    #[inline]
+    fn add_scaled(&mut self, w: f64, other: &Self) {
+        *self += (w as f32) * other;
+    }
+}
+
+impl<T: Value> Value for Complex<T> {
+    #[inline]
+    fn scale(&self, w: f64) -> Self {
+        return Complex::new(self.re.scale(w), self.im.scale(w));
+    }
+
+    #[inline]
+    fn add_scaled(&mut self, w: f64, other: &Self) {
+        self.re.add_scaled(w, &other.re);
+        self.im.add_scaled(w, &other.im);
+    }
+}
+
+impl<T: Value, const K: usize> Value for [T; K] {
+    #[inline]
+    fn scale(&self, w: f64) -> Self {
+        return self.each_ref().map(|v| v.scale(w));
+    }
+
+    #[inline]
+    fn add_scaled(&mut self, w: f64, other: &Self) {
+        for (a, b) in self.iter_mut().zip(other) {
+            a.add_scaled(w, b);
+        }
+    }
+}
+
+impl<T: Value> Value for Vec<T> {
+    fn scale(&self, w: f64) -> Self {
+        return self.iter().map(|v| v.scale(w)).collect();
+    }
+
+    fn add_scaled(&mut self, w: f64, other: &Self) {
+        assert_eq!(self.len(), other.len());
+        for (a, b) in self.iter_mut().zip(other) {
+            a.add_scaled(w, b);
+        }
+    }
+}
+
+// values made of f64 components, for integrators that need per-component errors
+pub trait Vector: Value {
+    fn components(&self) -> &[f64];
+    fn components_mut(&mut self) -> &mut [f64];
+
+    // the same shape as self, with f applied to each component
+    fn map(&self, f: impl Fn(f64) -> f64) -> Self {
+        let mut ret = self.clone();
+        for c in ret.components_mut() {
+            *c = f(*c);
+        }
+        return ret;
+    }
+}
+
+impl Vector for f64 {
+    #[inline]
+    fn components(&self) -> &[f64] {
+        return std::slice::from_ref(self);
+    }
+
+    #[inline]
+    fn components_mut(&mut self) -> &mut [f64] {
+        return std::slice::from_mut(self);
+    }
+}
+
+impl<const K: usize> Vector for [f64; K] {
+    #[inline]
+    fn components(&self) -> &[f64] {
+        return self;
+    }
+
+    #[inline]
+    fn components_mut(&mut self) -> &mut [f64] {
+        return self;
+    }
+}
+
+impl Vector for Vec<f64> {
+    #[inline]
+    fn components(&self) -> &[f64] {
+        return self;
+    }
+
+    #[inline]
+    fn components_mut(&mut self) -> &mut [f64] {
+        return self;
+    }
+}
+
+// Σ_i w_i ys_i, for ws and ys of the same (nonzero) length
+pub fn weighted_sum<T: Value>(ws: &[f64], ys: &[T]) -> T {
+    let mut ret = ys[0].scale(ws[0]);
+    for (w, y) in ws[1..].iter().zip(&ys[1..]) {
+        ret.add_scaled(*w, y);
+    }
+    return ret;
+}
+
+// a + b
+pub fn sum2<T: Value>(a: &T, b: &T) -> T {
+    let mut ret = a.clone();
+    ret.add_scaled(1.0, b);
+    return ret;
+}